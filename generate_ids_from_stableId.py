@@ -81,7 +81,7 @@ def generate_for(enum, data):
     return_list += [f"pub enum {enum} {{"]
     return_list += [f"\t{key} = {value}," for key, value in data.items()]
     # return_list 
-    return ["\n".join(return_list) + "}"] + ["\n\n"] + gd.implementations(enum)
+    return ["\n".join(return_list) + "}"] + ["\n\n"] + [gd.variants(enum, data)] + gd.implementations(enum)
 
 
 def generate(file=r"C:\Users\danie\Documents\StarCraft II\stableid.json"):
@@ -0,0 +1,575 @@
+"""
+Writes sc2_helper/sc2_helper.pyi, the type stub of the Rust extension module.
+
+Classes and functions are listed in STUB below, keep it in sync with the #[pyclass]es and #[pyfunction]s.
+Enum members are read from the Rust sources, so run this again after regenerating src/generated_enums.rs.
+"""
+import re
+
+ENUM_SOURCES = {
+    "src/generated_enums.rs": ["UnitTypeId", "AbilityId", "UpgradeId", "BuffId", "EffectId"],
+    "src/enums.rs": ["Attribute"],
+    "src/weapon.rs": ["WeaponTargetType"],
+}
+
+STUB = '''\
+# Written by generate_stubs.py. Edit the classes and functions in its STUB, enum members are read from the
+# Rust sources.
+import asyncio
+from enum import Enum, IntEnum
+from typing import Any, List, Optional, Sequence, Tuple, Union
+
+# A python-sc2 Unit or a CombatUnit
+UnitLike = Any
+# An int, a UnitTypeId or python-sc2's UnitTypeId
+UnitTypeIdLike = Union[int, Enum]
+ReinforcementWave = Tuple[float, Sequence[UnitLike]]
+
+class CombatModelParams:
+    healing_per_second: float
+    melee_target_bonus: float
+    slow_target_bonus: float
+    outranged_target_penalty: float
+    high_melee_fraction: float
+    low_melee_fraction: float
+    surround_packing: float
+    zealot_radius: float
+    vespene_multiplier: float
+    def __init__(self) -> None: ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(json: str) -> CombatModelParams: ...
+
+class Terrain:
+    high_ground_player: int
+    high_ground_vision: bool
+    choke_width: float
+    creep_players: List[int]
+    def __init__(self) -> None: ...
+    def swapped(self) -> Terrain: ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(json: str) -> Terrain: ...
+
+class CombatSettings:
+    bad_micro: bool
+    debug: bool
+    enable_splash: bool
+    enable_timing_adjustment: bool
+    enable_surround_limits: bool
+    enable_melee_blocking: bool
+    workers_do_no_damage: bool
+    assume_reasonable_positioning: bool
+    max_time: float
+    start_time: float
+    multi_threaded: bool
+    retreat_threshold: float
+    retreat_by_supply: bool
+    retreat_player: int
+    chase_time: float
+    early_out_health_fraction: float
+    early_out_player: int
+    trace: bool
+    params: CombatModelParams
+    terrain: Terrain
+    def __init__(self) -> None: ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(json: str) -> CombatSettings: ...
+
+class CalibrationResult:
+    params: CombatModelParams
+    accuracy: float
+    initial_accuracy: float
+    health_left_error: float
+    initial_health_left_error: float
+    evaluations: int
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(json: str) -> CalibrationResult: ...
+
+class UnitSnapshot:
+    tag: int
+    side: int
+    type_id: int
+    health: float
+    shield: float
+    energy: float
+    target: Optional[int]
+    damage_dealt: float
+    healing_done: float
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(json: str) -> UnitSnapshot: ...
+
+class TraceIteration:
+    iteration: int
+    time: float
+    units: List[UnitSnapshot]
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(json: str) -> TraceIteration: ...
+
+class CombatTrace:
+    iterations: List[TraceIteration]
+    def __len__(self) -> int: ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(json: str) -> CombatTrace: ...
+
+class CombatResult:
+    winner: int
+    winning_team: int
+    health_left: List[float]
+    retreated: List[int]
+    time: float
+    trace: Optional[CombatTrace]
+    pending_reinforcements: List[int]
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(json: str) -> CombatResult: ...
+
+class Composition:
+    units: List[Tuple[int, int]]
+    minerals: int
+    vespene: int
+    supply: float
+    adjusted_cost: int
+    health_left: float
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(json: str) -> Composition: ...
+
+class Matchup:
+    unit1: int
+    unit2: int
+    upgrade_level: int
+    count1: int
+    count2: int
+    winner: int
+    cost_efficiency: float
+    time: float
+    health_left: List[float]
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(json: str) -> Matchup: ...
+
+class EngagementResult:
+    replay: str
+    start_time: float
+    end_time: float
+    position: Tuple[float, float]
+    units1: List[Tuple[int, int]]
+    units2: List[Tuple[int, int]]
+    unknown_units: int
+    winner: int
+    predicted_winner: int
+    health_left: List[float]
+    predicted_health_left: List[float]
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(json: str) -> EngagementResult: ...
+
+class BenchmarkReport:
+    replays: int
+    engagements: List[EngagementResult]
+    skipped: int
+    errors: List[str]
+    accuracy: float
+    health_left_error: float
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(json: str) -> BenchmarkReport: ...
+
+class DamageBonus:
+    attribute: int
+    bonus: float
+    def __init__(self, attribute: int, bonus: float) -> None: ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(json: str) -> DamageBonus: ...
+
+class Weapon:
+    type: int
+    damage: float
+    attacks: int
+    range: float
+    speed: float
+    damage_bonus: Optional[DamageBonus]
+    def __init__(
+        self,
+        type: int,
+        damage: float,
+        attacks: int,
+        range: float,
+        speed: float,
+        damage_bonus: Optional[DamageBonus] = None,
+    ) -> None: ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(json: str) -> Weapon: ...
+
+class Cost:
+    minerals: int
+    vespene: int
+    time: float
+    def __init__(self, minerals: int = 0, vespene: int = 0, time: float = 0.0) -> None: ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(json: str) -> Cost: ...
+
+class CombatUnit:
+    tag: int
+    health: float
+    health_max: float
+    shield: float
+    shield_max: float
+    energy: float
+    energy_max: float
+    armor: float
+    movement_speed: float
+    radius: float
+    attack_upgrade_level: int
+    armor_upgrade_level: int
+    shield_upgrade_level: int
+    weapons: List[Weapon]
+    attributes: List[int]
+    cost: Cost
+    supply: float
+    def __init__(
+        self,
+        type_id: UnitTypeIdLike,
+        health: float,
+        weapons: Sequence[Weapon] = ...,
+        attributes: Sequence[int] = ...,
+        armor: float = 0.0,
+        shield: float = 0.0,
+        energy: float = 0.0,
+        movement_speed: float = 0.0,
+        radius: float = 0.5,
+        is_flying: bool = False,
+        cost: Optional[Cost] = None,
+        supply: float = 0.0,
+        name: Optional[str] = None,
+    ) -> None: ...
+    @staticmethod
+    def from_type_id(type_id: UnitTypeIdLike) -> CombatUnit: ...
+    @staticmethod
+    def from_unit(unit: Any, predictor: Optional[CombatPredictor] = None) -> CombatUnit: ...
+    @property
+    def type_id(self) -> int: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def is_flying(self) -> bool: ...
+    @property
+    def ground_dps(self) -> float: ...
+    @property
+    def ground_range(self) -> float: ...
+    @property
+    def air_dps(self) -> float: ...
+    @property
+    def air_range(self) -> float: ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(json: str) -> CombatUnit: ...
+    def __copy__(self) -> CombatUnit: ...
+    def __deepcopy__(self, memo: Any) -> CombatUnit: ...
+
+class CombatPredictor:
+    def __init__(self) -> None: ...
+    def clear_cache(self) -> None: ...
+    def start_game(self, game_key: str = "") -> None: ...
+    @property
+    def cache_size(self) -> int: ...
+    def predict_engage(
+        self,
+        units1: Sequence[UnitLike],
+        units2: Sequence[UnitLike],
+        defender_player: int,
+        settings: CombatSettings,
+    ) -> Tuple[int, float]: ...
+    def predict_engage_result(
+        self,
+        units1: Sequence[UnitLike],
+        units2: Sequence[UnitLike],
+        defender_player: int,
+        settings: CombatSettings,
+    ) -> CombatResult: ...
+    def predict_engage_async(
+        self,
+        units1: Sequence[UnitLike],
+        units2: Sequence[UnitLike],
+        defender_player: int,
+        settings: CombatSettings,
+    ) -> asyncio.Future[CombatResult]: ...
+    def predict_engage_batch(
+        self,
+        pairs: Sequence[Tuple[Sequence[UnitLike], Sequence[UnitLike]]],
+        settings: CombatSettings,
+        defender_player: int = 0,
+    ) -> List[CombatResult]: ...
+    def predict_engage_with_reinforcements(
+        self,
+        units1: Sequence[UnitLike],
+        units2: Sequence[UnitLike],
+        reinforcements1: Sequence[ReinforcementWave],
+        reinforcements2: Sequence[ReinforcementWave],
+        defender_player: int,
+        settings: CombatSettings,
+    ) -> CombatResult: ...
+    def predict_engage_multi(
+        self,
+        armies: Sequence[Sequence[UnitLike]],
+        teams: Sequence[int],
+        defender_player: int,
+        settings: CombatSettings,
+        reinforcements: Optional[Sequence[Sequence[ReinforcementWave]]] = None,
+    ) -> CombatResult: ...
+    def find_best_composition(
+        self,
+        enemy_units: Sequence[UnitLike],
+        unit_types: Sequence[UnitTypeIdLike],
+        minerals: int,
+        vespene: int,
+        supply: float,
+        settings: CombatSettings,
+        max_unit_types: int = 2,
+        defender_player: int = 0,
+    ) -> Optional[Composition]: ...
+    def find_minimum_force(
+        self,
+        enemy_units: Sequence[UnitLike],
+        units: Sequence[Tuple[int, int]],
+        settings: CombatSettings,
+        confidence: float = 1.0,
+        health_margin: float = 0.0,
+        samples: int = 1,
+        max_count: int = 100,
+        defender_player: int = 0,
+    ) -> Optional[Composition]: ...
+    def matchup_matrix(
+        self,
+        settings: CombatSettings,
+        unit_types: Optional[Sequence[UnitTypeIdLike]] = None,
+        upgrade_levels: Sequence[int] = ...,
+        resources: int = 2000,
+    ) -> List[Matchup]: ...
+    def calibrate(
+        self,
+        engagements_json: str,
+        params: Optional[CombatModelParams] = None,
+        iterations: int = 10,
+    ) -> CalibrationResult: ...
+    def benchmark_replays(
+        self,
+        settings: CombatSettings,
+        replays: Sequence[str],
+        min_deaths: int = 3,
+    ) -> BenchmarkReport: ...
+
+class Region:
+    id: int
+    area: int
+    center: Tuple[float, float]
+    height: float
+    ramps: List[int]
+    chokes: List[int]
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(json: str) -> Region: ...
+
+class Ramp:
+    id: int
+    points: List[Tuple[int, int]]
+    center: Tuple[float, float]
+    upper: Tuple[float, float]
+    lower: Tuple[float, float]
+    width: float
+    regions: List[int]
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(json: str) -> Ramp: ...
+
+class Choke:
+    id: int
+    points: List[Tuple[int, int]]
+    center: Tuple[float, float]
+    width: float
+    regions: List[int]
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(json: str) -> Choke: ...
+
+class PathFinder:
+    width: int
+    height: int
+    def __init__(self, pathing: Any, map_size: Optional[Tuple[int, int]] = None) -> None: ...
+    def is_passable(self, x: int, y: int, radius: float = 0.0) -> bool: ...
+    def find_path(
+        self,
+        start: Tuple[float, float],
+        goal: Tuple[float, float],
+        radius: float = 0.0,
+        weights: Optional[Any] = None,
+        smooth: bool = True,
+    ) -> Optional[List[Tuple[float, float]]]: ...
+    def find_paths(
+        self,
+        queries: Sequence[Tuple[Tuple[float, float], Tuple[float, float], float]],
+        weights: Optional[Any] = None,
+        smooth: bool = True,
+    ) -> List[Optional[List[Tuple[float, float]]]]: ...
+
+class MapAnalysis:
+    regions: List[Region]
+    ramps: List[Ramp]
+    chokes: List[Choke]
+    expansion_locations: List[Tuple[float, float]]
+    width: int
+    height: int
+    def __init__(
+        self,
+        pathing: Any,
+        placement: Any,
+        terrain_height: Any,
+        map_size: Optional[Tuple[int, int]] = None,
+        minerals: Optional[Sequence[Tuple[float, float]]] = None,
+        geysers: Optional[Sequence[Tuple[float, float]]] = None,
+        max_choke_width: float = 8.0,
+    ) -> None: ...
+    def is_pathable(self, x: int, y: int) -> bool: ...
+    def is_placeable(self, x: int, y: int) -> bool: ...
+    def height_at(self, position: Tuple[float, float]) -> float: ...
+    def region_at(self, position: Tuple[float, float]) -> Optional[int]: ...
+    def pathable_points_inside_circle(
+        self, position: Tuple[float, float], radius: float
+    ) -> List[Tuple[int, int]]: ...
+    def path_finder(self) -> PathFinder: ...
+    def terrain(
+        self, position1: Tuple[float, float], position2: Tuple[float, float]
+    ) -> Terrain: ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(json: str) -> MapAnalysis: ...
+
+class InfluenceMap:
+    width: int
+    height: int
+    def __init__(self, width: int, height: int) -> None: ...
+    def clear(self) -> None: ...
+    def add_units(self, units: Sequence[Any], buffer: float = 0.0) -> None: ...
+    def threat_at(self, position: Tuple[float, float], air: bool = False) -> float: ...
+    def safe_points(
+        self,
+        position: Tuple[float, float],
+        radius: float,
+        air: bool = False,
+        max_threat: float = 0.0,
+    ) -> List[Tuple[int, int]]: ...
+    def find_safe_point(
+        self,
+        position: Tuple[float, float],
+        max_distance: float,
+        air: bool = False,
+        max_threat: float = 0.0,
+        path_finder: Optional[PathFinder] = None,
+        radius: float = 0.0,
+    ) -> Optional[Tuple[float, float]]: ...
+    def rows(self, air: bool = False) -> List[List[float]]: ...
+    def to_numpy(self, air: bool = False) -> Any: ...
+    def find_path(
+        self,
+        path_finder: PathFinder,
+        start: Tuple[float, float],
+        goal: Tuple[float, float],
+        radius: float = 0.0,
+        air: bool = False,
+        threat_cost: float = 0.1,
+        smooth: bool = True,
+    ) -> Optional[List[Tuple[float, float]]]: ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(json: str) -> InfluenceMap: ...
+
+class SpatialIndex:
+    def __init__(self, units: Sequence[Any], cell_size: float = 4.0) -> None: ...
+    def __len__(self) -> int: ...
+    def closer_than(
+        self, position: Tuple[float, float], distance: float, include_radius: bool = False
+    ) -> List[int]: ...
+    def k_nearest(
+        self, position: Tuple[float, float], k: int, max_distance: Optional[float] = None
+    ) -> List[int]: ...
+    def in_attack_range_of(
+        self,
+        unit: Any,
+        position: Optional[Tuple[float, float]] = None,
+        bonus_distance: float = 0.0,
+    ) -> List[int]: ...
+
+class UnitCluster:
+    indices: List[int]
+    tags: List[int]
+    center: Tuple[float, float]
+    radius: float
+    adjusted_cost: int
+    supply: float
+    ground_units: int
+    air_units: int
+    ground_dps: float
+    air_dps: float
+    def __len__(self) -> int: ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(json: str) -> UnitCluster: ...
+
+def engagements_to_csv(engagements: Sequence[EngagementResult]) -> str: ...
+def matchups_to_csv(matchups: Sequence[Matchup]) -> str: ...
+def circles_intersect(
+    pos1: Tuple[float, float], pos2: Tuple[float, float], r1: float, r2: float
+) -> bool: ...
+def find_points_inside_circle(
+    position: Tuple[float, float], radius: float, h: int, w: int
+) -> List[Tuple[int, int]]: ...
+def circle_indices(
+    position: Tuple[float, float], radius: float, h: int, w: int
+) -> Tuple[Any, Any]: ...
+def add_inside_circle(
+    grid: Any, position: Tuple[float, float], radius: float, value: float
+) -> None: ...
+def set_inside_circle(
+    grid: Any, position: Tuple[float, float], radius: float, value: float
+) -> None: ...
+def cluster_units(
+    units: Sequence[Any], distance: float = 8.0, min_samples: int = 1
+) -> List[UnitCluster]: ...
+def pair_clusters(
+    own: Sequence[UnitCluster], enemy: Sequence[UnitCluster], max_distance: float = 15.0
+) -> List[Tuple[int, int, float]]: ...
+def update_log_level() -> None: ...
+'''
+
+
+def read_enums(file, names):
+    with open(file, "r") as f:
+        source = f.read()
+    enums = {}
+    for name in names:
+        body = re.search(r"pub enum %s \{(.*?)\n\}" % name, source, re.S).group(1)
+        enums[name] = re.findall(r"^\s*(\w+) = (-?\d+),", body, re.M)
+    return enums
+
+
+def generate(file="sc2_helper/sc2_helper.pyi"):
+    lines = [STUB]
+    for source, names in ENUM_SOURCES.items():
+        for name, members in read_enums(source, names).items():
+            lines.append(f"\nclass {name}(IntEnum):")
+            lines += [f"    {key} = {value}" for key, value in members]
+    with open(file, "w") as f:
+        f.write("\n".join(lines) + "\n")
+
+
+if __name__ == "__main__":
+    generate()
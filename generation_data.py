@@ -15,6 +15,15 @@ macros = [
 ]
 
 
+def variants(enum_name, data):
+    return_list = [f"impl {enum_name} {{",
+                   "\t/// Name and value of every variant, used to export the enum to Python.",
+                   "\tpub const VARIANTS: &[(&str, i32)] = &["]
+    return_list += [f"\t\t(\"{key}\", {value})," for key, value in data.items()]
+    return_list += ["\t];", "}"]
+    return "\n".join(return_list)
+
+
 def implementations(enum_name):
     default_mapping = {
         "UnitTypeId": "UnitTypeId::NOTAUNIT",
@@ -0,0 +1,34 @@
+from .sc2_helper import (
+    AbilityId,
+    Attribute,
+    BenchmarkReport,
+    BuffId,
+    CalibrationResult,
+    Choke,
+    CombatModelParams,
+    CombatPredictor,
+    CombatResult,
+    CombatSettings,
+    CombatTrace,
+    CombatUnit,
+    Composition,
+    Cost,
+    DamageBonus,
+    EffectId,
+    EngagementResult,
+    InfluenceMap,
+    MapAnalysis,
+    Matchup,
+    PathFinder,
+    Ramp,
+    Region,
+    SpatialIndex,
+    Terrain,
+    TraceIteration,
+    UnitCluster,
+    UnitSnapshot,
+    UnitTypeId,
+    UpgradeId,
+    Weapon,
+    WeaponTargetType,
+)
@@ -1,5 +1,5 @@
-from .sc2_helper import CombatPredictor, CombatSettings
-from typing import Tuple
+from .sc2_helper import CombatPredictor, CombatSettings, update_log_level
+from typing import Any, List, Optional, Tuple
 
 
 class CombatSimulator:
@@ -9,14 +9,18 @@ class CombatSimulator:
 
     def debug(self, value: bool):
         """
-        Print debug information. Warning: Slow!
-        Do not enable in production.
+        Log debug information of each simulation to the "sc2_helper.combat_predictor" logger. Warning: Slow!
+        Per unit details are logged below logging.DEBUG, at level 5.
+        Only enable it for the engagements you want to diagnose. Configure the logger before enabling it,
+        or call sc2_helper.sc2_helper.update_log_level() after changing its level.
 
         Default: False
         :param value:
         :return:
         """
         self.combat_settings.debug = value
+        if value:
+            update_log_level()
 
     def bad_micro(self, value: bool):
         """
@@ -109,6 +113,96 @@ class CombatSimulator:
         """
         self.combat_settings.start_time = value
 
+    def retreat_threshold(self, value: float):
+        """
+        Fraction of the starting army (0.0 - 1.0) a side can lose before it retreats.
+        0.0 disables retreating and the fight is simulated until one side dies.
+
+        Default: 0.0
+        :param value:
+        :return:
+        """
+        self.combat_settings.retreat_threshold = value
+
+    def retreat_by_supply(self, value: bool):
+        """
+        Measure army losses in supply instead of resources when checking retreat_threshold.
+
+        Default: False
+        :param value:
+        :return:
+        """
+        self.combat_settings.retreat_by_supply = value
+
+    def retreat_player(self, value: int):
+        """
+        Player that is allowed to retreat. 1 == units1, 2 == units2, 0 == both
+
+        Default: 0
+        :param value:
+        :return:
+        """
+        self.combat_settings.retreat_player = value
+
+    def chase_time(self, value: float):
+        """
+        Seconds the winning side chases a retreating army. Faster units deal more damage while chasing.
+
+        Default: 5.0
+        :param value:
+        :return:
+        """
+        self.combat_settings.chase_time = value
+
+    def trace(self, value: bool):
+        """
+        Record every unit's health, shield, energy, target, damage dealt and healing done for each iteration of the simulation.
+        The trace is available as CombatResult.trace and can be exported with trace.to_json(). Slow!
+
+        Default: False
+        :param value:
+        :return:
+        """
+        self.combat_settings.trace = value
+
+    def params(self, value):
+        """
+        Constants of the simulation heuristics as a CombatModelParams, e.g. the params found by calibrate.
+
+        Default: CombatModelParams()
+        :param value:
+        :return:
+        """
+        self.combat_settings.params = value
+
+    def terrain(self, value):
+        """
+        High ground, choke width and creep around the next engagements as a Terrain, players 1 == Self, 2 == Enemy.
+        Units on the high ground can't be shot by ranged ground units without vision, a choke limits how many
+        melee units engage at once and Zerg units on creep move faster.
+
+        Default: Terrain()
+        :param value:
+        :return:
+        """
+        self.combat_settings.terrain = value
+
+    def clear_cache(self):
+        """
+        Forget the unit stats cached by the combat predictor. Units are cached per type and upgrade levels.
+        :return:
+        """
+        self.combat_predictor.clear_cache()
+
+    def start_game(self, game_key: str = ""):
+        """
+        Call this in on_start so unit stats from a previous game or game version are not reused.
+        The cache is kept if game_key is the key of the previous game, an empty key always clears it.
+        :param game_key:
+        :return:
+        """
+        self.combat_predictor.start_game(game_key)
+
     def predict_engage(self,
                        own_units,
                        enemy_units,
@@ -141,11 +235,203 @@ class CombatSimulator:
             elif defender_player == 2:
                 defender_player = 1
 
-            winner, health_left = self.combat_predictor.predict_engage(enemy_units,
-                                                                       own_units,
-                                                                       defender_player,
-                                                                       self.combat_settings)
+            terrain = self.combat_settings.terrain
+            self.combat_settings.terrain = terrain.swapped()
+            try:
+                winner, health_left = self.combat_predictor.predict_engage(enemy_units,
+                                                                           own_units,
+                                                                           defender_player,
+                                                                           self.combat_settings)
+            finally:
+                self.combat_settings.terrain = terrain
             if winner == 2:
                 return True, health_left
             else:
                 return False, health_left
+
+    async def predict_engage_async(self,
+                                   own_units,
+                                   enemy_units,
+                                   defender_player: int = 0) -> Tuple[bool, float]:
+        """
+        Same as predict_engage with optimistic == True, but the simulation runs on a background thread
+        so several engagements can be evaluated concurrently, e.g. with asyncio.gather in on_step.
+
+        :param own_units: sc2.Units object containing own units to simulate
+        :param enemy_units: sc2.Units object containing enemy units to simulate
+        :param defender_player: Defending player. 1 == Self, 2 == Enemy
+        :return:
+        """
+        result = await self.combat_predictor.predict_engage_async(own_units,
+                                                                  enemy_units,
+                                                                  defender_player,
+                                                                  self.combat_settings)
+        return result.winner == 1, result.health_left[result.winner - 1]
+
+    def predict_engage_batch(self,
+                             pairs: List[Tuple[Any, Any]],
+                             defender_player: int = 0) -> List[Tuple[bool, float]]:
+        """
+        Predict many engagements at once, e.g. every own army cluster against every nearby enemy cluster.
+        Much faster than calling predict_engage for each pair: units are converted once and the simulations
+        run in parallel. Returns a list of (Winner(True if own_units won), winner's health left), one per pair.
+
+        :param pairs: List of (own_units, enemy_units) sc2.Units objects
+        :param defender_player: Defending player. 1 == Self, 2 == Enemy
+        :return:
+        """
+        results = self.combat_predictor.predict_engage_batch(pairs, self.combat_settings, defender_player)
+        return [(r.winner == 1, r.health_left[r.winner - 1]) for r in results]
+
+    def predict_engage_with_reinforcements(self,
+                                           own_units,
+                                           enemy_units,
+                                           own_reinforcements: List[Tuple[float, Any]],
+                                           enemy_reinforcements: List[Tuple[float, Any]],
+                                           defender_player: int = 0) -> Tuple[bool, float]:
+        """
+        Predict an engagement where units join the fight after it started, e.g. units still walking across the map.
+        Returns a tuple containing Winner(True if own_units won) and winner's units' health left after engagement.
+        Waves arriving after max_time never join the fight.
+
+        :param own_units: sc2.Units object containing own units to simulate
+        :param enemy_units: sc2.Units object containing enemy units to simulate
+        :param own_reinforcements: List of (arrival time in seconds, sc2.Units) joining own_units
+        :param enemy_reinforcements: List of (arrival time in seconds, sc2.Units) joining enemy_units
+        :param defender_player: Defending player. 1 == Self, 2 == Enemy
+        :return:
+        """
+        result = self.combat_predictor.predict_engage_with_reinforcements(own_units,
+                                                                          enemy_units,
+                                                                          own_reinforcements,
+                                                                          enemy_reinforcements,
+                                                                          defender_player,
+                                                                          self.combat_settings)
+        if result.winner == 1:
+            return True, result.health_left[0]
+        else:
+            return False, result.health_left[1]
+
+    def predict_engage_multi(self,
+                             armies: List[Any],
+                             teams: Optional[List[int]] = None,
+                             defender_player: int = 0):
+        """
+        Predict an engagement between any number of armies, e.g. for FFA or 2v2 games.
+        Returns a CombatResult with the winning side and team, and the health left per army.
+
+        :param armies: List of sc2.Units objects, one per army
+        :param teams: Team of each army. Armies with the same team are allied. Defaults to free-for-all
+        :param defender_player: Defending army, starting at 1. 0 == no defender
+        :return:
+        """
+        return self.combat_predictor.predict_engage_multi(armies,
+                                                          teams or [],
+                                                          defender_player,
+                                                          self.combat_settings)
+
+    def find_best_composition(self,
+                              enemy_units,
+                              unit_types: List[Any],
+                              minerals: int,
+                              vespene: int,
+                              supply: float,
+                              max_unit_types: int = 2,
+                              defender_player: int = 0):
+        """
+        Find the cheapest army made of unit_types that is predicted to beat enemy_units.
+        Returns a Composition with the amount of each unit type, its cost and the predicted health left,
+        or None if nothing affordable wins. Each army size is predicted once, so close fights can come out
+        either way; find_minimum_force with more samples gives a more reliable amount for a chosen mix.
+
+        :param enemy_units: sc2.Units object containing enemy units to beat
+        :param unit_types: List of UnitTypeIds that can be produced
+        :param minerals: Mineral budget
+        :param vespene: Vespene budget
+        :param supply: Supply budget
+        :param max_unit_types: Max amount of different unit types in the composition, 1 or 2
+        :param defender_player: Defending player. 1 == Self, 2 == Enemy
+        :return:
+        """
+        return self.combat_predictor.find_best_composition(enemy_units,
+                                                           unit_types,
+                                                           minerals,
+                                                           vespene,
+                                                           supply,
+                                                           self.combat_settings,
+                                                           max_unit_types,
+                                                           defender_player)
+
+    def find_minimum_force(self,
+                           enemy_units,
+                           unit_ratio: List[Tuple[Any, int]],
+                           confidence: float = 1.0,
+                           health_margin: float = 0.0,
+                           samples: int = 1,
+                           max_count: int = 100,
+                           defender_player: int = 0):
+        """
+        Find the smallest army with the given unit ratio that is predicted to beat enemy_units.
+        Returns a Composition with the amount of each unit type, or None if max_count times the ratio is not enough.
+
+        :param enemy_units: sc2.Units object containing enemy units to beat
+        :param unit_ratio: List of (UnitTypeId, amount), e.g. [(MARINE, 3), (MARAUDER, 1)]
+        :param confidence: Fraction of the samples (0.0 - 1.0) that have to be won
+        :param health_margin: Fraction of the starting health + shields that has to be left after winning
+        :param samples: Amount of predictions per army size
+        :param max_count: Max multiple of unit_ratio to try
+        :param defender_player: Defending player. 1 == Self, 2 == Enemy
+        :return:
+        """
+        return self.combat_predictor.find_minimum_force(enemy_units,
+                                                        unit_ratio,
+                                                        self.combat_settings,
+                                                        confidence,
+                                                        health_margin,
+                                                        samples,
+                                                        max_count,
+                                                        defender_player)
+
+    def matchup_matrix(self,
+                       unit_types: Optional[List[Any]] = None,
+                       upgrade_levels: Optional[List[int]] = None,
+                       resources: int = 2000):
+        """
+        Predict a fight between equal amounts of resources for every pair of unit types, at each upgrade level.
+        Returns a list of Matchup with the winner, cost efficiency and time to kill of each pair.
+        Use matchups_to_csv from sc2_helper.sc2_helper to export them.
+
+        :param unit_types: List of UnitTypeId, every unit type that can attack if None
+        :param upgrade_levels: Attack, armor and shield upgrade levels of both sides, [0, 1, 2, 3] if None
+        :param resources: Army value of each side, minerals + 1.5 * vespene
+        :return:
+        """
+        if upgrade_levels is None:
+            upgrade_levels = [0, 1, 2, 3]
+        return self.combat_predictor.matchup_matrix(self.combat_settings, unit_types, upgrade_levels, resources)
+
+    def benchmark_replays(self, replays: List[str], min_deaths: int = 3):
+        """
+        Find the engagements in 1v1 replays and compare their outcome with the predictions of the current settings.
+        Returns a BenchmarkReport with the winner accuracy and the result of each engagement.
+        Use engagements_to_csv from sc2_helper.sc2_helper to export them.
+
+        :param replays: Paths of .SC2Replay files
+        :param min_deaths: Engagements with fewer deaths are ignored
+        :return:
+        """
+        return self.combat_predictor.benchmark_replays(self.combat_settings, replays, min_deaths)
+
+    def calibrate(self, engagements_json: str, iterations: int = 10):
+        """
+        Fit the simulation heuristics to engagements with a known outcome, starting at the current params,
+        and use the fitted params from now on. Returns a CalibrationResult with the accuracy before and after.
+
+        :param engagements_json: JSON object with an "engagements" list, each a scenario like the ones sc2-sim
+        runs with the "winner" (1 or 2) and optionally the "health_left" fraction of both sides
+        :param iterations: Rounds of the parameter search
+        :return:
+        """
+        result = self.combat_predictor.calibrate(engagements_json, self.combat_settings.params, iterations)
+        self.combat_settings.params = result.params
+        return result
@@ -0,0 +1,392 @@
+use crate::combat_predictor::{CombatPredictor, CombatSettings};
+use crate::combat_unit::CombatUnit;
+use crate::conversion;
+use crate::generated_enums::UnitTypeId;
+use crate::replay::{self, Engagement, EngagementOptions, Replay, LOOPS_PER_SECOND};
+use crate::unit_database;
+use pyo3::prelude::*;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// A fight found in a replay, compared with its prediction.
+#[pyclass(module = "sc2_helper.sc2_helper")]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct EngagementResult {
+    #[pyo3(get)]
+    pub replay: String,
+    /// Seconds at faster game speed.
+    #[pyo3(get)]
+    pub start_time: f32,
+    #[pyo3(get)]
+    pub end_time: f32,
+    #[pyo3(get)]
+    pub position: (f32, f32),
+    /// Unit types and counts of each player at the start of the engagement.
+    #[pyo3(get)]
+    pub units1: Vec<(UnitTypeId, u32)>,
+    #[pyo3(get)]
+    pub units2: Vec<(UnitTypeId, u32)>,
+    /// Units the unit database has no data for, left out of the prediction.
+    #[pyo3(get)]
+    pub unknown_units: u32,
+    /// Player that lost the smaller fraction of its army value, 0 if both lost the same.
+    #[pyo3(get)]
+    pub winner: u32,
+    #[pyo3(get)]
+    pub predicted_winner: u32,
+    /// Fraction of health + shield left per player. Replays don't record health, so surviving units
+    /// count as undamaged.
+    #[pyo3(get)]
+    pub health_left: Vec<f32>,
+    #[pyo3(get)]
+    pub predicted_health_left: Vec<f32>,
+}
+
+#[pymethods]
+impl EngagementResult {
+    /// JSON representation, also used to pickle it.
+    pub fn to_json(&self) -> PyResult<String> {
+        conversion::to_json(self)
+    }
+
+    #[staticmethod]
+    pub fn from_json(json: &str) -> PyResult<Self> {
+        conversion::from_json(json)
+    }
+
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (String,))> {
+        conversion::reduce_json::<Self, _>(py, self)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+/// Accuracy of the combat predictor on the engagements of a set of replays.
+#[pyclass(module = "sc2_helper.sc2_helper")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct BenchmarkReport {
+    #[pyo3(get)]
+    pub replays: u32,
+    #[pyo3(get)]
+    pub engagements: Vec<EngagementResult>,
+    /// Engagements where a player had no units the unit database knows.
+    #[pyo3(get)]
+    pub skipped: u32,
+    /// Replays that could not be read.
+    #[pyo3(get)]
+    pub errors: Vec<String>,
+    /// Fraction of the engagements with a winner where the predicted winner is right.
+    #[pyo3(get)]
+    pub accuracy: f32,
+    /// Mean absolute difference between the predicted and real fraction of health left.
+    #[pyo3(get)]
+    pub health_left_error: f32,
+}
+
+#[pymethods]
+impl BenchmarkReport {
+    /// JSON representation, also used to pickle it.
+    pub fn to_json(&self) -> PyResult<String> {
+        conversion::to_json(self)
+    }
+
+    #[staticmethod]
+    pub fn from_json(json: &str) -> PyResult<Self> {
+        conversion::from_json(json)
+    }
+
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (String,))> {
+        conversion::reduce_json::<Self, _>(py, self)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "BenchmarkReport {{ replays: {}, engagements: {}, skipped: {}, errors: {}, accuracy: {:.3}, health_left_error: {:.3} }}",
+            self.replays,
+            self.engagements.len(),
+            self.skipped,
+            self.errors.len(),
+            self.accuracy,
+            self.health_left_error
+        )
+    }
+}
+
+/// Highest level of the upgrades starting with one of `prefixes`, e.g. 2 for
+/// `"TerranInfantryWeaponsLevel2"`.
+fn upgrade_level(upgrades: &[&str], prefixes: &[&str]) -> i64 {
+    upgrades
+        .iter()
+        .filter_map(|name| {
+            prefixes.iter().find_map(|prefix| {
+                name.strip_prefix(prefix)?
+                    .strip_prefix("Level")?
+                    .parse::<i64>()
+                    .ok()
+            })
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+fn zerg_weapons(unit: &CombatUnit) -> &'static str {
+    if unit.ground_range <= 1.0 {
+        "ZergMeleeWeapons"
+    } else {
+        "ZergMissileWeapons"
+    }
+}
+
+/// Sets the upgrade levels of `unit` from the upgrades its owner researched. The upgrade names of all
+/// races are checked, a player only has the ones of its own race.
+pub fn apply_upgrades(unit: &mut CombatUnit, upgrades: &[&str]) {
+    let (weapons, armors): (Vec<&str>, Vec<&str>) = if unit.is_flying {
+        (
+            vec!["TerranShipWeapons", "ProtossAirWeapons", "ZergFlyerWeapons"],
+            vec![
+                "TerranVehicleAndShipArmors",
+                "TerranShipArmors",
+                "ProtossAirArmors",
+                "ZergFlyerArmors",
+            ],
+        )
+    } else if unit.is_biological {
+        (
+            vec![
+                "TerranInfantryWeapons",
+                "ProtossGroundWeapons",
+                zerg_weapons(unit),
+            ],
+            vec![
+                "TerranInfantryArmors",
+                "ProtossGroundArmors",
+                "ZergGroundArmors",
+            ],
+        )
+    } else {
+        (
+            vec![
+                "TerranVehicleWeapons",
+                "ProtossGroundWeapons",
+                zerg_weapons(unit),
+            ],
+            vec![
+                "TerranVehicleAndShipArmors",
+                "TerranVehicleArmors",
+                "ProtossGroundArmors",
+                "ZergGroundArmors",
+            ],
+        )
+    };
+    unit.attack_upgrade_level = upgrade_level(upgrades, &weapons);
+    unit.armor_upgrade_level = upgrade_level(upgrades, &armors);
+    unit.shield_upgrade_level = upgrade_level(upgrades, &["ProtossShields"]);
+}
+
+fn count_types(units: &[CombatUnit]) -> Vec<(UnitTypeId, u32)> {
+    let mut counts: Vec<(UnitTypeId, u32)> = vec![];
+    for unit in units {
+        match counts.iter_mut().find(|(t, _)| *t == unit.type_id) {
+            Some((_, n)) => *n += 1,
+            None => counts.push((unit.type_id, 1)),
+        }
+    }
+    counts
+}
+
+/// Predicts `engagement` with the armies both players had at its start. None if a player had no units
+/// the unit database knows.
+pub fn benchmark_engagement(
+    predictor: &CombatPredictor,
+    replay: &Replay,
+    engagement: &Engagement,
+    settings: &CombatSettings,
+) -> Option<EngagementResult> {
+    let mut armies: Vec<Vec<CombatUnit>> = vec![];
+    let mut health_start = vec![];
+    let mut health_left = vec![];
+    let mut lost = vec![];
+    let mut unknown_units = 0;
+    for (side, units) in engagement.armies.iter().enumerate() {
+        let upgrades = replay.upgrades_at(replay.players[side], engagement.start);
+        let mut army = vec![];
+        let (mut health, mut left, mut value, mut value_lost) = (0.0, 0.0, 0.0, 0.0);
+        for unit in units {
+            let mut combat_unit = match replay::unit_type_from_name(&unit.unit_type)
+                .and_then(unit_database::get_unit)
+            {
+                Some(u) => u,
+                None => {
+                    unknown_units += 1;
+                    continue;
+                }
+            };
+            apply_upgrades(&mut combat_unit, &upgrades);
+            combat_unit.tag = unit.tag;
+            let unit_health = combat_unit.health + combat_unit.shield;
+            let cost = combat_unit.get_adjusted_cost() as f32;
+            health += unit_health;
+            value += cost;
+            if unit.died {
+                value_lost += cost;
+            } else {
+                left += unit_health;
+            }
+            army.push(combat_unit);
+        }
+        if army.is_empty() {
+            return None;
+        }
+        armies.push(army);
+        health_start.push(health);
+        health_left.push(left / health);
+        lost.push(value_lost / value);
+    }
+
+    let winner = if lost[0] < lost[1] {
+        1
+    } else if lost[1] < lost[0] {
+        2
+    } else {
+        0
+    };
+    let (units1, units2) = (count_types(&armies[0]), count_types(&armies[1]));
+    let units2_army = armies.pop().unwrap();
+    let units1_army = armies.pop().unwrap();
+    let result =
+        predictor
+            .clone()
+            ._predict_engage(units1_army, units2_army, vec![], vec![], 0, settings);
+    Some(EngagementResult {
+        replay: replay.name.clone(),
+        start_time: engagement.start as f32 / LOOPS_PER_SECOND,
+        end_time: engagement.end as f32 / LOOPS_PER_SECOND,
+        position: (engagement.x, engagement.y),
+        units1,
+        units2,
+        unknown_units,
+        winner,
+        predicted_winner: result.winner,
+        health_left,
+        predicted_health_left: result
+            .health_left
+            .iter()
+            .zip(health_start.iter())
+            .map(|(left, start)| left / start)
+            .collect(),
+    })
+}
+
+/// Finds the engagements in `replays` and compares them with the predictions of `predictor`.
+/// Replays are read in parallel, the ones that can't be read are listed in `errors`.
+pub fn benchmark(
+    predictor: &CombatPredictor,
+    replays: &[PathBuf],
+    settings: &CombatSettings,
+    options: &EngagementOptions,
+) -> BenchmarkReport {
+    let results: Vec<Result<(Vec<EngagementResult>, u32), String>> = replays
+        .par_iter()
+        .map(|path| {
+            let replay = Replay::load(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+            let engagements = replay.engagements(options);
+            let total = engagements.len() as u32;
+            let results: Vec<EngagementResult> = engagements
+                .iter()
+                .filter_map(|e| benchmark_engagement(predictor, &replay, e, settings))
+                .collect();
+            let skipped = total - results.len() as u32;
+            Ok((results, skipped))
+        })
+        .collect();
+
+    let mut report = BenchmarkReport {
+        replays: replays.len() as u32,
+        ..Default::default()
+    };
+    for result in results {
+        match result {
+            Ok((engagements, skipped)) => {
+                report.engagements.extend(engagements);
+                report.skipped += skipped;
+            }
+            Err(e) => report.errors.push(e),
+        }
+    }
+    report.summarize();
+    report
+}
+
+impl BenchmarkReport {
+    /// Computes `accuracy` and `health_left_error` from `engagements`.
+    pub fn summarize(&mut self) {
+        let decided: Vec<&EngagementResult> =
+            self.engagements.iter().filter(|e| e.winner != 0).collect();
+        let correct = decided
+            .iter()
+            .filter(|e| e.predicted_winner == e.winner)
+            .count();
+        self.accuracy = correct as f32 / decided.len().max(1) as f32;
+        let error: f32 = self
+            .engagements
+            .iter()
+            .map(|e| {
+                e.health_left
+                    .iter()
+                    .zip(e.predicted_health_left.iter())
+                    .map(|(real, predicted)| (real - predicted).abs())
+                    .sum::<f32>()
+                    / 2.0
+            })
+            .fold(0.0, |a, b| a + b);
+        self.health_left_error = error / self.engagements.len().max(1) as f32;
+    }
+}
+
+/// Header of the CSV written by `to_csv`.
+pub const CSV_HEADER: &str = "replay,start_time,end_time,x,y,units1,units2,unknown_units,winner,predicted_winner,health_left1,health_left2,predicted_health_left1,predicted_health_left2";
+
+fn format_units(units: &[(UnitTypeId, u32)]) -> String {
+    units
+        .iter()
+        .map(|(t, n)| format!("{}x{:?}", n, t))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// `value` as a CSV field, quoted if it contains separators, quotes or line breaks.
+fn csv_field(value: &str) -> String {
+    if value.contains(&[',', '"', '\n', '\r'][..]) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// One line per engagement, with the units as e.g. `10xMARINE 2xMEDIVAC`.
+pub fn to_csv(engagements: &[EngagementResult]) -> String {
+    let mut csv = format!("{}\n", CSV_HEADER);
+    for e in engagements {
+        csv += &format!(
+            "{},{:.1},{:.1},{:.1},{:.1},{},{},{},{},{},{:.3},{:.3},{:.3},{:.3}\n",
+            csv_field(&e.replay),
+            e.start_time,
+            e.end_time,
+            e.position.0,
+            e.position.1,
+            format_units(&e.units1),
+            format_units(&e.units2),
+            e.unknown_units,
+            e.winner,
+            e.predicted_winner,
+            e.health_left[0],
+            e.health_left[1],
+            e.predicted_health_left[0],
+            e.predicted_health_left[1]
+        );
+    }
+    csv
+}
@@ -0,0 +1,422 @@
+//! Runs engagement scenarios from JSON or TOML files through the combat predictor.
+//!
+//! A file holds one scenario, or several in a `scenarios` list:
+//!
+//! ```toml
+//! [[scenarios]]
+//! name = "marines vs zerglings"
+//! units1 = [{ unit = "MARINE", count = 10, attack_upgrade_level = 1 }]
+//! units2 = [{ unit = "ZERGLING", count = 20 }]
+//! settings = { enable_timing_adjustment = true }
+//! ```
+//!
+//! `sc2-sim matchups` instead predicts resource-equal fights between every pair of unit types,
+//! `sc2-sim benchmark` compares predictions with the engagements in `.SC2Replay` files and
+//! `sc2-sim calibrate` fits the simulation parameters to recorded engagements.
+use clap::{Parser, Subcommand, ValueEnum};
+use sc2_helper::benchmark::{self, BenchmarkReport};
+use sc2_helper::calibration::{self, CalibrationResult, Dataset};
+use sc2_helper::combat_predictor::{CombatPredictor, CombatSettings};
+use sc2_helper::generated_enums::UnitTypeId;
+use sc2_helper::matchup::{self, Matchup};
+use sc2_helper::replay::EngagementOptions;
+use sc2_helper::scenario::{Scenario, ScenarioReport};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+
+#[derive(Parser)]
+#[command(
+    name = "sc2-sim",
+    about = "Predict StarCraft II engagements from scenario files",
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
+)]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+    /// Scenario files, `.toml` or `.json`
+    #[arg(required = true)]
+    files: Vec<PathBuf>,
+    /// Report format, `csv` for matchups and `text` otherwise by default
+    #[arg(short, long, value_enum, global = true)]
+    format: Option<Format>,
+    /// Write the report to this file instead of stdout
+    #[arg(short, long, global = true)]
+    output: Option<PathBuf>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Resource-equal fights between every pair of unit types
+    Matchups {
+        /// Unit type names, e.g. `MARINE,ZERGLING`. Every unit type that can attack if not given
+        #[arg(short, long, value_delimiter = ',')]
+        units: Vec<String>,
+        /// Upgrade levels of both sides
+        #[arg(long, value_delimiter = ',', default_values_t = [0, 1, 2, 3])]
+        upgrades: Vec<i64>,
+        /// Resources per side, minerals + 1.5 * vespene
+        #[arg(short, long, default_value_t = 2000)]
+        resources: i32,
+    },
+    /// Compare predictions with the engagements in replays
+    Benchmark {
+        /// `.SC2Replay` files, or directories to search for them
+        #[arg(required = true)]
+        replays: Vec<PathBuf>,
+        /// Engagements with fewer deaths are ignored
+        #[arg(long, default_value_t = 3)]
+        min_deaths: u32,
+    },
+    /// Fit the simulation parameters to recorded engagements
+    Calibrate {
+        /// `.toml` or `.json` files with an `engagements` list of scenarios and their `winner`
+        #[arg(required = true)]
+        datasets: Vec<PathBuf>,
+        /// Parameters to fit, e.g. `healing_per_second,surround_packing`. All if not given
+        #[arg(short, long, value_delimiter = ',')]
+        params: Vec<String>,
+        #[arg(short, long, default_value_t = 10)]
+        iterations: u32,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Text,
+    Csv,
+    Json,
+    Toml,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ScenarioFile {
+    Many { scenarios: Vec<Scenario> },
+    One(Scenario),
+}
+
+#[derive(serde::Serialize)]
+struct Report<'a> {
+    reports: &'a [ScenarioReport],
+}
+
+#[derive(serde::Serialize)]
+struct MatchupReport<'a> {
+    matchups: &'a [Matchup],
+}
+
+fn parse_unit_type(name: &str) -> Result<UnitTypeId, String> {
+    serde_json::from_value(serde_json::Value::String(name.trim().to_uppercase()))
+        .map_err(|_| format!("Unknown unit type {}", name))
+}
+
+/// Reads a `.toml` file, or a JSON file with any other extension.
+fn read_file<T: DeserializeOwned>(path: &Path) -> Result<T, String> {
+    let content = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    match path.extension().and_then(|e| e.to_str()) {
+        Some("toml") => toml::from_str(&content).map_err(|e| e.to_string()),
+        _ => serde_json::from_str(&content).map_err(|e| e.to_string()),
+    }
+    .map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+fn read_scenarios(path: &Path) -> Result<Vec<Scenario>, String> {
+    let mut scenarios = match read_file(path)? {
+        ScenarioFile::Many { scenarios } => scenarios,
+        ScenarioFile::One(scenario) => vec![scenario],
+    };
+    // Unnamed scenarios are named after their file
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let count = scenarios.len();
+    for (index, scenario) in scenarios.iter_mut().enumerate() {
+        if scenario.name.is_empty() {
+            scenario.name = if count == 1 {
+                stem.to_string()
+            } else {
+                format!("{} #{}", stem, index + 1)
+            };
+        }
+    }
+    Ok(scenarios)
+}
+
+fn format_text(reports: &[ScenarioReport]) -> String {
+    let width = reports
+        .iter()
+        .map(|r| r.name.len())
+        .max()
+        .unwrap_or(0)
+        .max(8);
+    let mut text = format!(
+        "{:<width$}  winner  {:>8}  {:>19}  {:>19}\n",
+        "scenario",
+        "time",
+        "health left 1",
+        "health left 2",
+        width = width
+    );
+    for report in reports {
+        let health: Vec<String> = report
+            .health_left
+            .iter()
+            .zip(report.health_start.iter())
+            .map(|(left, start)| format!("{:.0}/{:.0}", left, start))
+            .collect();
+        text += &format!(
+            "{:<width$}  {:>6}  {:>7.1}s  {:>19}  {:>19}",
+            report.name,
+            report.winner,
+            report.time,
+            health[0],
+            health[1],
+            width = width
+        );
+        if !report.retreated.is_empty() {
+            text += &format!("  (retreated: {:?})", report.retreated);
+        }
+        text += "\n";
+    }
+    text
+}
+
+fn format_csv(reports: &[ScenarioReport]) -> String {
+    let mut csv = String::from(
+        "scenario,winner,time,health_left1,health_start1,health_left2,health_start2\n",
+    );
+    for r in reports {
+        csv += &format!(
+            "{},{},{:.2},{:.1},{:.1},{:.1},{:.1}\n",
+            r.name,
+            r.winner,
+            r.time,
+            r.health_left[0],
+            r.health_start[0],
+            r.health_left[1],
+            r.health_start[1]
+        );
+    }
+    csv
+}
+
+fn format_matchups_text(matchups: &[Matchup]) -> String {
+    let mut text = String::new();
+    for m in matchups {
+        text += &format!(
+            "{:?} vs {:?} +{}: {} vs {}, winner {}, cost efficiency {:.2}, {:.1}s\n",
+            m.unit1,
+            m.unit2,
+            m.upgrade_level,
+            m.count1,
+            m.count2,
+            m.winner,
+            m.cost_efficiency,
+            m.time
+        );
+    }
+    text
+}
+
+fn run_matchups(
+    args: &Args,
+    units: &[String],
+    upgrades: &[i64],
+    resources: i32,
+) -> Result<String, String> {
+    let unit_types = if units.is_empty() {
+        matchup::combat_unit_types()
+    } else {
+        units
+            .iter()
+            .map(|name| parse_unit_type(name))
+            .collect::<Result<Vec<UnitTypeId>, String>>()?
+    };
+    let matchups = matchup::matchup_matrix(
+        &CombatPredictor::new(),
+        &unit_types,
+        upgrades,
+        resources,
+        &CombatSettings::new(),
+    )?;
+    match args.format.unwrap_or(Format::Csv) {
+        Format::Text => Ok(format_matchups_text(&matchups)),
+        Format::Csv => Ok(matchup::to_csv(&matchups)),
+        Format::Json => serde_json::to_string_pretty(&MatchupReport {
+            matchups: &matchups,
+        })
+        .map_err(|e| e.to_string()),
+        Format::Toml => toml::to_string(&MatchupReport {
+            matchups: &matchups,
+        })
+        .map_err(|e| e.to_string()),
+    }
+}
+
+/// Replay files in `paths`, directories are searched recursively.
+fn find_replays(paths: &[PathBuf]) -> Result<Vec<PathBuf>, String> {
+    let mut replays = vec![];
+    for path in paths {
+        if path.is_dir() {
+            let mut entries = fs::read_dir(path)
+                .map_err(|e| format!("{}: {}", path.display(), e))?
+                .map(|entry| entry.map(|e| e.path()))
+                .collect::<Result<Vec<PathBuf>, _>>()
+                .map_err(|e| format!("{}: {}", path.display(), e))?;
+            entries.sort();
+            entries.retain(|p| {
+                p.is_dir() || p.extension().and_then(|e| e.to_str()) == Some("SC2Replay")
+            });
+            replays.extend(find_replays(&entries)?);
+        } else {
+            replays.push(path.clone());
+        }
+    }
+    Ok(replays)
+}
+
+fn format_benchmark_text(report: &BenchmarkReport) -> String {
+    let mut text = String::new();
+    for error in report.errors.iter() {
+        text += &format!("error: {}\n", error);
+    }
+    let decided = report.engagements.iter().filter(|e| e.winner != 0).count();
+    text += &format!(
+        "{} replays, {} engagements ({} with a winner), {} skipped\n",
+        report.replays,
+        report.engagements.len(),
+        decided,
+        report.skipped
+    );
+    text += &format!("winner accuracy: {:.1}%\n", report.accuracy * 100.0);
+    text += &format!("health left error: {:.3}\n", report.health_left_error);
+    text
+}
+
+fn run_benchmark(args: &Args, paths: &[PathBuf], min_deaths: u32) -> Result<String, String> {
+    let replays = find_replays(paths)?;
+    let options = EngagementOptions {
+        min_deaths,
+        ..Default::default()
+    };
+    let report = benchmark::benchmark(
+        &CombatPredictor::new(),
+        &replays,
+        &CombatSettings::new(),
+        &options,
+    );
+    match args.format.unwrap_or(Format::Text) {
+        Format::Text => Ok(format_benchmark_text(&report)),
+        Format::Csv => Ok(benchmark::to_csv(&report.engagements)),
+        Format::Json => serde_json::to_string_pretty(&report).map_err(|e| e.to_string()),
+        Format::Toml => toml::to_string(&report).map_err(|e| e.to_string()),
+    }
+}
+
+fn format_calibration_text(result: &CalibrationResult) -> String {
+    let mut text = format!(
+        "accuracy: {:.1}% -> {:.1}%\nhealth left error: {:.3} -> {:.3}\n{} evaluations\n\n",
+        result.initial_accuracy * 100.0,
+        result.accuracy * 100.0,
+        result.initial_health_left_error,
+        result.health_left_error,
+        result.evaluations
+    );
+    // Ready to paste into a scenario file
+    text += "[settings.params]\n";
+    for (name, ..) in calibration::PARAMS {
+        text += &format!(
+            "{} = {:?}\n",
+            name,
+            calibration::param(&result.params, name)
+        );
+    }
+    text
+}
+
+fn run_calibration(
+    args: &Args,
+    paths: &[PathBuf],
+    params: &[String],
+    iterations: u32,
+) -> Result<String, String> {
+    let mut engagements = vec![];
+    for path in paths {
+        let dataset: Dataset = read_file(path)?;
+        engagements.extend(dataset.engagements);
+    }
+    let result = calibration::calibrate(
+        &CombatPredictor::new(),
+        &engagements,
+        &Default::default(),
+        params,
+        iterations,
+    )?;
+    match args.format.unwrap_or(Format::Text) {
+        Format::Text => Ok(format_calibration_text(&result)),
+        Format::Csv => {
+            let mut csv = String::from("param,value\n");
+            for (name, ..) in calibration::PARAMS {
+                csv += &format!("{},{:?}\n", name, calibration::param(&result.params, name));
+            }
+            Ok(csv)
+        }
+        Format::Json => serde_json::to_string_pretty(&result).map_err(|e| e.to_string()),
+        Format::Toml => toml::to_string(&result).map_err(|e| e.to_string()),
+    }
+}
+
+fn run(args: &Args) -> Result<String, String> {
+    match &args.command {
+        Some(Command::Matchups {
+            units,
+            upgrades,
+            resources,
+        }) => return run_matchups(args, units, upgrades, *resources),
+        Some(Command::Benchmark {
+            replays,
+            min_deaths,
+        }) => return run_benchmark(args, replays, *min_deaths),
+        Some(Command::Calibrate {
+            datasets,
+            params,
+            iterations,
+        }) => return run_calibration(args, datasets, params, *iterations),
+        None => {}
+    }
+    let mut predictor = CombatPredictor::new();
+    let mut reports: Vec<ScenarioReport> = vec![];
+    for path in args.files.iter() {
+        for scenario in read_scenarios(path)? {
+            let report = scenario
+                .run(&predictor)
+                .map_err(|e| format!("{}: {}", scenario.name, e))?;
+            reports.push(report);
+        }
+    }
+    match args.format.unwrap_or(Format::Text) {
+        Format::Text => Ok(format_text(&reports)),
+        Format::Csv => Ok(format_csv(&reports)),
+        Format::Json => {
+            serde_json::to_string_pretty(&Report { reports: &reports }).map_err(|e| e.to_string())
+        }
+        Format::Toml => toml::to_string(&Report { reports: &reports }).map_err(|e| e.to_string()),
+    }
+}
+
+fn main() {
+    let args = Args::parse();
+    let result = run(&args).and_then(|report| match &args.output {
+        Some(path) => fs::write(path, report).map_err(|e| format!("{}: {}", path.display(), e)),
+        None => {
+            print!("{}", report);
+            Ok(())
+        }
+    });
+    if let Err(e) = result {
+        eprintln!("sc2-sim: {}", e);
+        process::exit(1);
+    }
+}
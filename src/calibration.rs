@@ -0,0 +1,244 @@
+use crate::combat_predictor::{CombatModelParams, CombatPredictor};
+use crate::combat_unit::CombatUnit;
+use crate::conversion;
+use crate::scenario::Scenario;
+use pyo3::prelude::*;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// An engagement with a known outcome, e.g. from a replay or a custom game.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RecordedEngagement {
+    #[serde(flatten)]
+    pub scenario: Scenario,
+    /// Side that won, 1 or 2.
+    pub winner: u32,
+    /// Fraction of health + shield each side had left. Breaks ties between parameters with the same
+    /// accuracy if given.
+    #[serde(default)]
+    pub health_left: Option<Vec<f32>>,
+}
+
+/// Recorded engagements, as loaded from a JSON or TOML file.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Dataset {
+    pub engagements: Vec<RecordedEngagement>,
+}
+
+/// Calibrated parameters and the range they are searched in.
+pub const PARAMS: &[(&str, f32, f32)] = &[
+    ("healing_per_second", 0.0, 20.0),
+    ("melee_target_bonus", 0.0, 2000.0),
+    ("slow_target_bonus", 0.0, 2000.0),
+    ("outranged_target_penalty", 0.0, 2000.0),
+    ("high_melee_fraction", 0.0, 1.0),
+    ("low_melee_fraction", 0.0, 1.0),
+    ("surround_packing", 0.2, 1.0),
+    ("zealot_radius", 0.25, 1.5),
+    ("vespene_multiplier", 0.5, 3.0),
+];
+
+fn param_mut<'a>(params: &'a mut CombatModelParams, name: &str) -> &'a mut f32 {
+    match name {
+        "healing_per_second" => &mut params.healing_per_second,
+        "melee_target_bonus" => &mut params.melee_target_bonus,
+        "slow_target_bonus" => &mut params.slow_target_bonus,
+        "outranged_target_penalty" => &mut params.outranged_target_penalty,
+        "high_melee_fraction" => &mut params.high_melee_fraction,
+        "low_melee_fraction" => &mut params.low_melee_fraction,
+        "surround_packing" => &mut params.surround_packing,
+        "zealot_radius" => &mut params.zealot_radius,
+        "vespene_multiplier" => &mut params.vespene_multiplier,
+        _ => unreachable!("Unknown parameter {}", name),
+    }
+}
+
+/// Value of the parameter `name` of `PARAMS`.
+pub fn param(params: &CombatModelParams, name: &str) -> f32 {
+    *param_mut(&mut params.clone(), name)
+}
+
+/// Parameters fitted by `calibrate` and how well they predict the dataset.
+#[pyclass(module = "sc2_helper.sc2_helper")]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CalibrationResult {
+    #[pyo3(get)]
+    pub params: CombatModelParams,
+    /// Fraction of the engagements whose winner is predicted right.
+    #[pyo3(get)]
+    pub accuracy: f32,
+    /// Accuracy of the parameters calibration started with.
+    #[pyo3(get)]
+    pub initial_accuracy: f32,
+    /// Mean absolute error of the predicted fraction of health left, over the engagements that have one.
+    #[pyo3(get)]
+    pub health_left_error: f32,
+    #[pyo3(get)]
+    pub initial_health_left_error: f32,
+    /// Times the dataset was predicted.
+    #[pyo3(get)]
+    pub evaluations: u32,
+}
+
+#[pymethods]
+impl CalibrationResult {
+    /// JSON representation, also used to pickle it.
+    pub fn to_json(&self) -> PyResult<String> {
+        conversion::to_json(self)
+    }
+
+    #[staticmethod]
+    pub fn from_json(json: &str) -> PyResult<Self> {
+        conversion::from_json(json)
+    }
+
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (String,))> {
+        conversion::reduce_json::<Self, _>(py, self)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+/// A recorded engagement with its armies built.
+struct Sample<'a> {
+    engagement: &'a RecordedEngagement,
+    units1: Vec<CombatUnit>,
+    units2: Vec<CombatUnit>,
+    health_start: [f32; 2],
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Score {
+    accuracy: f32,
+    health_left_error: f32,
+}
+
+impl Score {
+    fn is_better_than(&self, other: &Score) -> bool {
+        const EPSILON: f32 = 1e-6;
+        self.accuracy > other.accuracy + EPSILON
+            || (self.accuracy > other.accuracy - EPSILON
+                && self.health_left_error < other.health_left_error - EPSILON)
+    }
+}
+
+fn evaluate(predictor: &CombatPredictor, samples: &[Sample], params: &CombatModelParams) -> Score {
+    let results: Vec<(bool, Option<f32>)> = samples
+        .par_iter()
+        .map(|sample| {
+            let scenario = &sample.engagement.scenario;
+            let mut settings = scenario.settings.clone();
+            settings.params = params.clone();
+            let result = predictor.clone()._predict_engage(
+                sample.units1.clone(),
+                sample.units2.clone(),
+                vec![],
+                vec![],
+                scenario.defender_player,
+                &settings,
+            );
+            let error = sample.engagement.health_left.as_ref().map(|health_left| {
+                (0..2)
+                    .map(|i| {
+                        (result.health_left[i] / sample.health_start[i] - health_left[i]).abs()
+                    })
+                    .sum::<f32>()
+                    / 2.0
+            });
+            (result.winner == sample.engagement.winner, error)
+        })
+        .collect();
+    let errors: Vec<f32> = results.iter().filter_map(|r| r.1).collect();
+    Score {
+        accuracy: results.iter().filter(|r| r.0).count() as f32 / results.len().max(1) as f32,
+        health_left_error: errors.iter().sum::<f32>() / errors.len().max(1) as f32,
+    }
+}
+
+/// Fits the parameters named in `names`, or all of `PARAMS` if empty, to `engagements` by coordinate
+/// search starting at `params`. Each iteration tries a step up and down for every parameter and keeps
+/// the first change that predicts more winners right, or the same winners with a lower health left
+/// error. The steps are halved when nothing improves.
+pub fn calibrate(
+    predictor: &CombatPredictor,
+    engagements: &[RecordedEngagement],
+    params: &CombatModelParams,
+    names: &[String],
+    iterations: u32,
+) -> Result<CalibrationResult, String> {
+    if engagements.is_empty() {
+        return Err("No recorded engagements".to_string());
+    }
+    let mut searched: Vec<(&str, f32, f32, f32)> = vec![];
+    for &(name, min, max) in PARAMS {
+        if names.is_empty() || names.iter().any(|n| n == name) {
+            searched.push((name, min, max, (max - min) / 4.0));
+        }
+    }
+    if let Some(name) = names.iter().find(|n| !PARAMS.iter().any(|(p, ..)| p == n)) {
+        return Err(format!("Unknown parameter {}", name));
+    }
+    let samples = engagements
+        .iter()
+        .map(|engagement| {
+            let (units1, units2) = engagement
+                .scenario
+                .armies()
+                .map_err(|e| format!("{}: {}", engagement.scenario.name, e))?;
+            let health = |units: &[CombatUnit]| -> f32 {
+                units
+                    .iter()
+                    .map(|u| u.health + u.shield)
+                    .sum::<f32>()
+                    .max(1.0)
+            };
+            let health_start = [health(&units1), health(&units2)];
+            Ok(Sample {
+                engagement,
+                units1,
+                units2,
+                health_start,
+            })
+        })
+        .collect::<Result<Vec<Sample>, String>>()?;
+
+    let mut best = params.clone();
+    let initial = evaluate(predictor, &samples, &best);
+    let mut best_score = initial;
+    let mut evaluations = 1;
+    for _ in 0..iterations {
+        let mut improved = false;
+        for (name, min, max, step) in searched.iter_mut() {
+            let current = *param_mut(&mut best, name);
+            for direction in [1.0, -1.0].iter() {
+                let value = (current + direction * *step).clamp(*min, *max);
+                if value == current {
+                    continue;
+                }
+                let mut candidate = best.clone();
+                *param_mut(&mut candidate, name) = value;
+                let score = evaluate(predictor, &samples, &candidate);
+                evaluations += 1;
+                if score.is_better_than(&best_score) {
+                    best = candidate;
+                    best_score = score;
+                    improved = true;
+                    break;
+                }
+            }
+        }
+        if !improved {
+            searched.iter_mut().for_each(|p| p.3 /= 2.0);
+        }
+    }
+    Ok(CalibrationResult {
+        params: best,
+        accuracy: best_score.accuracy,
+        initial_accuracy: initial.accuracy,
+        health_left_error: best_score.health_left_error,
+        initial_health_left_error: initial.health_left_error,
+        evaluations,
+    })
+}
@@ -0,0 +1,204 @@
+//! Groups units into armies to pick engagements for the combat predictor.
+use crate::combat_unit::{CombatUnit, UnitCache};
+use crate::conversion;
+use crate::influence::extract_positioned;
+use crate::spatial::{IndexedUnit, SpatialIndex};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Indices of two clusters, of the own and the enemy units, and the gap between them.
+pub type ClusterPair = (usize, usize, f32);
+
+/// Units close to each other, found by `cluster_units`.
+#[pyclass(module = "sc2_helper.sc2_helper")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UnitCluster {
+    /// Indices of the units in the list passed to `cluster_units`.
+    #[pyo3(get)]
+    pub indices: Vec<usize>,
+    #[pyo3(get)]
+    pub tags: Vec<u64>,
+    /// Mean position of the units.
+    #[pyo3(get)]
+    pub center: (f32, f32),
+    /// Distance from `center` to the edge of the furthest unit.
+    #[pyo3(get)]
+    pub radius: f32,
+    /// Minerals + 1.5 * vespene of all units.
+    #[pyo3(get)]
+    pub adjusted_cost: i32,
+    #[pyo3(get)]
+    pub supply: f32,
+    #[pyo3(get)]
+    pub ground_units: u32,
+    #[pyo3(get)]
+    pub air_units: u32,
+    /// Summed DPS of the units against ground targets.
+    #[pyo3(get)]
+    pub ground_dps: f32,
+    /// Summed DPS of the units against air targets.
+    #[pyo3(get)]
+    pub air_dps: f32,
+}
+
+#[pymethods]
+impl UnitCluster {
+    /// JSON representation, also used to pickle it.
+    pub fn to_json(&self) -> PyResult<String> {
+        conversion::to_json(self)
+    }
+
+    #[staticmethod]
+    pub fn from_json(json: &str) -> PyResult<Self> {
+        conversion::from_json(json)
+    }
+
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (String,))> {
+        conversion::reduce_json::<Self, _>(py, self)
+    }
+
+    fn __len__(&self) -> usize {
+        self.indices.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl UnitCluster {
+    fn new(units: &[(CombatUnit, (f32, f32))], indices: Vec<usize>) -> Self {
+        let count = indices.len().max(1) as f32;
+        let mut cluster = UnitCluster::default();
+        for &i in indices.iter() {
+            let (unit, position) = &units[i];
+            cluster.center.0 += position.0 / count;
+            cluster.center.1 += position.1 / count;
+            cluster.tags.push(unit.tag);
+            cluster.adjusted_cost += unit.get_adjusted_cost();
+            cluster.supply += unit.get_supply_cost();
+            if unit.is_flying {
+                cluster.air_units += 1;
+            } else {
+                cluster.ground_units += 1;
+            }
+            cluster.ground_dps += unit.ground_dps;
+            cluster.air_dps += unit.air_dps;
+        }
+        cluster.radius = indices
+            .iter()
+            .map(|&i| {
+                let (unit, position) = &units[i];
+                let dx = position.0 - cluster.center.0;
+                let dy = position.1 - cluster.center.1;
+                (dx * dx + dy * dy).sqrt() + unit.radius
+            })
+            .fold(0.0, f32::max);
+        cluster.indices = indices;
+        cluster
+    }
+
+    /// Distance between the edges of the two clusters, 0.0 if they overlap.
+    pub fn gap(&self, other: &UnitCluster) -> f32 {
+        let dx = self.center.0 - other.center.0;
+        let dy = self.center.1 - other.center.1;
+        ((dx * dx + dy * dy).sqrt() - self.radius - other.radius).max(0.0)
+    }
+}
+
+/// DBSCAN clustering of `units` at their positions: units with at least `min_samples` units, themselves
+/// included, closer than `distance` are cores, clusters are the units reachable from them. Other units are
+/// left out. With `min_samples` 1 every unit is in the cluster of its connected component. Largest
+/// clusters first.
+pub fn cluster(
+    units: &[(CombatUnit, (f32, f32))],
+    distance: f32,
+    min_samples: usize,
+) -> Result<Vec<UnitCluster>, String> {
+    let index = SpatialIndex::new(
+        units
+            .iter()
+            .enumerate()
+            .map(|(i, (unit, position))| IndexedUnit {
+                tag: i as u64,
+                position: *position,
+                radius: unit.radius,
+                is_flying: unit.is_flying,
+            })
+            .collect(),
+        1.0f32.max(distance),
+    )?;
+    let neighbours = |i: usize| -> Vec<u64> { index.closer_than(units[i].1, distance, false) };
+    let mut assigned = vec![false; units.len()];
+    let mut clusters = vec![];
+    for start in 0..units.len() {
+        if assigned[start] || neighbours(start).len() < min_samples {
+            continue;
+        }
+        assigned[start] = true;
+        let mut members = vec![start];
+        let mut open = vec![start];
+        while let Some(i) = open.pop() {
+            let found = neighbours(i);
+            if found.len() < min_samples {
+                continue;
+            }
+            for j in found.into_iter().map(|j| j as usize) {
+                if !assigned[j] {
+                    assigned[j] = true;
+                    members.push(j);
+                    open.push(j);
+                }
+            }
+        }
+        members.sort_unstable();
+        clusters.push(UnitCluster::new(units, members));
+    }
+    clusters.sort_by_key(|c| std::cmp::Reverse(c.indices.len()));
+    Ok(clusters)
+}
+
+/// Pairs of own and enemy clusters whose edges are at most `max_distance` apart, closest first.
+pub fn pair(own: &[UnitCluster], enemy: &[UnitCluster], max_distance: f32) -> Vec<ClusterPair> {
+    let mut pairs: Vec<ClusterPair> = own
+        .iter()
+        .enumerate()
+        .flat_map(|(i, a)| enemy.iter().enumerate().map(move |(j, b)| (i, j, a.gap(b))))
+        .filter(|&(_, _, gap)| gap <= max_distance)
+        .collect();
+    pairs.sort_by(|a, b| a.2.total_cmp(&b.2).then((a.0, a.1).cmp(&(b.0, b.1))));
+    pairs
+}
+
+/// Splits python-sc2 `Unit`s or `(CombatUnit, position)` tuples into clusters of units closer than
+/// `distance` to each other, see `UnitCluster`. Units without `min_samples` units nearby that aren't close
+/// to a cluster are left out.
+#[pyfunction]
+#[pyo3(signature = (units, distance = 8.0, min_samples = 1))]
+pub fn cluster_units(
+    py: Python<'_>,
+    units: Vec<&PyAny>,
+    distance: f32,
+    min_samples: usize,
+) -> PyResult<Vec<UnitCluster>> {
+    let mut cache = UnitCache::new();
+    let units = units
+        .into_iter()
+        .map(|obj| extract_positioned(obj, &mut cache))
+        .collect::<PyResult<Vec<_>>>()?;
+    py.allow_threads(|| cluster(&units, distance, min_samples))
+        .map_err(PyValueError::new_err)
+}
+
+/// Candidate engagements: `(own, enemy, gap)` indices of `own` and `enemy` clusters whose edges are at most
+/// `max_distance` apart, closest first.
+#[pyfunction]
+#[pyo3(signature = (own, enemy, max_distance = 15.0))]
+pub fn pair_clusters(
+    own: Vec<UnitCluster>,
+    enemy: Vec<UnitCluster>,
+    max_distance: f32,
+) -> Vec<ClusterPair> {
+    pair(&own, &enemy, max_distance)
+}
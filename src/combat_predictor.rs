@@ -1,12 +1,45 @@
-use crate::combat_unit::CombatUnit;
+#[cfg(feature = "replay")]
+use crate::benchmark::{self, BenchmarkReport};
+use crate::calibration::{self, CalibrationResult, Dataset};
+use crate::combat_unit::{CombatUnit, UnitCache};
+use crate::composition::{self, Budget, Composition, ForceRequirement};
+use crate::conversion;
 use crate::generated_enums::UnitTypeId;
+use crate::matchup::{self, Matchup};
+#[cfg(feature = "replay")]
+use crate::replay::EngagementOptions;
+use crate::terrain::Terrain;
+use crate::trace::CombatTrace;
+use crate::unit_database;
 use crate::weapon::Weapon;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use pyo3::types::PyTuple;
+use pyo3::AsPyPointer;
 use rand::seq::SliceRandom;
 use rand::thread_rng;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
 use std::borrow::BorrowMut;
+use std::collections::HashMap;
 use std::f32::consts::PI;
 use std::f32::EPSILON;
+#[cfg(feature = "replay")]
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use tracing::{debug, debug_span, trace, warn, Span};
+
+/// Span for a phase of the simulation. Only created when `CombatSettings::debug` is set, so diagnostics
+/// cost nothing otherwise.
+macro_rules! phase_span {
+    ($debug:expr, $($span:tt)+) => {
+        if $debug {
+            debug_span!($($span)+)
+        } else {
+            Span::none()
+        }
+    };
+}
 
 #[derive(Clone, Copy)]
 pub struct SurroundInfo {
@@ -17,15 +50,21 @@ pub struct SurroundInfo {
 pub fn max_surround(
     mut enemy_ground_unit_area: f32,
     enemy_ground_units: i32,
-    zealot_radius: f32,
+    params: &CombatModelParams,
+    choke_width: f32,
 ) -> SurroundInfo {
     if enemy_ground_units > 0 {
-        enemy_ground_unit_area /= 0.6;
+        enemy_ground_unit_area /= params.surround_packing;
     }
     let radius: f32 = (enemy_ground_unit_area / PI).sqrt();
-    let representative_melee_unit_radius = zealot_radius;
-    let circumference_defenders: f32 = radius * (2.0 * PI);
-    let circumference_attackers: f32 = (radius + representative_melee_unit_radius) * (2.0 * PI);
+    let representative_melee_unit_radius = params.zealot_radius;
+    let mut circumference_defenders: f32 = radius * (2.0 * PI);
+    let mut circumference_attackers: f32 = (radius + representative_melee_unit_radius) * (2.0 * PI);
+    if choke_width > 0.0 {
+        // Only the front of the army that fits through the choke can be reached
+        circumference_defenders = circumference_defenders.min(choke_width);
+        circumference_attackers = circumference_attackers.min(choke_width);
+    }
     let approximate_defenders_in_melee_range: f32;
     let value1: f32 = circumference_defenders / (2.0 * representative_melee_unit_radius);
     if value1 < enemy_ground_units as f32 {
@@ -48,8 +87,87 @@ pub fn max_surround(
     }
 }
 
-#[pyclass]
-#[derive(Clone, Debug)]
+/// Constants of the heuristics the simulation uses. The defaults are hand-tuned, `calibration` fits them
+/// to recorded engagements.
+#[pyclass(module = "sc2_helper.sc2_helper")]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(default)]
+pub struct CombatModelParams {
+    /// Health per second a medivac heals.
+    #[pyo3(get, set)]
+    pub healing_per_second: f32,
+    /// Target score bonus of melee units for melee targets, which block them otherwise.
+    #[pyo3(get, set)]
+    pub melee_target_bonus: f32,
+    /// Target score bonus of melee units for targets that can't outrun them.
+    #[pyo3(get, set)]
+    pub slow_target_bonus: f32,
+    /// Target score penalty of ranged ground units for targets with more range, when the opponent has
+    /// many melee units to tank for them.
+    #[pyo3(get, set)]
+    pub outranged_target_penalty: f32,
+    /// Fraction of melee units above which targets with 0.5 more range are avoided.
+    #[pyo3(get, set)]
+    pub high_melee_fraction: f32,
+    /// Fraction of melee units above which targets with 1.0 more range are avoided.
+    #[pyo3(get, set)]
+    pub low_melee_fraction: f32,
+    /// Fraction of the area of an army covered by its units, used for surround limits.
+    #[pyo3(get, set)]
+    pub surround_packing: f32,
+    /// Radius of the melee unit surround limits assume.
+    #[pyo3(get, set)]
+    pub zealot_radius: f32,
+    /// Minerals a vespene is worth when valuing armies and targets.
+    #[pyo3(get, set)]
+    pub vespene_multiplier: f32,
+}
+
+#[pymethods]
+impl CombatModelParams {
+    #[new]
+    pub fn new() -> Self {
+        CombatModelParams {
+            healing_per_second: 12.6 / 1.4,
+            melee_target_bonus: 1000.0,
+            slow_target_bonus: 500.0,
+            outranged_target_penalty: 1000.0,
+            high_melee_fraction: 0.5,
+            low_melee_fraction: 0.3,
+            surround_packing: 0.6,
+            zealot_radius: 0.5,
+            vespene_multiplier: 1.5,
+        }
+    }
+
+    /// JSON representation, also used to pickle it.
+    pub fn to_json(&self) -> PyResult<String> {
+        conversion::to_json(self)
+    }
+
+    #[staticmethod]
+    pub fn from_json(json: &str) -> PyResult<Self> {
+        conversion::from_json(json)
+    }
+
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (String,))> {
+        conversion::reduce_json::<Self, _>(py, self)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl Default for CombatModelParams {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[pyclass(module = "sc2_helper.sc2_helper")]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default)]
 pub struct CombatSettings {
     #[pyo3(get, set)]
     pub bad_micro: bool,
@@ -73,6 +191,35 @@ pub struct CombatSettings {
     pub start_time: f32,
     #[pyo3(get, set)]
     pub multi_threaded: bool,
+    /// Fraction of the starting army (0.0 - 1.0) a side may lose before it retreats. 0.0 disables retreating.
+    #[pyo3(get, set)]
+    pub retreat_threshold: f32,
+    /// Measure losses in supply instead of adjusted resource cost.
+    #[pyo3(get, set)]
+    pub retreat_by_supply: bool,
+    /// Player allowed to retreat. 0 == both players.
+    #[pyo3(get, set)]
+    pub retreat_player: u32,
+    /// Seconds the winning side keeps chasing a retreating army.
+    #[pyo3(get, set)]
+    pub chase_time: f32,
+    /// Stop the simulation once a side has less than this fraction of its health + shields left,
+    /// the winner is then decided on the health left at that time. 0.0 disables it.
+    #[pyo3(get, set)]
+    pub early_out_health_fraction: f32,
+    /// Side whose health is checked against `early_out_health_fraction`. 0 == every side.
+    #[pyo3(get, set)]
+    pub early_out_player: u32,
+    /// Record the state of every unit each iteration in `CombatResult.trace`. Slow.
+    #[pyo3(get, set)]
+    pub trace: bool,
+    /// Constants of the simulation heuristics. Python gets a copy, assign it back after changing it.
+    #[pyo3(get, set)]
+    pub params: CombatModelParams,
+    /// High ground, choke and creep around the engagement. Python gets a copy, assign it back after
+    /// changing it.
+    #[pyo3(get, set)]
+    pub terrain: Terrain,
 }
 
 #[pymethods]
@@ -91,35 +238,669 @@ impl CombatSettings {
             max_time: 100_000.0,
             start_time: 0.0,
             multi_threaded: false,
+            retreat_threshold: 0.0,
+            retreat_by_supply: false,
+            retreat_player: 0,
+            chase_time: 5.0,
+            early_out_health_fraction: 0.0,
+            early_out_player: 0,
+            trace: false,
+            params: CombatModelParams::new(),
+            terrain: Terrain::new(),
         }
     }
+
+    /// JSON representation, also used to pickle it.
+    pub fn to_json(&self) -> PyResult<String> {
+        conversion::to_json(self)
+    }
+
+    #[staticmethod]
+    pub fn from_json(json: &str) -> PyResult<Self> {
+        conversion::from_json(json)
+    }
+
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (String,))> {
+        conversion::reduce_json::<Self, _>(py, self)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl Default for CombatSettings {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[pyclass(module = "sc2_helper.sc2_helper")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CombatResult {
+    /// Winning player (side), starting at 1. The side with the most health left of the winning team.
+    #[pyo3(get)]
+    pub winner: u32,
+    /// Winning team. Equal to `winner` for two player engagements.
+    #[pyo3(get)]
+    pub winning_team: u32,
+    /// Health + shield left per side.
+    #[pyo3(get)]
+    pub health_left: Vec<f32>,
+    /// Sides that retreated, in the order they retreated.
+    #[pyo3(get)]
+    pub retreated: Vec<u32>,
+    /// Time at which the engagement ended or the retreat started.
+    #[pyo3(get)]
+    pub time: f32,
+    /// Unit states per iteration, only recorded if `CombatSettings.trace` is enabled.
+    #[pyo3(get)]
+    pub trace: Option<CombatTrace>,
+    /// Units per side in reinforcement waves that never joined, because the engagement ended before they
+    /// arrived or after `max_time`. They are not part of `health_left`. Waves of a side that retreats are
+    /// called off and not counted.
+    #[pyo3(get)]
+    #[serde(default)]
+    pub pending_reinforcements: Vec<u32>,
+}
+
+#[pymethods]
+impl CombatResult {
+    /// JSON representation, also used to pickle it.
+    pub fn to_json(&self) -> PyResult<String> {
+        conversion::to_json(self)
+    }
+
+    #[staticmethod]
+    pub fn from_json(json: &str) -> PyResult<Self> {
+        conversion::from_json(json)
+    }
+
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (String,))> {
+        conversion::reduce_json::<Self, _>(py, self)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+/// Units joining the engagement `.0` seconds after it started.
+pub type ReinforcementWave = (f32, Vec<CombatUnit>);
+/// `ReinforcementWave` before its units are converted.
+type PyReinforcementWave<'a> = (f32, Vec<&'a PyAny>);
+
+#[pyclass(module = "sc2_helper.sc2_helper")]
+#[derive(Clone)]
+pub struct CombatPredictor {
+    /// Static data of the units converted by this predictor. Shared with its clones.
+    pub(crate) cache: Arc<Mutex<UnitCache>>,
+    /// Key of the game the cache was filled in, see `start_game`.
+    game_key: Arc<Mutex<String>>,
+}
+
+/// Hostile units pooled as targets in `_predict_engage_multi`, with the state kept per target.
+#[derive(Default)]
+struct Targets {
+    units: Vec<CombatUnit>,
+    /// Side each target belongs to.
+    owners: Vec<usize>,
+    /// Targets ranged ground units can't shoot, see `Terrain::high_ground_player`.
+    hidden: Vec<bool>,
+    /// Melee units attacking each target in the current iteration.
+    melee_attack_count: Vec<i32>,
 }
 
+impl Targets {
+    /// Removes a dead target from every list, so they stay aligned.
+    fn swap_remove(&mut self, index: usize) {
+        self.units.swap_remove(index);
+        self.owners.swap_remove(index);
+        self.hidden.swap_remove(index);
+        self.melee_attack_count.swap_remove(index);
+    }
+}
+
+/// Resolves an `asyncio.Future` with the outcome of a prediction. Scheduled with `call_soon_threadsafe`,
+/// so the future is only touched from its event loop.
 #[pyclass]
-pub struct CombatPredictor {}
+struct FutureResolver {
+    future: PyObject,
+    outcome: Option<PyResult<PyObject>>,
+}
+
+#[pymethods]
+impl FutureResolver {
+    fn __call__(&mut self, py: Python<'_>) -> PyResult<()> {
+        // The future may have been cancelled while the prediction was running
+        if self.future.call_method0(py, "done")?.is_true(py)? {
+            return Ok(());
+        }
+        match self.outcome.take() {
+            Some(Ok(result)) => self.future.call_method1(py, "set_result", (result,))?,
+            Some(Err(e)) => self.future.call_method1(py, "set_exception", (e,))?,
+            None => return Ok(()),
+        };
+        Ok(())
+    }
+}
 
 #[pymethods]
 impl CombatPredictor {
     #[new]
     pub fn new() -> Self {
-        CombatPredictor {}
+        CombatPredictor {
+            cache: Arc::new(Mutex::new(UnitCache::with_capacity(100))),
+            game_key: Arc::new(Mutex::new(String::new())),
+        }
+    }
+
+    /// Forgets the static data of converted units, see `start_game`.
+    pub fn clear_cache(&self) {
+        self.cache.lock().unwrap().clear();
+    }
+
+    /// Call at the start of every game. Clears the unit cache unless `game_key` is the key of the previous
+    /// game, e.g. the game version when unit stats can only change between versions. An empty key always
+    /// clears it.
+    #[pyo3(signature = (game_key = ""))]
+    pub fn start_game(&self, game_key: &str) {
+        let mut current = self.game_key.lock().unwrap();
+        if game_key.is_empty() || *current != game_key {
+            self.clear_cache();
+            *current = game_key.to_string();
+        }
+    }
+
+    /// Pickles as a new predictor, the unit cache is not part of its state.
+    fn __reduce__(&self, py: Python<'_>) -> (PyObject, Py<PyTuple>) {
+        (py.get_type::<Self>().into(), PyTuple::empty(py).into())
+    }
+
+    /// Number of unit types, per upgrade state, in the cache.
+    #[getter]
+    pub fn cache_size(&self) -> usize {
+        self.cache.lock().unwrap().len()
+    }
+
+    #[pyo3(name = "predict_engage")]
+    pub fn py_predict_engage(
+        &self,
+        py: Python<'_>,
+        units1: Vec<&PyAny>,
+        units2: Vec<&PyAny>,
+        defender_player: u32,
+        settings: &CombatSettings,
+    ) -> PyResult<(u32, f32)> {
+        let units1 = self.convert_units(units1)?;
+        let units2 = self.convert_units(units2)?;
+        py.allow_threads(|| self.predict_engage(units1, units2, defender_player, settings))
+    }
+
+    /// Same as `predict_engage`, but returns the full `CombatResult`, including retreats.
+    #[pyo3(name = "predict_engage_result")]
+    pub fn py_predict_engage_result(
+        &self,
+        py: Python<'_>,
+        units1: Vec<&PyAny>,
+        units2: Vec<&PyAny>,
+        defender_player: u32,
+        settings: &CombatSettings,
+    ) -> PyResult<CombatResult> {
+        let units1 = self.convert_units(units1)?;
+        let units2 = self.convert_units(units2)?;
+        py.allow_threads(|| self.predict_engage_result(units1, units2, defender_player, settings))
+    }
+
+    /// Same as `predict_engage_result`, but runs the simulation on the rayon thread pool and returns an
+    /// `asyncio.Future` resolving to the `CombatResult`. Has to be called from a running event loop.
+    /// Like the other predictions, a panic in the simulation aborts the interpreter.
+    pub fn predict_engage_async(
+        &self,
+        py: Python<'_>,
+        units1: Vec<&PyAny>,
+        units2: Vec<&PyAny>,
+        defender_player: u32,
+        settings: &CombatSettings,
+    ) -> PyResult<PyObject> {
+        let units1 = self.convert_units(units1)?;
+        let units2 = self.convert_units(units2)?;
+        let event_loop: PyObject = py
+            .import("asyncio")?
+            .call_method0("get_running_loop")?
+            .into();
+        let future: PyObject = event_loop.call_method0(py, "create_future")?;
+        let resolved_future = future.clone_ref(py);
+        let predictor = self.clone();
+        let settings = settings.clone();
+        rayon::spawn(move || {
+            let result =
+                predictor.predict_engage_result(units1, units2, defender_player, &settings);
+            Python::with_gil(|py| {
+                let resolver = FutureResolver {
+                    future: resolved_future,
+                    outcome: Some(result.map(|r| r.into_py(py))),
+                };
+                // Fails if the event loop was closed in the meantime, nobody is waiting then
+                if let Err(e) = event_loop.call_method1(py, "call_soon_threadsafe", (resolver,)) {
+                    warn!("Could not resolve prediction future: {}", e);
+                }
+            });
+        });
+        Ok(future)
+    }
+
+    /// Predicts many engagements at once, e.g. every own army cluster against every nearby enemy cluster.
+    /// `pairs` holds `(units1, units2)` tuples. Units are converted once, even if they appear in several
+    /// pairs, and the simulations run in parallel. Returns one `CombatResult` per pair.
+    #[pyo3(signature = (pairs, settings, defender_player = 0))]
+    pub fn predict_engage_batch(
+        &self,
+        py: Python<'_>,
+        pairs: Vec<(Vec<&PyAny>, Vec<&PyAny>)>,
+        settings: &CombatSettings,
+        defender_player: u32,
+    ) -> PyResult<Vec<CombatResult>> {
+        let mut batch: Vec<(Vec<CombatUnit>, Vec<CombatUnit>)> = Vec::with_capacity(pairs.len());
+        {
+            let mut cache = self.cache.lock().unwrap();
+            // Keyed by the address of the python object, which stays alive until the conversion is done
+            let mut converted: HashMap<usize, CombatUnit> = HashMap::new();
+            let mut convert = |units: Vec<&PyAny>| -> PyResult<Vec<CombatUnit>> {
+                units
+                    .into_iter()
+                    .map(|obj| {
+                        if let Some(unit) = converted.get(&(obj.as_ptr() as usize)) {
+                            return Ok(unit.clone());
+                        }
+                        let unit = CombatUnit::extract_cached(obj, &mut cache)?;
+                        converted.insert(obj.as_ptr() as usize, unit.clone());
+                        Ok(unit)
+                    })
+                    .collect()
+            };
+            for (units1, units2) in pairs {
+                batch.push((convert(units1)?, convert(units2)?));
+            }
+        }
+        let settings = settings.clone();
+        Ok(py.allow_threads(|| self.predict_engage_pairs(batch, defender_player, &settings)))
+    }
+
+    /// Predict an engagement where each side receives reinforcement waves of `(arrival_time, units)`.
+    /// Arrival times are seconds after the start of the engagement.
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(name = "predict_engage_with_reinforcements")]
+    pub fn py_predict_engage_with_reinforcements(
+        &self,
+        py: Python<'_>,
+        units1: Vec<&PyAny>,
+        units2: Vec<&PyAny>,
+        reinforcements1: Vec<PyReinforcementWave>,
+        reinforcements2: Vec<PyReinforcementWave>,
+        defender_player: u32,
+        settings: &CombatSettings,
+    ) -> PyResult<CombatResult> {
+        let units1 = self.convert_units(units1)?;
+        let units2 = self.convert_units(units2)?;
+        let reinforcements1 = self.convert_waves(reinforcements1)?;
+        let reinforcements2 = self.convert_waves(reinforcements2)?;
+        py.allow_threads(|| {
+            self.predict_engage_with_reinforcements(
+                units1,
+                units2,
+                reinforcements1,
+                reinforcements2,
+                defender_player,
+                settings,
+            )
+        })
+    }
+
+    /// Predict an engagement between any number of armies. `teams` assigns a team to each army,
+    /// armies on the same team are allied. An empty `teams` is a free-for-all.
+    #[pyo3(name = "predict_engage_multi", signature = (armies, teams, defender_player, settings, reinforcements = None))]
+    pub fn py_predict_engage_multi(
+        &self,
+        py: Python<'_>,
+        armies: Vec<Vec<&PyAny>>,
+        teams: Vec<u32>,
+        defender_player: u32,
+        settings: &CombatSettings,
+        reinforcements: Option<Vec<Vec<PyReinforcementWave>>>,
+    ) -> PyResult<CombatResult> {
+        let armies = armies
+            .into_iter()
+            .map(|army| self.convert_units(army))
+            .collect::<PyResult<Vec<Vec<CombatUnit>>>>()?;
+        let reinforcements = reinforcements
+            .map(|reinforcements| {
+                reinforcements
+                    .into_iter()
+                    .map(|waves| self.convert_waves(waves))
+                    .collect::<PyResult<Vec<Vec<ReinforcementWave>>>>()
+            })
+            .transpose()?;
+        py.allow_threads(|| {
+            self.predict_engage_multi(armies, teams, defender_player, settings, reinforcements)
+        })
+    }
+
+    /// Cheapest army built from `unit_types` that is predicted to beat `enemy_units` without exceeding
+    /// the given minerals, vespene and supply. Returns `None` if no affordable army wins. Each army size is
+    /// predicted once, use `find_minimum_force` with more `samples` for close fights.
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(name = "find_best_composition", signature = (enemy_units, unit_types, minerals, vespene, supply, settings, max_unit_types = 2, defender_player = 0))]
+    pub fn py_find_best_composition(
+        &self,
+        py: Python<'_>,
+        enemy_units: Vec<&PyAny>,
+        unit_types: Vec<UnitTypeId>,
+        minerals: i32,
+        vespene: i32,
+        supply: f32,
+        settings: &CombatSettings,
+        max_unit_types: usize,
+        defender_player: u32,
+    ) -> PyResult<Option<Composition>> {
+        let enemy_units = self.convert_units(enemy_units)?;
+        py.allow_threads(|| {
+            self.find_best_composition(
+                enemy_units,
+                unit_types,
+                minerals,
+                vespene,
+                supply,
+                settings,
+                max_unit_types,
+                defender_player,
+            )
+        })
+    }
+
+    /// Smallest army with the unit ratio given by `units`, e.g. `[(MARINE, 3), (MARAUDER, 1)]`, that beats
+    /// `enemy_units` in at least `confidence` of `samples` predictions while keeping `health_margin`
+    /// of its starting health. Returns `None` if `max_count` times the ratio is not enough.
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(name = "find_minimum_force", signature = (enemy_units, units, settings, confidence = 1.0, health_margin = 0.0, samples = 1, max_count = 100, defender_player = 0))]
+    pub fn py_find_minimum_force(
+        &self,
+        py: Python<'_>,
+        enemy_units: Vec<&PyAny>,
+        units: Vec<(UnitTypeId, u32)>,
+        settings: &CombatSettings,
+        confidence: f32,
+        health_margin: f32,
+        samples: u32,
+        max_count: u32,
+        defender_player: u32,
+    ) -> PyResult<Option<Composition>> {
+        let enemy_units = self.convert_units(enemy_units)?;
+        py.allow_threads(|| {
+            self.find_minimum_force(
+                enemy_units,
+                units,
+                settings,
+                confidence,
+                health_margin,
+                samples,
+                max_count,
+                defender_player,
+            )
+        })
+    }
+
+    /// Resource-equal engagement of every pair of `unit_types` at every upgrade level, with armies worth
+    /// up to `resources` (minerals + 1.5 * vespene). Defaults to every unit type in the unit database that
+    /// can attack.
+    #[pyo3(signature = (settings, unit_types = None, upgrade_levels = vec![0, 1, 2, 3], resources = 2000))]
+    pub fn matchup_matrix(
+        &self,
+        py: Python<'_>,
+        settings: &CombatSettings,
+        unit_types: Option<Vec<UnitTypeId>>,
+        upgrade_levels: Vec<i64>,
+        resources: i32,
+    ) -> PyResult<Vec<Matchup>> {
+        let unit_types = unit_types.unwrap_or_else(matchup::combat_unit_types);
+        let settings = settings.clone();
+        py.allow_threads(|| {
+            matchup::matchup_matrix(self, &unit_types, &upgrade_levels, resources, &settings)
+        })
+        .map_err(PyValueError::new_err)
+    }
+
+    /// Fits the `CombatModelParams` to recorded engagements, given as JSON with an `engagements` list of
+    /// scenarios and the side that won each. Starts at `params`, the defaults if None.
+    #[pyo3(signature = (engagements_json, params = None, iterations = 10))]
+    pub fn calibrate(
+        &self,
+        py: Python<'_>,
+        engagements_json: &str,
+        params: Option<CombatModelParams>,
+        iterations: u32,
+    ) -> PyResult<CalibrationResult> {
+        let dataset: Dataset = conversion::from_json(engagements_json)?;
+        let params = params.unwrap_or_default();
+        py.allow_threads(|| {
+            calibration::calibrate(self, &dataset.engagements, &params, &[], iterations)
+        })
+        .map_err(PyValueError::new_err)
+    }
+
+    /// Finds the engagements in the `.SC2Replay` files at `replays` and compares their outcome with the
+    /// predictions. Only 1v1 replays are supported, engagements with fewer than `min_deaths` deaths are
+    /// ignored.
+    #[cfg(feature = "replay")]
+    #[pyo3(signature = (settings, replays, min_deaths = 3))]
+    pub fn benchmark_replays(
+        &self,
+        py: Python<'_>,
+        settings: &CombatSettings,
+        replays: Vec<PathBuf>,
+        min_deaths: u32,
+    ) -> BenchmarkReport {
+        let options = EngagementOptions {
+            min_deaths,
+            ..Default::default()
+        };
+        let settings = settings.clone();
+        py.allow_threads(|| benchmark::benchmark(self, &replays, &settings, &options))
+    }
+}
+
+impl CombatPredictor {
+    /// Converts python-sc2 units, or `CombatUnit`s created in Python, through the unit cache.
+    pub fn convert_units(&self, units: Vec<&PyAny>) -> PyResult<Vec<CombatUnit>> {
+        let mut cache = self.cache.lock().unwrap();
+        units
+            .into_iter()
+            .map(|obj| CombatUnit::extract_cached(obj, &mut cache))
+            .collect()
+    }
+
+    fn convert_waves(&self, waves: Vec<PyReinforcementWave>) -> PyResult<Vec<ReinforcementWave>> {
+        waves
+            .into_iter()
+            .map(|(time, units)| Ok((time, self.convert_units(units)?)))
+            .collect()
     }
 
     pub fn predict_engage(
-        &mut self,
+        &self,
         units1: Vec<CombatUnit>,
         units2: Vec<CombatUnit>,
         defender_player: u32,
         settings: &CombatSettings,
     ) -> PyResult<(u32, f32)> {
-        let (x, y) = self._predict_engage(units1, units2, defender_player, settings);
-        Ok((x, y))
+        let result =
+            self._predict_engage(units1, units2, vec![], vec![], defender_player, settings);
+        let health = result.health_left[result.winner as usize - 1];
+        Ok((result.winner, health))
     }
-}
 
-impl CombatPredictor {
-    fn get_zealot_radius(&self) -> f32 {
-        0.5
+    pub fn predict_engage_result(
+        &self,
+        units1: Vec<CombatUnit>,
+        units2: Vec<CombatUnit>,
+        defender_player: u32,
+        settings: &CombatSettings,
+    ) -> PyResult<CombatResult> {
+        Ok(self._predict_engage(units1, units2, vec![], vec![], defender_player, settings))
+    }
+
+    pub fn predict_engage_with_reinforcements(
+        &self,
+        units1: Vec<CombatUnit>,
+        units2: Vec<CombatUnit>,
+        reinforcements1: Vec<ReinforcementWave>,
+        reinforcements2: Vec<ReinforcementWave>,
+        defender_player: u32,
+        settings: &CombatSettings,
+    ) -> PyResult<CombatResult> {
+        Ok(self._predict_engage(
+            units1,
+            units2,
+            reinforcements1,
+            reinforcements2,
+            defender_player,
+            settings,
+        ))
+    }
+
+    pub fn predict_engage_multi(
+        &self,
+        armies: Vec<Vec<CombatUnit>>,
+        teams: Vec<u32>,
+        defender_player: u32,
+        settings: &CombatSettings,
+        reinforcements: Option<Vec<Vec<ReinforcementWave>>>,
+    ) -> PyResult<CombatResult> {
+        let teams: Vec<u32> = if teams.is_empty() {
+            (1..=armies.len() as u32).collect()
+        } else {
+            teams
+        };
+        let reinforcements = reinforcements.unwrap_or_else(|| vec![vec![]; armies.len()]);
+        if armies.len() < 2 {
+            return Err(PyValueError::new_err(format!(
+                "Expected at least 2 armies, got {:?}",
+                armies.len()
+            )));
+        }
+        if teams.len() != armies.len() || reinforcements.len() != armies.len() {
+            return Err(PyValueError::new_err(format!(
+                "Expected one team and reinforcement list per army, got {:?} armies, {:?} teams and {:?} reinforcement lists",
+                armies.len(),
+                teams.len(),
+                reinforcements.len()
+            )));
+        }
+        Ok(self._predict_engage_multi(armies, teams, reinforcements, defender_player, settings))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn find_best_composition(
+        &self,
+        enemy_units: Vec<CombatUnit>,
+        unit_types: Vec<UnitTypeId>,
+        minerals: i32,
+        vespene: i32,
+        supply: f32,
+        settings: &CombatSettings,
+        max_unit_types: usize,
+        defender_player: u32,
+    ) -> PyResult<Option<Composition>> {
+        if !(1..=2).contains(&max_unit_types) {
+            return Err(PyValueError::new_err(format!(
+                "Expected 1 or 2 max_unit_types, got {:?}",
+                max_unit_types
+            )));
+        }
+        let mut candidates: Vec<CombatUnit> = Vec::with_capacity(unit_types.len());
+        for type_id in unit_types {
+            match unit_database::get_unit(type_id) {
+                Some(unit) => candidates.push(unit),
+                None => {
+                    return Err(PyValueError::new_err(format!(
+                        "No unit data for {:?}",
+                        type_id
+                    )))
+                }
+            }
+        }
+        let budget = Budget {
+            minerals,
+            vespene,
+            supply,
+        };
+        Ok(composition::find_best_composition(
+            self,
+            &enemy_units,
+            &candidates,
+            &budget,
+            max_unit_types,
+            defender_player,
+            settings,
+        ))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn find_minimum_force(
+        &self,
+        enemy_units: Vec<CombatUnit>,
+        units: Vec<(UnitTypeId, u32)>,
+        settings: &CombatSettings,
+        confidence: f32,
+        health_margin: f32,
+        samples: u32,
+        max_count: u32,
+        defender_player: u32,
+    ) -> PyResult<Option<Composition>> {
+        let mut candidates: Vec<CombatUnit> = Vec::with_capacity(units.len());
+        let mut ratio: Vec<(usize, u32)> = Vec::with_capacity(units.len());
+        for (index, (type_id, amount)) in units.into_iter().enumerate() {
+            match unit_database::get_unit(type_id) {
+                Some(unit) => candidates.push(unit),
+                None => {
+                    return Err(PyValueError::new_err(format!(
+                        "No unit data for {:?}",
+                        type_id
+                    )))
+                }
+            }
+            ratio.push((index, amount));
+        }
+        let requirement = ForceRequirement {
+            confidence,
+            health_margin,
+            samples,
+        };
+        Ok(composition::find_minimum_force(
+            self,
+            &enemy_units,
+            &candidates,
+            &ratio,
+            max_count,
+            &requirement,
+            defender_player,
+            settings,
+        ))
+    }
+
+    /// Predicts the engagement of every `(units1, units2)` pair in parallel.
+    pub fn predict_engage_pairs(
+        &self,
+        pairs: Vec<(Vec<CombatUnit>, Vec<CombatUnit>)>,
+        defender_player: u32,
+        settings: &CombatSettings,
+    ) -> Vec<CombatResult> {
+        pairs
+            .into_par_iter()
+            .map(|(units1, units2)| {
+                self._predict_engage(units1, units2, vec![], vec![], defender_player, settings)
+            })
+            .collect()
     }
 
     fn get_fastest_attacker_speed(units: &[CombatUnit]) -> f32 {
@@ -169,6 +950,215 @@ impl CombatPredictor {
         )
     }
 
+    fn get_total_health(units: &[CombatUnit]) -> f32 {
+        units.iter().map(|u| u.health + u.shield).sum()
+    }
+
+    fn get_army_value(units: &[CombatUnit], settings: &CombatSettings) -> f32 {
+        units
+            .iter()
+            .filter(|u| u.health > 0.0)
+            .map(|u| {
+                if settings.retreat_by_supply {
+                    u.get_supply_cost()
+                } else {
+                    u.get_value(settings.params.vespene_multiplier)
+                }
+            })
+            .sum()
+    }
+
+    /// Returns the side that has lost more than `retreat_threshold` of its army and should retreat.
+    fn find_retreating_side(
+        armies: &[Vec<CombatUnit>],
+        teams: &[u32],
+        retreated: &[u32],
+        initial_value: &[f32],
+        combat_settings: &CombatSettings,
+    ) -> Option<usize> {
+        let mut retreating: Option<(usize, f32)> = None;
+        for (side, units) in armies.iter().enumerate() {
+            let player = side as u32 + 1;
+            if (combat_settings.retreat_player != 0 && combat_settings.retreat_player != player)
+                || retreated.contains(&player)
+            {
+                continue;
+            }
+            let has_enemies = armies.iter().enumerate().any(|(other, enemies)| {
+                teams[other] != teams[side]
+                    && !retreated.contains(&(other as u32 + 1))
+                    && !enemies.is_empty()
+            });
+            let initial = initial_value[side];
+            if units.is_empty() || !has_enemies || initial <= 0.0 {
+                continue;
+            }
+            let lost = 1.0 - Self::get_army_value(units, combat_settings) / initial;
+            if lost >= combat_settings.retreat_threshold {
+                match retreating {
+                    Some((_, l)) if l >= lost => {}
+                    _ => retreating = Some((side, lost)),
+                }
+            }
+        }
+        retreating.map(|(side, _)| side)
+    }
+
+    /// Returns a side that has less than `early_out_health_fraction` of its health + shields left.
+    fn find_early_out_side(
+        armies: &[Vec<CombatUnit>],
+        retreated: &[u32],
+        initial_health: &[f32],
+        combat_settings: &CombatSettings,
+    ) -> Option<usize> {
+        (0..armies.len()).find(|&side| {
+            let player = side as u32 + 1;
+            (combat_settings.early_out_player == 0 || combat_settings.early_out_player == player)
+                && !retreated.contains(&player)
+                && Self::get_total_health(&armies[side])
+                    < initial_health[side] * combat_settings.early_out_health_fraction
+        })
+    }
+
+    /// Whether at least two hostile sides are still in the fight.
+    fn has_hostile_sides(armies: &[Vec<CombatUnit>], teams: &[u32], retreated: &[u32]) -> bool {
+        let active: Vec<usize> = (0..armies.len())
+            .filter(|&side| !retreated.contains(&(side as u32 + 1)) && !armies[side].is_empty())
+            .collect();
+        active
+            .iter()
+            .any(|&a| active.iter().any(|&b| teams[a] != teams[b]))
+    }
+
+    /// Time `chaser` can keep `target` in weapon range while `target` runs away.
+    fn chase_exposure(
+        chaser: &CombatUnit,
+        target: &CombatUnit,
+        range: f32,
+        chase_time: f32,
+    ) -> f32 {
+        let speed_diff = target.movement_speed - chaser.movement_speed;
+        if speed_diff <= 0.0 {
+            chase_time
+        } else {
+            (range / speed_diff).min(chase_time)
+        }
+    }
+
+    /// Damage dealt by `chasers` to the `retreating` units while they disengage.
+    fn simulate_chase(
+        chasers: &[CombatUnit],
+        retreating: &mut Vec<CombatUnit>,
+        combat_settings: &CombatSettings,
+        mut trace: Option<&mut CombatTrace>,
+    ) {
+        let debug = combat_settings.debug;
+        let _span = phase_span!(debug, "chase", retreating = retreating.len()).entered();
+        for chaser in chasers {
+            if chaser.movement_speed <= 0.0
+                || (combat_settings.workers_do_no_damage && chaser.is_basic_harvester())
+            {
+                continue;
+            }
+            let mut time_left = combat_settings.chase_time;
+            let mut escaped: Vec<bool> = vec![false; retreating.len()];
+
+            while time_left > 0.0 {
+                // index, dps, time in range
+                let mut best: Option<(usize, f32, f32)> = None;
+                for (j, target) in retreating.iter().enumerate() {
+                    if escaped[j] {
+                        continue;
+                    }
+                    let weapons = [
+                        (chaser.air_weapons(), chaser.air_range),
+                        (chaser.ground_weapons(), chaser.ground_range),
+                    ];
+                    for (weapon, range) in weapons.iter() {
+                        let dps = match weapon {
+                            Some(w) => w.calculate_dps(chaser, target),
+                            None => 0.0,
+                        };
+                        if dps <= 0.0 {
+                            continue;
+                        }
+                        let exposure = Self::chase_exposure(chaser, target, *range, time_left);
+                        match best {
+                            Some((_, d, e)) if d * e >= dps * exposure => {}
+                            _ => best = Some((j, dps, exposure)),
+                        }
+                    }
+                }
+                let (j, dps, exposure) = match best {
+                    Some(b) => b,
+                    None => break,
+                };
+                let target = &mut retreating[j];
+                let time_to_kill = (target.health + target.shield) / dps;
+                if debug {
+                    debug!(
+                        "{:?} chasing {:?}, dps={:?}, time in range={:?}",
+                        chaser.name, target.name, dps, exposure
+                    );
+                }
+                let health_before = target.health + target.shield;
+                if time_to_kill <= exposure {
+                    if let Some(trace) = trace.as_mut() {
+                        trace.record_attack(chaser.tag, target.tag, health_before);
+                    }
+                    retreating.swap_remove(j);
+                    escaped.swap_remove(j);
+                    time_left -= time_to_kill;
+                } else {
+                    target.modify_health(-dps * exposure);
+                    if let Some(trace) = trace.as_mut() {
+                        trace.record_attack(
+                            chaser.tag,
+                            target.tag,
+                            health_before - target.health - target.shield,
+                        );
+                    }
+                    escaped[j] = true;
+                    time_left -= exposure;
+                }
+            }
+        }
+    }
+
+    fn get_next_arrival(reinforcements: &[Vec<ReinforcementWave>]) -> Option<f32> {
+        reinforcements
+            .iter()
+            .flatten()
+            .map(|(arrival, _)| *arrival)
+            .fold(None, |next: Option<f32>, arrival| {
+                Some(next.map_or(arrival, |t| t.min(arrival)))
+            })
+    }
+
+    /// Moves every wave that has arrived by `elapsed` into `units`. Returns the value of the added units.
+    fn add_reinforcements(
+        reinforcements: &mut Vec<ReinforcementWave>,
+        units: &mut Vec<CombatUnit>,
+        elapsed: f32,
+        combat_settings: &CombatSettings,
+    ) -> f32 {
+        let mut added_value: f32 = 0.0;
+        let mut i: usize = 0;
+        while i < reinforcements.len() {
+            if reinforcements[i].0 <= elapsed {
+                let (arrival, wave) = reinforcements.swap_remove(i);
+                if combat_settings.debug {
+                    debug!("{:?} reinforcements arrived at {:?}", wave.len(), arrival);
+                }
+                added_value += Self::get_army_value(&wave, combat_settings);
+                units.extend(wave);
+            } else {
+                i += 1;
+            }
+        }
+        added_value
+    }
+
     // fn find_best_target_multi_threaded<'a>(
     //     unit: &'a CombatUnit,
     //     units: &[CombatUnit],
@@ -232,25 +1222,28 @@ impl CombatPredictor {
     //     (best_target, best_target_index, best_weapon, best_dps)
     // }
 
-    fn find_best_target<'a>(
+    fn find_best_target<'a, 'w>(
         unit: &CombatUnit,
-        units: &'a [CombatUnit],
+        targets: &'a Targets,
         combat_settings: &CombatSettings,
         has_ground: bool,
         has_air: bool,
         is_unit_melee: bool,
-        melee_unit_attack_count: &[i32],
         surround: &SurroundInfo,
         opponent_fraction_melee_units: f32,
-        _best_weapon: Option<&'a Weapon>,
-    ) -> (Option<&'a CombatUnit>, usize, Option<&'a Weapon>, f32) {
+        _best_weapon: Option<&'w Weapon>,
+    ) -> (Option<&'a CombatUnit>, usize, Option<&'w Weapon>, f32) {
         let mut best_target: Option<&CombatUnit> = None;
         let mut best_target_index: usize = 0;
         let mut best_score: f32 = 0.0;
         let mut best_weapon: Option<&Weapon> = None;
         let mut best_dps: f32 = 0.0;
 
-        for (j, other) in units.iter().enumerate() {
+        for (j, other) in targets.units.iter().enumerate() {
+            // Units on the high ground can only be shot with vision, melee units walk up and get it
+            if targets.hidden[j] && !is_unit_melee {
+                continue;
+            }
             let air_dps2: f32 = match unit.air_weapons() {
                 Some(t) => t.calculate_dps(unit, other),
                 None => 0.0,
@@ -262,11 +1255,13 @@ impl CombatPredictor {
 
             let dps: f32 = air_dps2.max(ground_dps2);
 
-            let mut score: f32 = dps * target_score(other, has_ground, has_air) * 0.001;
+            let params = &combat_settings.params;
+            let mut score: f32 =
+                dps * target_score(other, has_ground, has_air, params.vespene_multiplier) * 0.001;
 
             if is_unit_melee {
                 if combat_settings.enable_surround_limits
-                    && melee_unit_attack_count[j] >= surround.max_attackers_per_defender
+                    && targets.melee_attack_count[j] >= surround.max_attackers_per_defender
                 {
                     continue;
                 }
@@ -275,18 +1270,20 @@ impl CombatPredictor {
                     score = -score;
                 }
                 if combat_settings.enable_melee_blocking && other.is_melee() {
-                    score += 1000.00;
+                    score += params.melee_target_bonus;
                 } else if combat_settings.enable_melee_blocking
                     && unit.movement_speed < 1.05 * other.movement_speed
                 {
-                    score += 500.00;
+                    score += params.slow_target_bonus;
                 }
             } else if !unit.is_flying {
                 let range_diff: f32 = other.get_max_range() - unit.get_max_range();
-                if opponent_fraction_melee_units > 0.5 && range_diff > 0.5 {
-                    score -= 1000.00;
-                } else if opponent_fraction_melee_units > 0.3 && range_diff > 1.0 {
-                    score -= 1000.00
+                if opponent_fraction_melee_units > params.high_melee_fraction && range_diff > 0.5 {
+                    score -= params.outranged_target_penalty;
+                } else if opponent_fraction_melee_units > params.low_melee_fraction
+                    && range_diff > 1.0
+                {
+                    score -= params.outranged_target_penalty
                 }
             }
 
@@ -322,93 +1319,163 @@ impl CombatPredictor {
         (best_target, best_target_index, best_weapon, best_dps)
     }
 
-    fn _predict_engage(
-        &mut self,
-        mut units1: Vec<CombatUnit>,
-        mut units2: Vec<CombatUnit>,
+    pub(crate) fn _predict_engage(
+        &self,
+        units1: Vec<CombatUnit>,
+        units2: Vec<CombatUnit>,
+        reinforcements1: Vec<ReinforcementWave>,
+        reinforcements2: Vec<ReinforcementWave>,
         defender_player: u32,
         combat_settings: &CombatSettings,
-    ) -> (u32, f32) {
-        const HEALING_PER_SECOND: f32 = 12.6 / 1.4;
+    ) -> CombatResult {
+        self._predict_engage_multi(
+            vec![units1, units2],
+            vec![1, 2],
+            vec![reinforcements1, reinforcements2],
+            defender_player,
+            combat_settings,
+        )
+    }
+
+    fn _predict_engage_multi(
+        &self,
+        mut armies: Vec<Vec<CombatUnit>>,
+        teams: Vec<u32>,
+        mut reinforcements: Vec<Vec<ReinforcementWave>>,
+        defender_player: u32,
+        combat_settings: &CombatSettings,
+    ) -> CombatResult {
         const MAX_ITERATIONS: u32 = 100;
 
         let debug: bool = combat_settings.debug;
-        let zealot_radius: f32 = self.get_zealot_radius();
+        let num_sides: usize = armies.len();
+        let _span = phase_span!(debug, "predict_engage", sides = num_sides).entered();
 
         let mut time: f32 = combat_settings.start_time;
         // let reset_buff: bool = time == 0.00;
 
-        let mut average_health_by_time: [f32; 2] = [0.0, 0.0];
-        let mut average_health_by_time_weight: [f32; 2] = [0.0, 0.0];
+        let mut average_health_by_time: Vec<f32> = vec![0.0; num_sides];
+        let mut average_health_by_time_weight: Vec<f32> = vec![0.0; num_sides];
         let max_range_defender: f32;
         let fastest_attacker_speed: f32;
         let mut changed: bool = true;
+        let mut retreated: Vec<u32> = vec![];
+        let mut initial_value: Vec<f32> = armies
+            .iter()
+            .map(|units| Self::get_army_value(units, combat_settings))
+            .collect();
+        let mut initial_health: Vec<f32> = armies
+            .iter()
+            .map(|units| Self::get_total_health(units))
+            .collect();
+
+        let mut trace: Option<CombatTrace> = None;
+        if combat_settings.trace {
+            CombatTrace::assign_ids(
+                armies.iter_mut().flatten().chain(
+                    reinforcements
+                        .iter_mut()
+                        .flatten()
+                        .flat_map(|(_, wave)| wave.iter_mut()),
+                ),
+            );
+            trace = Some(CombatTrace::default());
+        }
+
+        for (side, units) in armies.iter_mut().enumerate() {
+            let player = side as u32 + 1;
+            combat_settings
+                .terrain
+                .apply_creep(player, units.iter_mut());
+            combat_settings.terrain.apply_creep(
+                player,
+                reinforcements[side]
+                    .iter_mut()
+                    .flat_map(|(_, wave)| wave.iter_mut()),
+            );
+        }
 
         let mut rng = thread_rng();
-        units1.shuffle(&mut rng);
-        units2.shuffle(&mut rng);
+        for units in armies.iter_mut() {
+            units.shuffle(&mut rng);
+        }
 
-        if defender_player == 1 || defender_player == 2 {
-            if defender_player == 1 {
-                max_range_defender = Self::get_max_range_defender(&units1);
-                fastest_attacker_speed = Self::get_fastest_attacker_speed(&units2);
-            } else {
-                max_range_defender = Self::get_max_range_defender(&units2);
-                fastest_attacker_speed = Self::get_fastest_attacker_speed(&units1);
-            }
+        if defender_player >= 1 && defender_player as usize <= num_sides {
+            let defender = defender_player as usize - 1;
+            max_range_defender = Self::get_max_range_defender(&armies[defender]);
+            fastest_attacker_speed = armies
+                .iter()
+                .enumerate()
+                .filter(|(side, _)| teams[*side] != teams[defender])
+                .map(|(_, units)| Self::get_fastest_attacker_speed(units))
+                .fold(0.0, f32::max);
         } else {
-            max_range_defender =
-                Self::get_max_range_defender(&units1).max(Self::get_max_range_defender(&units2));
-            fastest_attacker_speed = Self::get_fastest_attacker_speed(&units2)
-                .max(Self::get_fastest_attacker_speed(&units1))
+            max_range_defender = armies
+                .iter()
+                .map(|units| Self::get_max_range_defender(units))
+                .fold(0.0, f32::max);
+            fastest_attacker_speed = armies
+                .iter()
+                .map(|units| Self::get_fastest_attacker_speed(units))
+                .fold(0.0, f32::max);
         }
 
         for it in 0..MAX_ITERATIONS {
+            let _iteration = phase_span!(debug, "iteration", it).entered();
+            let next_arrival = Self::get_next_arrival(&reinforcements);
             if !changed {
-                break;
+                // Nothing can attack anymore, skip ahead to the next reinforcement wave
+                match next_arrival {
+                    Some(arrival)
+                        if combat_settings.start_time + arrival <= combat_settings.max_time =>
+                    {
+                        time = time.max(combat_settings.start_time + arrival);
+                    }
+                    _ => break,
+                }
             }
-            if debug {
-                let mut total_health1 = 0.0;
-                let mut total_health2 = 0.0;
-                for u in &units1 {
-                    total_health1 += u.health + u.shield
+            if next_arrival.is_some() {
+                let elapsed = time - combat_settings.start_time;
+                for side in 0..num_sides {
+                    initial_health[side] -= Self::get_total_health(&armies[side]);
+                    initial_value[side] += Self::add_reinforcements(
+                        &mut reinforcements[side],
+                        &mut armies[side],
+                        elapsed,
+                        combat_settings,
+                    );
+                    initial_health[side] += Self::get_total_health(&armies[side]);
                 }
-                for u in &units2 {
-                    total_health2 += u.health + u.shield
+            }
+            if debug {
+                for (side, units) in armies.iter().enumerate() {
+                    let total_health: f32 = Self::get_total_health(units);
+                    debug!(
+                        "units{:?}-health={:?}, total={:?}",
+                        side + 1,
+                        total_health,
+                        units.len()
+                    );
                 }
-                println!(
-                    "units1-health={:?}, total={:?}, unit2-health={:?}, total={:?}",
-                    total_health1,
-                    units1.len(),
-                    total_health2,
-                    units2.len()
-                );
             }
 
-            let (
-                has_air1,
-                has_ground1,
-                ground_area1,
-                _average_health_by_time,
-                _average_health_by_time_weight,
-            ) = Self::get_unit_info(&units1, time);
-            average_health_by_time[0] = _average_health_by_time;
-            average_health_by_time_weight[0] = _average_health_by_time_weight;
-
-            let (
-                has_air2,
-                has_ground2,
-                ground_area2,
-                _average_health_by_time,
-                _average_health_by_time_weight,
-            ) = Self::get_unit_info(&units2, time);
-            average_health_by_time[1] = _average_health_by_time;
-            average_health_by_time_weight[1] = _average_health_by_time_weight;
-
-            let pi_ga1 = ground_area1 * PI;
-            let pi_ga2 = ground_area2 * PI;
-            let surround_info1: SurroundInfo = max_surround(pi_ga2, has_ground2, zealot_radius);
-            let surround_info2: SurroundInfo = max_surround(pi_ga1, has_ground1, zealot_radius);
+            let mut has_air_units: Vec<i32> = vec![0; num_sides];
+            let mut has_ground_units: Vec<i32> = vec![0; num_sides];
+            let mut ground_areas: Vec<f32> = vec![0.0; num_sides];
+            for (side, units) in armies.iter().enumerate() {
+                let (
+                    has_air,
+                    has_ground,
+                    ground_area,
+                    _average_health_by_time,
+                    _average_health_by_time_weight,
+                ) = Self::get_unit_info(units, time);
+                has_air_units[side] = has_air;
+                has_ground_units[side] = has_ground;
+                ground_areas[side] = ground_area;
+                average_health_by_time[side] = _average_health_by_time;
+                average_health_by_time_weight[side] = _average_health_by_time_weight;
+            }
 
             let dt = if 5 < 1 + (it / 10) {
                 5_f32
@@ -416,48 +1483,78 @@ impl CombatPredictor {
                 (1 + (it / 10)) as f32
             };
             if debug {
-                println!("Iteration: {:?} Time:  {:?}", it, time);
+                debug!("Iteration: {:?} Time:  {:?}", it, time);
+            }
+            if let Some(trace) = trace.as_mut() {
+                trace.record(time, &armies);
             }
             changed = false;
 
-            for group in 0..2 {
+            for group in 0..num_sides {
+                let hostile: Vec<usize> = (0..num_sides)
+                    .filter(|&side| {
+                        teams[side] != teams[group] && !retreated.contains(&(side as u32 + 1))
+                    })
+                    .collect();
+                if hostile.is_empty() || retreated.contains(&(group as u32 + 1)) {
+                    continue;
+                }
                 if debug {
-                    println!("Processing group {:?}", group);
+                    debug!("Processing group {:?}", group);
                 }
-                let (g1, g2): (&mut Vec<CombatUnit>, &mut Vec<CombatUnit>) = match group {
-                    0 => (&mut units1, &mut units2),
-                    1 => (&mut units2, &mut units1),
-                    _ => unreachable!(),
-                };
 
-                let surround: SurroundInfo = if group == 0 {
-                    surround_info1
-                } else {
-                    surround_info2
-                };
+                let surround: SurroundInfo = max_surround(
+                    hostile.iter().map(|&side| ground_areas[side]).sum::<f32>() * PI,
+                    hostile.iter().map(|&side| has_ground_units[side]).sum(),
+                    &combat_settings.params,
+                    combat_settings.terrain.choke_width,
+                );
 
-                let max_extra_melee_distance =
-                    (ground_area1 / PI).sqrt() * PI + (ground_area2 / PI).sqrt() * PI;
+                let max_extra_melee_distance = (ground_areas[group] / PI).sqrt() * PI
+                    + hostile
+                        .iter()
+                        .map(|&side| (ground_areas[side] / PI).sqrt() * PI)
+                        .sum::<f32>();
+
+                let hidden_player = combat_settings
+                    .terrain
+                    .hidden_player(group as u32 + 1, &armies[group]);
+
+                // All hostile units are pooled into one target list and handed back afterwards
+                let mut targets = Targets::default();
+                for &side in hostile.iter() {
+                    let is_hidden = hidden_player == Some(side as u32 + 1);
+                    targets.hidden.extend(
+                        armies[side]
+                            .iter()
+                            .map(|u| is_hidden && !u.is_flying && !u.is_melee()),
+                    );
+                    targets
+                        .owners
+                        .resize(targets.owners.len() + armies[side].len(), side);
+                    targets.units.append(&mut armies[side]);
+                }
+                targets.melee_attack_count = vec![0; targets.units.len()];
+                let g1: &mut Vec<CombatUnit> = &mut armies[group];
 
                 let mut num_melee_units_used: i32 = 0;
 
                 let mut opponent_fraction_melee_units: f32 = 0.0;
 
-                for u in g2.iter() {
+                for u in targets.units.iter() {
                     if u.health > 0.0 && u.is_melee() {
                         opponent_fraction_melee_units += 1.0;
                     }
                 }
 
-                if !g2.is_empty() {
-                    opponent_fraction_melee_units /= g2.len() as f32;
+                if !targets.units.is_empty() {
+                    opponent_fraction_melee_units /= targets.units.len() as f32;
                 }
 
                 let mut has_been_healed: Vec<bool> = vec![false; g1.len()];
-                let mut melee_unit_attack_count: Vec<i32> = vec![0; g2.len()];
 
                 if debug {
-                    println!(
+                    debug!(
                         "Max melee attackers: {:?} {:?} num units: {:?}",
                         surround.max_melee_attackers,
                         surround.max_attackers_per_defender,
@@ -475,7 +1572,7 @@ impl CombatPredictor {
                     let ground_dps = unit.get_dps(false);
 
                     if debug {
-                        println!("Processing {:?}, health: {:?}, shield: {:?}, energy: {:?}, ground_dps: {:?}, air_dps: {:?}",
+                        trace!("Processing {:?}, health: {:?}, shield: {:?}, energy: {:?}, ground_dps: {:?}, air_dps: {:?}",
                                  unit.name,
                                  unit.health,
                                  unit.shield,
@@ -485,7 +1582,9 @@ impl CombatPredictor {
                     }
 
                     if unit.type_id == UnitTypeId::MEDIVAC {
+                        let _phase = phase_span!(debug, "healing").entered();
                         if unit.energy > 0.0 {
+                            let healer = unit.tag;
                             let offset: usize = rand::random::<usize>() % g1_len;
 
                             for j in 0..g1_len {
@@ -498,16 +1597,28 @@ impl CombatPredictor {
                                     && other.is_biological
                                 {
                                     if debug {
-                                        println!(
+                                        trace!(
                                             "Unit {:?} being healed. Health before ={:?}",
-                                            other.type_id, other.health
+                                            other.type_id,
+                                            other.health
+                                        );
+                                    }
+                                    let health_before = other.health;
+                                    other.modify_health(
+                                        combat_settings.params.healing_per_second * dt,
+                                    );
+                                    if let Some(trace) = trace.as_mut() {
+                                        trace.record_healing(
+                                            healer,
+                                            other.tag,
+                                            other.health - health_before,
                                         );
                                     }
-                                    other.modify_health(HEALING_PER_SECOND * dt);
                                     if debug {
-                                        println!(
+                                        trace!(
                                             "Unit {:?} being healed. Health after ={:?}",
-                                            other.type_id, other.health
+                                            other.type_id,
+                                            other.health
                                         );
                                     }
                                     has_been_healed[index] = true;
@@ -538,7 +1649,7 @@ impl CombatPredictor {
 
                     //Timing adjustment
                     if combat_settings.enable_timing_adjustment {
-                        if group + 1 != defender_player {
+                        if group as u32 + 1 != defender_player {
                             let mut distance_to_enemy = max_range_defender;
                             if is_unit_melee {
                                 distance_to_enemy +=
@@ -564,16 +1675,9 @@ impl CombatPredictor {
                         }
                     }
 
-                    let has_ground: bool = if group == 0 {
-                        has_ground1 != 0
-                    } else {
-                        has_ground2 != 0
-                    };
-                    let has_air: bool = if group == 0 {
-                        has_air1 != 0
-                    } else {
-                        has_air2 != 0
-                    };
+                    let target_selection = phase_span!(debug, "target_selection").entered();
+                    let has_ground: bool = has_ground_units[group] != 0;
+                    let has_air: bool = has_air_units[group] != 0;
                     let _best_weapon = if air_dps > ground_dps {
                         unit.air_weapons()
                     } else {
@@ -582,33 +1686,35 @@ impl CombatPredictor {
                     let (best_target, best_target_index, best_weapon, best_dps) =
                         Self::find_best_target(
                             unit,
-                            g2,
+                            &targets,
                             combat_settings,
                             has_ground,
                             has_air,
                             is_unit_melee,
-                            &melee_unit_attack_count,
                             &surround,
                             opponent_fraction_melee_units,
                             _best_weapon,
                         );
+                    drop(target_selection);
 
                     if best_target.is_some() {
+                        let _phase = phase_span!(debug, "damage").entered();
                         if is_unit_melee {
                             num_melee_units_used += 1;
                         }
-                        melee_unit_attack_count[best_target_index] += 1;
+                        targets.melee_attack_count[best_target_index] += 1;
 
                         let best_weapon_splash = best_weapon.unwrap().splash();
                         let remaining_splash = best_weapon_splash.max(1.0);
 
-                        let other: &mut CombatUnit = g2[best_target_index].borrow_mut();
+                        let other: &mut CombatUnit = targets.units[best_target_index].borrow_mut();
                         changed = true;
 
                         let dps: f32 = best_dps * remaining_splash.max(1.0);
                         let damage_multiplier: f32 = 1.0;
+                        let health_before = other.health + other.shield;
                         if debug {
-                            println!(
+                            trace!(
                                 "Modify health of {:?}, current health={:?}, delta={:?}",
                                 other.name,
                                 other.health,
@@ -616,75 +1722,187 @@ impl CombatPredictor {
                             );
                         }
                         other.modify_health(-dps * damage_multiplier * dt);
+                        if let Some(trace) = trace.as_mut() {
+                            trace.record_attack(
+                                g1[i].tag,
+                                other.tag,
+                                health_before - other.health - other.shield,
+                            );
+                        }
 
                         if debug {
-                            println!("Health of unit after modification ={:?}", other.health);
+                            trace!("Health of unit after modification ={:?}", other.health);
                         }
 
                         if other.health == 0.0 {
-                            g2.swap_remove(best_target_index);
-                            melee_unit_attack_count.swap_remove(best_target_index);
+                            targets.swap_remove(best_target_index);
                         }
                     }
                 }
 
                 if debug {
-                    println!(
+                    debug!(
                         "Melee attackers used: {:?} did change in the last iteration {:?}",
                         num_melee_units_used, changed
                     );
                 }
+
+                for (unit, owner) in targets.units.into_iter().zip(targets.owners) {
+                    armies[owner].push(unit);
+                }
             }
 
             time += dt;
+
+            if combat_settings.retreat_threshold > 0.0 {
+                if let Some(side) = Self::find_retreating_side(
+                    &armies,
+                    &teams,
+                    &retreated,
+                    &initial_value,
+                    combat_settings,
+                ) {
+                    if debug {
+                        debug!("Player {:?} retreats at time {:?}", side + 1, time);
+                    }
+                    if let Some(trace) = trace.as_mut() {
+                        trace.record(time, &armies);
+                    }
+                    let mut retreating = std::mem::take(&mut armies[side]);
+                    for (other, chasers) in armies.iter().enumerate() {
+                        if teams[other] != teams[side] && !retreated.contains(&(other as u32 + 1)) {
+                            Self::simulate_chase(
+                                chasers,
+                                &mut retreating,
+                                combat_settings,
+                                trace.as_mut(),
+                            );
+                        }
+                    }
+                    armies[side] = retreating;
+                    reinforcements[side].clear();
+                    retreated.push(side as u32 + 1);
+
+                    if !Self::has_hostile_sides(&armies, &teams, &retreated) {
+                        break;
+                    }
+                }
+            }
+
+            if combat_settings.early_out_health_fraction > 0.0 {
+                if let Some(side) =
+                    Self::find_early_out_side(&armies, &retreated, &initial_health, combat_settings)
+                {
+                    if debug {
+                        debug!(
+                            "Player {:?} dropped below the early out health at {:?}",
+                            side + 1,
+                            time
+                        );
+                    }
+                    break;
+                }
+            }
+
             if time > combat_settings.max_time {
                 break;
             }
         }
 
         //        println!("Main loop took {:?}", sw.elapsed());
-        average_health_by_time[0] /= average_health_by_time_weight[0].max(0.01);
-        average_health_by_time[1] /= average_health_by_time_weight[1].max(0.01);
+        if let Some(trace) = trace.as_mut() {
+            trace.record(time, &armies);
+        }
+        for side in 0..num_sides {
+            average_health_by_time[side] /= average_health_by_time_weight[side].max(0.01);
+        }
 
         if debug {
-            println!(
-                "1: {:?}, 2: {:?}",
-                average_health_by_time[0], average_health_by_time[1]
-            );
+            debug!("Average health by time: {:?}", average_health_by_time);
         }
 
-        let mut total_health1: f32 = 0.0;
-        let mut total_health2: f32 = 0.0;
-        for u in &units1 {
-            if debug {
-                println!("{:?} has {:?} health left", u.type_id, u.health + u.shield);
+        let health_left: Vec<f32> = armies
+            .iter()
+            .map(|units| {
+                let mut total_health: f32 = 0.0;
+                for u in units {
+                    if debug {
+                        debug!("{:?} has {:?} health left", u.type_id, u.health + u.shield);
+                    }
+                    total_health += u.health + u.shield;
+                }
+                total_health
+            })
+            .collect();
+
+        // Sides that retreated can't win, ties go to the later team
+        let mut team_health: Vec<(u32, f32)> = vec![];
+        for side in 0..num_sides {
+            if retreated.contains(&(side as u32 + 1)) {
+                continue;
             }
-            total_health1 += u.health + u.shield;
-        }
-        for u in &units2 {
-            if debug {
-                println!("{:?} has {:?} health left", u.type_id, u.health + u.shield);
+            match team_health
+                .iter_mut()
+                .find(|(team, _)| *team == teams[side])
+            {
+                Some((_, health)) => *health += health_left[side],
+                None => team_health.push((teams[side], health_left[side])),
             }
-            total_health2 += u.health + u.shield;
         }
-
-        if total_health1 > total_health2 {
-            if debug {
-                println!("Player 1 wins with health={:?}", total_health1);
+        let mut winning_team: u32 = 0;
+        let mut best_health: f32 = 0.0;
+        for (team, health) in team_health {
+            if winning_team == 0 || health >= best_health {
+                winning_team = team;
+                best_health = health;
             }
-            (1, total_health1)
-        } else {
-            if debug {
-                println!("Player 2 wins with health={:?}", total_health2);
+        }
+        let mut winner: u32 = 0;
+        for side in 0..num_sides {
+            if teams[side] == winning_team
+                && !retreated.contains(&(side as u32 + 1))
+                && (winner == 0 || health_left[side] >= health_left[winner as usize - 1])
+            {
+                winner = side as u32 + 1;
             }
-            (2, total_health2)
+        }
+        if debug {
+            debug!(
+                "Player {:?} wins with health={:?}",
+                winner,
+                health_left[winner as usize - 1]
+            );
+        }
+        let pending_reinforcements: Vec<u32> = reinforcements
+            .iter()
+            .map(|waves| waves.iter().map(|(_, wave)| wave.len() as u32).sum())
+            .collect();
+        if debug && pending_reinforcements.iter().any(|&n| n > 0) {
+            debug!(
+                "Reinforcements that never arrived: {:?}",
+                pending_reinforcements
+            );
+        }
+        CombatResult {
+            winner,
+            winning_team,
+            health_left,
+            retreated,
+            time,
+            trace,
+            pending_reinforcements,
         }
     }
 }
 
-pub fn target_score(unit: &CombatUnit, has_ground: bool, has_air: bool) -> f32 {
+pub fn target_score(
+    unit: &CombatUnit,
+    has_ground: bool,
+    has_air: bool,
+    vespene_multiplier: f32,
+) -> f32 {
     let mut score: f32 = 0.0;
-    let cost: f32 = unit.get_adjusted_cost() as f32;
+    let cost: f32 = unit.get_value(vespene_multiplier);
 
     let air_dps: f32 = unit.get_dps(true);
     let ground_dps: f32 = unit.get_dps(false);
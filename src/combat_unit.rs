@@ -1,17 +1,55 @@
+use crate::combat_predictor::CombatPredictor;
+use crate::conversion::{self, extract_field};
+use crate::enums::Attribute;
 use crate::generated_enums::{UnitTypeId, UpgradeId};
 // use crate::num_traits::FromPrimitive;
-use crate::unit_type_data::UnitTypeData;
+use crate::unit_database;
+use crate::unit_type_data::{Cost, UnitTypeData};
 use crate::weapon::{Weapon, WeaponTargetType};
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::types::PyAny;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::collections::HashSet;
-use std::sync::Mutex;
-lazy_static! {
-    pub static ref UNIT_CACHE: Mutex<HashMap<UnitTypeId, CombatUnit>> =
-        Mutex::new(HashMap::with_capacity(100));
+
+/// Identifies the static data of a unit. Besides the type, it holds everything that can differ between
+/// units of the same type, like upgrades and weapon ranges, so upgraded units are not converted with stale
+/// stats. The movement speed changes all the time, e.g. on creep, so it is read from the live unit instead.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct UnitCacheKey {
+    pub type_id: UnitTypeId,
+    pub attack_upgrade_level: i64,
+    pub armor_upgrade_level: i64,
+    pub shield_upgrade_level: i64,
+    /// `f32::to_bits` of the maximum health, shield and energy.
+    pub health_max: u32,
+    pub shield_max: u32,
+    pub energy_max: u32,
+    /// `f32::to_bits` of the weapon ranges, which range upgrades change together with the weapons.
+    pub ground_range: u32,
+    pub air_range: u32,
 }
 
+impl UnitCacheKey {
+    pub fn of(unit: &CombatUnit) -> Self {
+        UnitCacheKey {
+            type_id: unit.type_id,
+            attack_upgrade_level: unit.attack_upgrade_level,
+            armor_upgrade_level: unit.armor_upgrade_level,
+            shield_upgrade_level: unit.shield_upgrade_level,
+            health_max: unit.health_max.to_bits(),
+            shield_max: unit.shield_max.to_bits(),
+            energy_max: unit.energy_max.to_bits(),
+            ground_range: unit.ground_range.to_bits(),
+            air_range: unit.air_range.to_bits(),
+        }
+    }
+}
+
+/// Static data of converted units, owned by a `CombatPredictor`.
+pub type UnitCache = HashMap<UnitCacheKey, CombatUnit>;
+
 const VESPENE_MULTIPLIER: f32 = 1.5;
 lazy_static! {
     pub static ref IS_MELEE: HashSet<UnitTypeId> = [
@@ -67,13 +105,14 @@ lazy_static! {
             .cloned()
             .collect();
 }
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CombatUnit {
     pub type_id: UnitTypeId,
     pub type_data: UnitTypeData,
     pub name: String,
     // pub race: Race,
-    // pub tag: i64,
+    /// Unit tag, only used to identify units in a `CombatTrace`. 0 if unknown.
+    pub tag: u64,
     // pub is_structure: bool,
     pub is_light: bool,
     pub is_armored: bool,
@@ -124,15 +163,31 @@ pub struct CombatUnit {
     // pub weapon_cooldown: f32,
     pub buff_timer: f32,
 }
-impl<'source> FromPyObject<'source> for CombatUnit {
-    fn extract(obj: &'source PyAny) -> PyResult<Self> {
-        let type_id: UnitTypeId = obj.getattr("type_id")?.extract()?;
-        let mut cache = UNIT_CACHE.lock().unwrap();
-        if let Some(x) = cache.get(&type_id) {
+impl CombatUnit {
+    /// Converts a python-sc2 `Unit`, reading static unit data from `cache` if a unit of the same type
+    /// and upgrades was seen before. A `CombatUnit` created in Python is copied as is.
+    pub fn extract_cached(obj: &PyAny, cache: &mut UnitCache) -> PyResult<Self> {
+        if let Ok(unit) = obj.downcast::<PyCell<PyCombatUnit>>() {
+            return Ok(unit.borrow().unit.clone());
+        }
+        let type_id: UnitTypeId = extract_field(obj, "type_id")?;
+        let key = UnitCacheKey {
+            type_id,
+            attack_upgrade_level: extract_field(obj, "attack_upgrade_level")?,
+            armor_upgrade_level: extract_field(obj, "armor_upgrade_level")?,
+            shield_upgrade_level: extract_field(obj, "shield_upgrade_level")?,
+            health_max: extract_field::<f32>(obj, "health_max")?.to_bits(),
+            shield_max: extract_field::<f32>(obj, "shield_max")?.to_bits(),
+            energy_max: extract_field::<f32>(obj, "energy_max")?.to_bits(),
+            ground_range: extract_field::<f32>(obj, "ground_range")?.to_bits(),
+            air_range: extract_field::<f32>(obj, "air_range")?.to_bits(),
+        };
+        if let Some(x) = cache.get(&key) {
             Ok(Self {
                 type_id,
                 type_data: x.type_data.clone(),
                 name: x.name.clone(),
+                tag: extract_field(obj, "tag")?,
                 is_light: x.is_light,
                 is_armored: x.is_armored,
                 is_biological: x.is_biological,
@@ -145,64 +200,72 @@ impl<'source> FromPyObject<'source> for CombatUnit {
                 air_dps: x.air_dps,
                 air_range: x.air_range,
                 armor: x.armor,
-                movement_speed: x.movement_speed,
-                health: obj.getattr("health")?.extract()?,
+                movement_speed: extract_field(obj, "movement_speed")?,
+                health: extract_field(obj, "health")?,
                 health_max: x.health_max,
-                shield: obj.getattr("shield")?.extract()?,
+                shield: extract_field(obj, "shield")?,
                 shield_max: x.shield_max,
-                energy: obj.getattr("energy")?.extract()?,
+                energy: extract_field(obj, "energy")?,
                 energy_max: x.energy_max,
                 radius: x.radius,
                 is_flying: x.is_flying,
-                attack_upgrade_level: obj.getattr("attack_upgrade_level")?.extract()?,
-                armor_upgrade_level: obj.getattr("armor_upgrade_level")?.extract()?,
+                attack_upgrade_level: key.attack_upgrade_level,
+                armor_upgrade_level: key.armor_upgrade_level,
                 buff_timer: 0.0,
-                shield_upgrade_level: obj.getattr("shield_upgrade_level")?.extract()?,
+                shield_upgrade_level: key.shield_upgrade_level,
             })
         } else {
             let mut cu = Self {
                 type_id,
-                type_data: obj.getattr("_type_data")?.extract()?,
-                name: obj.getattr("name")?.extract()?,
+                type_data: extract_field(obj, "_type_data")?,
+                name: extract_field(obj, "name")?,
                 // race: (),
-                // tag: obj.getattr("tag")?.extract()?,
-                // is_structure: obj.getattr("is_structure")?.extract()?,
-                is_light: obj.getattr("is_light")?.extract()?,
-                is_armored: obj.getattr("is_armored")?.extract()?,
-                is_biological: obj.getattr("is_biological")?.extract()?,
-                is_mechanical: obj.getattr("is_mechanical")?.extract()?,
-                is_massive: obj.getattr("is_massive")?.extract()?,
-                is_psionic: obj.getattr("is_psionic")?.extract()?,
-                weapons: obj.getattr("_weapons")?.extract()?,
+                tag: extract_field(obj, "tag")?,
+                // is_structure: extract_field(obj, "is_structure")?,
+                is_light: extract_field(obj, "is_light")?,
+                is_armored: extract_field(obj, "is_armored")?,
+                is_biological: extract_field(obj, "is_biological")?,
+                is_mechanical: extract_field(obj, "is_mechanical")?,
+                is_massive: extract_field(obj, "is_massive")?,
+                is_psionic: extract_field(obj, "is_psionic")?,
+                weapons: match extract_field::<Option<Vec<&PyAny>>>(obj, "_weapons")? {
+                    Some(weapons) => Some(
+                        weapons
+                            .into_iter()
+                            .map(Weapon::from_sc2)
+                            .collect::<PyResult<Vec<Weapon>>>()?,
+                    ),
+                    None => None,
+                },
                 // can_attack_both: obj.getattr( "can_attack_both")?.extract()?,
                 // can_attack_ground: obj.getattr( "can_attack_ground")?.extract()?,
                 // can_attack_air: obj.getattr( "can_attack_air")?.extract()?,
-                ground_dps: obj.getattr("ground_dps")?.extract()?,
-                ground_range: obj.getattr("ground_range")?.extract()?,
-                air_dps: obj.getattr("air_dps")?.extract()?,
-                air_range: obj.getattr("air_range")?.extract()?,
+                ground_dps: extract_field(obj, "ground_dps")?,
+                ground_range: f32::from_bits(key.ground_range),
+                air_dps: extract_field(obj, "air_dps")?,
+                air_range: f32::from_bits(key.air_range),
                 // bonus_damage: None,
-                armor: obj.getattr("armor")?.extract()?,
+                armor: extract_field(obj, "armor")?,
                 // sight_range: obj.getattr(py, "sight_range")?.extract(py)?,
-                movement_speed: obj.getattr("movement_speed")?.extract()?,
-                health: obj.getattr("health")?.extract()?,
-                health_max: obj.getattr("health_max")?.extract()?,
-                shield: obj.getattr("shield")?.extract()?,
-                shield_max: obj.getattr("shield_max")?.extract()?,
-                energy: obj.getattr("energy")?.extract()?,
-                energy_max: obj.getattr("energy_max")?.extract()?,
+                movement_speed: extract_field(obj, "movement_speed")?,
+                health: extract_field(obj, "health")?,
+                health_max: f32::from_bits(key.health_max),
+                shield: extract_field(obj, "shield")?,
+                shield_max: f32::from_bits(key.shield_max),
+                energy: extract_field(obj, "energy")?,
+                energy_max: f32::from_bits(key.energy_max),
                 // // alliance: (),
                 // is_mine: obj.getattr( "is_mine")?.extract()?,
                 // is_enemy: obj.getattr( "is_enemy")?.extract()?,
                 // owner_id: obj.getattr( "owner_id")?.extract()?,
-                radius: obj.getattr("radius")?.extract()?,
+                radius: extract_field(obj, "radius")?,
                 // is_revealed: obj.getattr( "is_revealed")?.extract()?,
                 // can_be_attacked: obj.getattr( "can_be_attacked")?.extract()?,
                 // buffs: Default::default(),
-                is_flying: obj.getattr("is_flying")?.extract()?,
-                attack_upgrade_level: obj.getattr("attack_upgrade_level")?.extract()?,
-                armor_upgrade_level: obj.getattr("armor_upgrade_level")?.extract()?,
-                shield_upgrade_level: obj.getattr("shield_upgrade_level")?.extract()?,
+                is_flying: extract_field(obj, "is_flying")?,
+                attack_upgrade_level: key.attack_upgrade_level,
+                armor_upgrade_level: key.armor_upgrade_level,
+                shield_upgrade_level: key.shield_upgrade_level,
                 // buff_duration_remain: obj.getattr( "buff_duration_remain")?.extract()?,
                 // buff_duration_max: obj.getattr( "buff_duration_max")?.extract()?,
                 // is_idle: obj.getattr( "is_idle")?.extract()?,
@@ -222,12 +285,42 @@ impl<'source> FromPyObject<'source> for CombatUnit {
                 cu.ground_dps = 35.714_287;
                 cu.air_dps = 22.321_428;
             }
-            cache.insert(type_id, cu.clone());
+            cache.insert(key, cu.clone());
             Ok(cu)
         }
     }
 }
 impl CombatUnit {
+    /// Sets the attributes and the matching `is_*` flags.
+    pub fn set_attributes(&mut self, attributes: Vec<Attribute>) {
+        self.is_light = attributes.contains(&Attribute::LIGHT);
+        self.is_armored = attributes.contains(&Attribute::ARMORED);
+        self.is_biological = attributes.contains(&Attribute::BIOLOGICAL);
+        self.is_mechanical = attributes.contains(&Attribute::MECHANICAL);
+        self.is_massive = attributes.contains(&Attribute::MASSIVE);
+        self.is_psionic = attributes.contains(&Attribute::PSIONIC);
+        self.type_data.attributes = attributes;
+    }
+    /// Sets the weapons and the dps and range derived from them. Same as python-sc2, dps is without
+    /// bonus damage.
+    pub fn set_weapons(&mut self, weapons: Vec<Weapon>) {
+        self.ground_dps = 0.0;
+        self.ground_range = 0.0;
+        self.air_dps = 0.0;
+        self.air_range = 0.0;
+        for weapon in weapons.iter() {
+            let dps = weapon.damage * weapon.attacks as f32 / weapon.speed;
+            if weapon.w_type != WeaponTargetType::AIR {
+                self.ground_dps = dps;
+                self.ground_range = weapon.range;
+            }
+            if weapon.w_type != WeaponTargetType::GROUND {
+                self.air_dps = dps;
+                self.air_range = weapon.range;
+            }
+        }
+        self.weapons = Some(weapons);
+    }
     pub fn get_max_range(&self) -> f32 {
         if self.air_range > self.ground_range {
             self.air_range
@@ -261,7 +354,12 @@ impl CombatUnit {
         }
     }
     pub fn get_adjusted_cost(&self) -> i32 {
-        self.get_mineral_cost() + (VESPENE_MULTIPLIER * self.get_vespene_cost() as f32) as i32
+        self.get_value(VESPENE_MULTIPLIER) as i32
+    }
+    /// Minerals + `vespene_multiplier` * vespene.
+    pub fn get_value(&self, vespene_multiplier: f32) -> f32 {
+        self.get_mineral_cost() as f32
+            + (vespene_multiplier * self.get_vespene_cost() as f32).floor()
     }
     pub fn get_mineral_cost(&self) -> i32 {
         self.type_data.cost.minerals
@@ -269,6 +367,9 @@ impl CombatUnit {
     pub fn get_vespene_cost(&self) -> i32 {
         self.type_data.cost.vespene
     }
+    pub fn get_supply_cost(&self) -> f32 {
+        self.type_data.food_required
+    }
     pub fn get_max_dps(&self) -> f32 {
         if self.air_dps > self.ground_dps {
             self.air_dps
@@ -342,3 +443,301 @@ impl CombatUnit {
         None
     }
 }
+
+/// `CombatUnit` for Python. Can be built from scratch, from the unit database or from a python-sc2 `Unit`,
+/// and is passed to the predictor without converting it again. Useful for what-if scenarios.
+#[pyclass(name = "CombatUnit", module = "sc2_helper.sc2_helper")]
+#[derive(Clone)]
+pub struct PyCombatUnit {
+    pub unit: CombatUnit,
+}
+
+#[pymethods]
+impl PyCombatUnit {
+    #[new]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (type_id, health, weapons = vec![], attributes = vec![], armor = 0.0, shield = 0.0, energy = 0.0, movement_speed = 0.0, radius = 0.5, is_flying = false, cost = None, supply = 0.0, name = None))]
+    fn new(
+        type_id: UnitTypeId,
+        health: f32,
+        weapons: Vec<Weapon>,
+        attributes: Vec<Attribute>,
+        armor: f32,
+        shield: f32,
+        energy: f32,
+        movement_speed: f32,
+        radius: f32,
+        is_flying: bool,
+        cost: Option<Cost>,
+        supply: f32,
+        name: Option<String>,
+    ) -> Self {
+        let mut unit = CombatUnit {
+            type_id,
+            type_data: UnitTypeData::new(
+                vec![],
+                cost.unwrap_or(Cost {
+                    minerals: 0,
+                    vespene: 0,
+                    time: 0.0,
+                }),
+            ),
+            name: name.unwrap_or_else(|| format!("{:?}", type_id)),
+            tag: 0,
+            is_light: false,
+            is_armored: false,
+            is_biological: false,
+            is_mechanical: false,
+            is_massive: false,
+            is_psionic: false,
+            weapons: None,
+            ground_dps: 0.0,
+            ground_range: 0.0,
+            air_dps: 0.0,
+            air_range: 0.0,
+            armor,
+            movement_speed,
+            health,
+            health_max: health,
+            shield,
+            shield_max: shield,
+            energy,
+            energy_max: energy,
+            radius,
+            is_flying,
+            attack_upgrade_level: 0,
+            armor_upgrade_level: 0,
+            shield_upgrade_level: 0,
+            buff_timer: 0.0,
+        };
+        unit.type_data.food_required = supply;
+        unit.set_attributes(attributes);
+        unit.set_weapons(weapons);
+        PyCombatUnit { unit }
+    }
+
+    /// Unit with full health and no upgrades from the built-in unit database.
+    #[staticmethod]
+    fn from_type_id(type_id: UnitTypeId) -> PyResult<Self> {
+        match unit_database::get_unit(type_id) {
+            Some(unit) => Ok(PyCombatUnit { unit }),
+            None => Err(PyValueError::new_err(format!(
+                "No unit data for {:?}",
+                type_id
+            ))),
+        }
+    }
+
+    /// Converts a python-sc2 `Unit` once, so it can be reused across predictions. Static unit data is
+    /// read from and added to the unit cache of `predictor` if given.
+    #[staticmethod]
+    #[pyo3(signature = (unit, predictor = None))]
+    fn from_unit(unit: &PyAny, predictor: Option<PyRef<CombatPredictor>>) -> PyResult<Self> {
+        let unit = match predictor {
+            Some(predictor) => predictor.convert_units(vec![unit])?.remove(0),
+            None => CombatUnit::extract_cached(unit, &mut UnitCache::new())?,
+        };
+        Ok(PyCombatUnit { unit })
+    }
+
+    #[getter]
+    fn type_id(&self) -> UnitTypeId {
+        self.unit.type_id
+    }
+    #[getter]
+    fn name(&self) -> String {
+        self.unit.name.clone()
+    }
+    #[getter]
+    fn tag(&self) -> u64 {
+        self.unit.tag
+    }
+    #[setter]
+    fn set_tag(&mut self, value: u64) {
+        self.unit.tag = value;
+    }
+    #[getter]
+    fn health(&self) -> f32 {
+        self.unit.health
+    }
+    #[setter]
+    fn set_health(&mut self, value: f32) {
+        self.unit.health = value;
+    }
+    #[getter]
+    fn health_max(&self) -> f32 {
+        self.unit.health_max
+    }
+    #[setter]
+    fn set_health_max(&mut self, value: f32) {
+        self.unit.health_max = value;
+    }
+    #[getter]
+    fn shield(&self) -> f32 {
+        self.unit.shield
+    }
+    #[setter]
+    fn set_shield(&mut self, value: f32) {
+        self.unit.shield = value;
+    }
+    #[getter]
+    fn shield_max(&self) -> f32 {
+        self.unit.shield_max
+    }
+    #[setter]
+    fn set_shield_max(&mut self, value: f32) {
+        self.unit.shield_max = value;
+    }
+    #[getter]
+    fn energy(&self) -> f32 {
+        self.unit.energy
+    }
+    #[setter]
+    fn set_energy(&mut self, value: f32) {
+        self.unit.energy = value;
+    }
+    #[getter]
+    fn energy_max(&self) -> f32 {
+        self.unit.energy_max
+    }
+    #[setter]
+    fn set_energy_max(&mut self, value: f32) {
+        self.unit.energy_max = value;
+    }
+    #[getter]
+    fn armor(&self) -> f32 {
+        self.unit.armor
+    }
+    #[setter]
+    fn set_armor(&mut self, value: f32) {
+        self.unit.armor = value;
+    }
+    #[getter]
+    fn movement_speed(&self) -> f32 {
+        self.unit.movement_speed
+    }
+    #[setter]
+    fn set_movement_speed(&mut self, value: f32) {
+        self.unit.movement_speed = value;
+    }
+    #[getter]
+    fn radius(&self) -> f32 {
+        self.unit.radius
+    }
+    #[setter]
+    fn set_radius(&mut self, value: f32) {
+        self.unit.radius = value;
+    }
+    #[getter]
+    fn is_flying(&self) -> bool {
+        self.unit.is_flying
+    }
+    #[getter]
+    fn attack_upgrade_level(&self) -> i64 {
+        self.unit.attack_upgrade_level
+    }
+    #[setter]
+    fn set_attack_upgrade_level(&mut self, value: i64) {
+        self.unit.attack_upgrade_level = value;
+    }
+    #[getter]
+    fn armor_upgrade_level(&self) -> i64 {
+        self.unit.armor_upgrade_level
+    }
+    #[setter]
+    fn set_armor_upgrade_level(&mut self, value: i64) {
+        self.unit.armor_upgrade_level = value;
+    }
+    #[getter]
+    fn shield_upgrade_level(&self) -> i64 {
+        self.unit.shield_upgrade_level
+    }
+    #[setter]
+    fn set_shield_upgrade_level(&mut self, value: i64) {
+        self.unit.shield_upgrade_level = value;
+    }
+    #[getter]
+    fn weapons(&self) -> Vec<Weapon> {
+        self.unit.weapons.clone().unwrap_or_default()
+    }
+    /// Also updates the dps and range of the unit.
+    #[setter]
+    fn set_weapons(&mut self, value: Vec<Weapon>) {
+        self.unit.set_weapons(value);
+    }
+    #[getter]
+    fn attributes(&self) -> Vec<Attribute> {
+        self.unit.type_data.attributes.clone()
+    }
+    #[setter]
+    fn set_attributes(&mut self, value: Vec<Attribute>) {
+        self.unit.set_attributes(value);
+    }
+    #[getter]
+    fn cost(&self) -> Cost {
+        self.unit.type_data.cost
+    }
+    #[setter]
+    fn set_cost(&mut self, value: Cost) {
+        self.unit.type_data.cost = value;
+    }
+    #[getter]
+    fn supply(&self) -> f32 {
+        self.unit.type_data.food_required
+    }
+    #[setter]
+    fn set_supply(&mut self, value: f32) {
+        self.unit.type_data.food_required = value;
+    }
+    #[getter]
+    fn ground_dps(&self) -> f32 {
+        self.unit.ground_dps
+    }
+    #[getter]
+    fn ground_range(&self) -> f32 {
+        self.unit.ground_range
+    }
+    #[getter]
+    fn air_dps(&self) -> f32 {
+        self.unit.air_dps
+    }
+    #[getter]
+    fn air_range(&self) -> f32 {
+        self.unit.air_range
+    }
+
+    /// JSON representation, also used to pickle it.
+    pub fn to_json(&self) -> PyResult<String> {
+        conversion::to_json(&self.unit)
+    }
+
+    #[staticmethod]
+    pub fn from_json(json: &str) -> PyResult<Self> {
+        Ok(PyCombatUnit {
+            unit: conversion::from_json(json)?,
+        })
+    }
+
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (String,))> {
+        conversion::reduce_json::<Self, _>(py, &self.unit)
+    }
+
+    fn __copy__(&self) -> Self {
+        self.clone()
+    }
+
+    fn __deepcopy__(&self, _memo: &PyAny) -> Self {
+        self.clone()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "CombatUnit({}, health={}/{}, shield={}/{})",
+            self.unit.name,
+            self.unit.health,
+            self.unit.health_max,
+            self.unit.shield,
+            self.unit.shield_max
+        )
+    }
+}
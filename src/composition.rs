@@ -0,0 +1,298 @@
+use crate::combat_predictor::{CombatPredictor, CombatSettings};
+use crate::combat_unit::CombatUnit;
+use crate::conversion;
+use crate::generated_enums::UnitTypeId;
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Unit count ratios tried when mixing two unit types.
+const MIX_RATIOS: [(u32, u32); 5] = [(1, 1), (2, 1), (1, 2), (3, 1), (1, 3)];
+
+#[derive(Clone, Copy, Debug)]
+pub struct Budget {
+    pub minerals: i32,
+    pub vespene: i32,
+    pub supply: f32,
+}
+
+#[pyclass(module = "sc2_helper.sc2_helper")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Composition {
+    /// Unit types and how many of each to build.
+    #[pyo3(get)]
+    pub units: Vec<(UnitTypeId, u32)>,
+    #[pyo3(get)]
+    pub minerals: i32,
+    #[pyo3(get)]
+    pub vespene: i32,
+    #[pyo3(get)]
+    pub supply: f32,
+    /// Minerals + 1.5 * vespene, used to rank compositions.
+    #[pyo3(get)]
+    pub adjusted_cost: i32,
+    /// Predicted health + shield left after the engagement.
+    #[pyo3(get)]
+    pub health_left: f32,
+}
+
+#[pymethods]
+impl Composition {
+    /// JSON representation, also used to pickle it.
+    pub fn to_json(&self) -> PyResult<String> {
+        conversion::to_json(self)
+    }
+
+    #[staticmethod]
+    pub fn from_json(json: &str) -> PyResult<Self> {
+        conversion::from_json(json)
+    }
+
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (String,))> {
+        conversion::reduce_json::<Self, _>(py, self)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl Composition {
+    fn new(candidates: &[CombatUnit], bundle: &[(usize, u32)], count: u32) -> Self {
+        let mut composition = Composition::default();
+        for &(index, n) in bundle {
+            let unit = &candidates[index];
+            let amount = n * count;
+            composition.units.push((unit.type_id, amount));
+            composition.minerals += unit.get_mineral_cost() * amount as i32;
+            composition.vespene += unit.get_vespene_cost() * amount as i32;
+            composition.supply += unit.get_supply_cost() * amount as f32;
+            composition.adjusted_cost += unit.get_adjusted_cost() * amount as i32;
+        }
+        composition
+    }
+
+    pub fn build_units(&self, candidates: &[CombatUnit]) -> Vec<CombatUnit> {
+        let mut units: Vec<CombatUnit> = vec![];
+        for &(type_id, amount) in &self.units {
+            if let Some(unit) = candidates.iter().find(|u| u.type_id == type_id) {
+                units.extend(std::iter::repeat_with(|| unit.clone()).take(amount as usize));
+            }
+        }
+        units
+    }
+}
+
+/// Highest multiple of `bundle` that fits in `budget`.
+fn max_bundle_count(candidates: &[CombatUnit], bundle: &[(usize, u32)], budget: &Budget) -> u32 {
+    let once = Composition::new(candidates, bundle, 1);
+    let mut max_count = u32::MAX;
+    if once.minerals > 0 {
+        max_count = max_count.min((budget.minerals / once.minerals).max(0) as u32);
+    }
+    if once.vespene > 0 {
+        max_count = max_count.min((budget.vespene / once.vespene).max(0) as u32);
+    }
+    if once.supply > 0.0 {
+        max_count = max_count.min((budget.supply / once.supply).max(0.0) as u32);
+    }
+    // Free units with no supply cost would never run out
+    if max_count == u32::MAX {
+        0
+    } else {
+        max_count
+    }
+}
+
+/// How convincingly an army has to win to count as enough.
+#[derive(Clone, Copy, Debug)]
+pub struct ForceRequirement {
+    /// Fraction of `samples` predictions (0.0 - 1.0) that have to be won.
+    pub confidence: f32,
+    /// Fraction of the starting health + shields that has to be left after winning.
+    pub health_margin: f32,
+    /// Amount of predictions run per army size.
+    pub samples: u32,
+}
+
+impl Default for ForceRequirement {
+    fn default() -> Self {
+        ForceRequirement {
+            confidence: 1.0,
+            health_margin: 0.0,
+            samples: 1,
+        }
+    }
+}
+
+struct Search<'a> {
+    predictor: &'a CombatPredictor,
+    enemy_units: &'a [CombatUnit],
+    candidates: &'a [CombatUnit],
+    requirement: ForceRequirement,
+    defender_player: u32,
+    settings: CombatSettings,
+}
+
+impl<'a> Search<'a> {
+    fn new(
+        predictor: &'a CombatPredictor,
+        enemy_units: &'a [CombatUnit],
+        candidates: &'a [CombatUnit],
+        requirement: ForceRequirement,
+        defender_player: u32,
+        settings: &CombatSettings,
+    ) -> Self {
+        let mut settings = settings.clone();
+        // Anything below the margin is a loss anyway, no need to simulate it to the end
+        if requirement.health_margin > 0.0 {
+            settings.early_out_health_fraction = requirement.health_margin;
+            settings.early_out_player = 1;
+        }
+        Search {
+            predictor,
+            enemy_units,
+            candidates,
+            requirement,
+            defender_player,
+            settings,
+        }
+    }
+
+    /// Simulates `count` bundles against the enemy, returning the composition if it meets the requirement.
+    fn evaluate(&mut self, bundle: &[(usize, u32)], count: u32) -> Option<Composition> {
+        let mut composition = Composition::new(self.candidates, bundle, count);
+        let units = composition.build_units(self.candidates);
+        let min_health =
+            units.iter().map(|u| u.health + u.shield).sum::<f32>() * self.requirement.health_margin;
+        let samples = self.requirement.samples.max(1);
+        let required_wins =
+            ((samples as f32 * self.requirement.confidence).ceil() as u32).clamp(1, samples);
+
+        let (mut wins, mut health_left) = (0, 0.0);
+        for sample in 0..samples {
+            // Stop as soon as the outcome is decided
+            if wins >= required_wins || wins + (samples - sample) < required_wins {
+                break;
+            }
+            let result = self.predictor._predict_engage(
+                units.clone(),
+                self.enemy_units.to_vec(),
+                vec![],
+                vec![],
+                self.defender_player,
+                &self.settings,
+            );
+            if result.winner == 1 && result.health_left[0] >= min_health {
+                wins += 1;
+                health_left += result.health_left[0];
+            }
+        }
+        if wins >= required_wins {
+            composition.health_left = health_left / wins as f32;
+            Some(composition)
+        } else {
+            None
+        }
+    }
+
+    /// Binary searches the smallest multiple of `bundle` up to `max_count` that meets the requirement.
+    /// Assumes that a bigger army never does worse than a smaller one. Predictions shuffle the armies, so
+    /// near the break-even size a single sample can break that and the search may settle on a larger
+    /// count than needed; more `samples` with a `confidence` below 1.0 smooth it out.
+    fn smallest_winning(&mut self, bundle: &[(usize, u32)], max_count: u32) -> Option<Composition> {
+        if max_count == 0 {
+            return None;
+        }
+        let mut winner = self.evaluate(bundle, max_count)?;
+        let (mut low, mut high) = (1, max_count);
+        while low < high {
+            let mid = (low + high) / 2;
+            match self.evaluate(bundle, mid) {
+                Some(c) => {
+                    winner = c;
+                    high = mid;
+                }
+                None => low = mid + 1,
+            }
+        }
+        Some(winner)
+    }
+}
+
+/// Searches single unit types and two-type mixes of `candidates` for the cheapest army that is predicted to
+/// beat `enemy_units` within `budget`. `max_unit_types` limits the amount of different unit types (1 or 2).
+pub fn find_best_composition(
+    predictor: &CombatPredictor,
+    enemy_units: &[CombatUnit],
+    candidates: &[CombatUnit],
+    budget: &Budget,
+    max_unit_types: usize,
+    defender_player: u32,
+    settings: &CombatSettings,
+) -> Option<Composition> {
+    let mut bundles: Vec<Vec<(usize, u32)>> = vec![];
+    for i in 0..candidates.len() {
+        bundles.push(vec![(i, 1)]);
+        if max_unit_types >= 2 {
+            for j in (i + 1)..candidates.len() {
+                for &(a, b) in MIX_RATIOS.iter() {
+                    bundles.push(vec![(i, a), (j, b)]);
+                }
+            }
+        }
+    }
+
+    let mut search = Search::new(
+        predictor,
+        enemy_units,
+        candidates,
+        ForceRequirement::default(),
+        defender_player,
+        settings,
+    );
+    let mut best: Option<Composition> = None;
+    for bundle in bundles {
+        let max_count = max_bundle_count(candidates, &bundle, budget);
+        let winner = match search.smallest_winning(&bundle, max_count) {
+            Some(c) => c,
+            None => continue,
+        };
+
+        let is_better = match &best {
+            None => true,
+            Some(b) => {
+                winner.adjusted_cost < b.adjusted_cost
+                    || (winner.adjusted_cost == b.adjusted_cost
+                        && winner.health_left > b.health_left)
+            }
+        };
+        if is_better {
+            best = Some(winner);
+        }
+    }
+    best
+}
+
+/// Smallest multiple (up to `max_count`) of the unit ratio `bundle` that beats `enemy_units` while meeting
+/// `requirement`. `bundle` holds (index into `candidates`, amount) pairs.
+#[allow(clippy::too_many_arguments)]
+pub fn find_minimum_force(
+    predictor: &CombatPredictor,
+    enemy_units: &[CombatUnit],
+    candidates: &[CombatUnit],
+    bundle: &[(usize, u32)],
+    max_count: u32,
+    requirement: &ForceRequirement,
+    defender_player: u32,
+    settings: &CombatSettings,
+) -> Option<Composition> {
+    let mut search = Search::new(
+        predictor,
+        enemy_units,
+        candidates,
+        *requirement,
+        defender_player,
+        settings,
+    );
+    search.smallest_winning(bundle, max_count)
+}
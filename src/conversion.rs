@@ -0,0 +1,70 @@
+use crate::num_traits::FromPrimitive;
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::PyTypeInfo;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+fn type_name(obj: &PyAny) -> &str {
+    obj.get_type().name().unwrap_or("object")
+}
+
+/// Reads attribute `field` of `obj`. Raises a `TypeError` naming the field if it is missing or can't be
+/// converted, or a `ValueError` if the value itself is invalid.
+pub fn extract_field<'a, T: FromPyObject<'a>>(obj: &'a PyAny, field: &str) -> PyResult<T> {
+    let value = obj.getattr(field).map_err(|_| {
+        PyTypeError::new_err(format!("{} has no field '{}'", type_name(obj), field))
+    })?;
+    value.extract().map_err(|e: PyErr| {
+        let py = obj.py();
+        let message = format!(
+            "Invalid field '{}' of {}: {}",
+            field,
+            type_name(obj),
+            e.value(py)
+        );
+        if e.is_instance_of::<PyValueError>(py) {
+            PyValueError::new_err(message)
+        } else {
+            PyTypeError::new_err(message)
+        }
+    })
+}
+
+/// Serializes `value` to JSON, raising a `ValueError` if that fails.
+pub fn to_json<T: Serialize>(value: &T) -> PyResult<String> {
+    serde_json::to_string(value).map_err(|e| PyValueError::new_err(e.to_string()))
+}
+
+/// Deserializes a `T` from JSON, raising a `ValueError` for invalid JSON.
+pub fn from_json<T: DeserializeOwned>(json: &str) -> PyResult<T> {
+    serde_json::from_str(json).map_err(|e| PyValueError::new_err(e.to_string()))
+}
+
+/// `__reduce__` for pickle, restoring `value` with the `from_json` static method of the Python class `C`.
+pub fn reduce_json<C: PyTypeInfo, T: Serialize>(
+    py: Python<'_>,
+    value: &T,
+) -> PyResult<(PyObject, (String,))> {
+    Ok((
+        py.get_type::<C>().getattr("from_json")?.into(),
+        (to_json(value)?,),
+    ))
+}
+
+/// Converts an int, `IntEnum` or enum with an int `value` (python-sc2's ids) to the enum `T` called `name`,
+/// raising a `ValueError` for unknown values.
+pub fn extract_enum<T: FromPrimitive>(ob: &PyAny, name: &str) -> PyResult<T> {
+    let value: i32 = ob
+        .extract()
+        .or_else(|_| ob.getattr("value")?.extract())
+        .map_err(|_| {
+            PyTypeError::new_err(format!(
+                "Expected an int for {}, got {}",
+                name,
+                type_name(ob)
+            ))
+        })?;
+    T::from_i32(value)
+        .ok_or_else(|| PyValueError::new_err(format!("{} is not a valid {}", value, name)))
+}
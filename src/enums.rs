@@ -1,5 +1,7 @@
-use crate::num_traits::{FromPrimitive, ToPrimitive};
-use pyo3::{FromPyObject, PyAny, PyObject, PyResult, Python, ToPyObject};
+use crate::conversion::extract_enum;
+use crate::num_traits::ToPrimitive;
+use pyo3::types::{PyDict, PyModule};
+use pyo3::{FromPyObject, IntoPy, PyAny, PyObject, PyResult, Python, ToPyObject};
 use serde::{Deserialize, Serialize};
 
 /// Attributes Enum
@@ -19,6 +21,24 @@ pub enum Attribute {
     SUMMONED = 11,
 }
 
+impl Attribute {
+    /// Name and value of every variant, used to export the enum to Python.
+    pub const VARIANTS: &[(&str, i32)] = &[
+        ("NULL", 0),
+        ("LIGHT", 1),
+        ("ARMORED", 2),
+        ("BIOLOGICAL", 3),
+        ("MECHANICAL", 4),
+        ("ROBOTIC", 5),
+        ("PSIONIC", 6),
+        ("MASSIVE", 7),
+        ("STRUCTURE", 8),
+        ("HOVER", 9),
+        ("HEROIC", 10),
+        ("SUMMONED", 11),
+    ];
+}
+
 impl Default for Attribute {
     fn default() -> Self {
         Attribute::NULL
@@ -31,10 +51,31 @@ impl ToPyObject for Attribute {
     }
 }
 
+impl IntoPy<PyObject> for Attribute {
+    fn into_py(self, py: Python) -> PyObject {
+        self.to_i32().unwrap().into_py(py)
+    }
+}
+
 impl<'source> FromPyObject<'source> for Attribute {
     fn extract(ob: &'source PyAny) -> PyResult<Self> {
-        let ob1: i32 = ob.extract::<i32>().unwrap();
-        let x: Attribute = Attribute::from_i32(ob1).unwrap_or_default();
-        Ok(x)
+        extract_enum(ob, "Attribute")
     }
 }
+
+/// Adds an `enum.IntEnum` called `name` to `m`. Its members compare equal to the ints returned by this module.
+/// python-sc2's enums are not ints, so they only compare equal to their `.value`.
+pub fn add_int_enum(
+    py: Python,
+    m: &PyModule,
+    name: &str,
+    variants: &[(&str, i32)],
+) -> PyResult<()> {
+    let kwargs = PyDict::new(py);
+    kwargs.set_item("module", "sc2_helper.sc2_helper")?;
+    let class = py
+        .import("enum")?
+        .getattr("IntEnum")?
+        .call((name, variants.to_vec()), Some(kwargs))?;
+    m.add(name, class)
+}
@@ -0,0 +1,140 @@
+//! Grid helpers that read and write numpy arrays in place through the buffer protocol, without copying
+//! them. The `numpy` crate is not a dependency: its release for pyo3 0.19 is not available to the build, and
+//! newer releases need a newer pyo3. Any contiguous buffer works this way, numpy arrays included.
+use crate::find_points_inside_circle;
+use pyo3::buffer::{Element, PyBuffer};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+/// Element types of the numpy arrays the helpers work on.
+trait GridValue: Element {
+    fn to_f64(self) -> f64;
+    fn from_f64(value: f64) -> Self;
+}
+
+macro_rules! impl_grid_value {
+    ($($t: ty),*) => {
+        $(
+            impl GridValue for $t {
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                fn from_f64(value: f64) -> Self {
+                    value as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_grid_value!(f32, f64, u8, i8, u16, i16, u32, i32, u64, i64);
+
+/// Applies `op` to the cells of a 2-dimensional `buffer` within `radius` of `position`, the same cells
+/// `find_points_inside_circle` returns.
+fn update_inside_circle<T: GridValue>(
+    py: Python<'_>,
+    buffer: PyBuffer<T>,
+    position: (f64, f64),
+    radius: f64,
+    op: impl Fn(f64) -> f64,
+) -> PyResult<()> {
+    if buffer.dimensions() != 2 {
+        return Err(PyValueError::new_err(format!(
+            "Expected a 2-dimensional grid, got {} dimensions",
+            buffer.dimensions()
+        )));
+    }
+    let (h, w) = (buffer.shape()[0], buffer.shape()[1]);
+    let cells = buffer.as_mut_slice(py).ok_or_else(|| {
+        PyValueError::new_err(
+            "Expected a writable C-contiguous grid, e.g. from numpy.ascontiguousarray",
+        )
+    })?;
+    for (x, y) in find_points_inside_circle(position, radius, h, w) {
+        let cell = &cells[y * w + x];
+        cell.set(T::from_f64(op(cell.get().to_f64())));
+    }
+    buffer.release(py);
+    Ok(())
+}
+
+/// Runs `update_inside_circle` with the element type of `grid`.
+fn update_grid(
+    grid: &PyAny,
+    position: (f64, f64),
+    radius: f64,
+    op: impl Fn(f64) -> f64,
+) -> PyResult<()> {
+    macro_rules! try_types {
+        ($($t: ty),*) => {
+            $(
+                if let Ok(buffer) = PyBuffer::<$t>::get(grid) {
+                    return update_inside_circle(grid.py(), buffer, position, radius, op);
+                }
+            )*
+        };
+    }
+    try_types!(f32, f64, u8, i8, u16, i16, u32, i32, u64, i64);
+    Err(PyValueError::new_err(
+        "Expected a 2-dimensional numpy array of numbers",
+    ))
+}
+
+/// Numpy array of `shape` with the elements of `data`, a numpy `dtype` of the same type.
+pub fn to_numpy<T: Element>(
+    py: Python<'_>,
+    data: &[T],
+    shape: &[usize],
+    dtype: &str,
+) -> PyResult<PyObject> {
+    let array = py
+        .import("numpy")?
+        .call_method1("empty", (shape.to_vec(), dtype))?;
+    let buffer = PyBuffer::<T>::get(array)?;
+    buffer.copy_from_slice(py, data)?;
+    buffer.release(py);
+    Ok(array.into())
+}
+
+/// Adds `value` to the cells of the numpy array `grid` within `radius` of `position`, in place.
+#[pyfunction]
+pub fn add_inside_circle(
+    grid: &PyAny,
+    position: (f64, f64),
+    radius: f64,
+    value: f64,
+) -> PyResult<()> {
+    update_grid(grid, position, radius, |cell| cell + value)
+}
+
+/// Sets the cells of the numpy array `grid` within `radius` of `position` to `value`, in place.
+#[pyfunction]
+pub fn set_inside_circle(
+    grid: &PyAny,
+    position: (f64, f64),
+    radius: f64,
+    value: f64,
+) -> PyResult<()> {
+    update_grid(grid, position, radius, |_| value)
+}
+
+/// Row and column index arrays of the cells `find_points_inside_circle` returns, for indexing a numpy
+/// array like `grid[circle_indices(position, radius, h, w)]`.
+#[pyfunction]
+pub fn circle_indices(
+    py: Python<'_>,
+    position: (f64, f64),
+    radius: f64,
+    h: usize,
+    w: usize,
+) -> PyResult<(PyObject, PyObject)> {
+    let (xs, ys): (Vec<i64>, Vec<i64>) = find_points_inside_circle(position, radius, h, w)
+        .into_iter()
+        .map(|(x, y)| (x as i64, y as i64))
+        .unzip();
+    Ok((
+        to_numpy(py, &ys, &[ys.len()], "int64")?,
+        to_numpy(py, &xs, &[xs.len()], "int64")?,
+    ))
+}
@@ -0,0 +1,283 @@
+//! Threat maps of the damage enemy units can deal to each tile.
+use crate::combat_unit::{CombatUnit, UnitCache};
+use crate::conversion::{self, extract_field};
+use crate::find_points_inside_circle;
+use crate::grid_ops;
+use crate::pathfinding::{Path, PathFinder};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+
+/// Ground and air DPS enemy units can deal on each tile of the map, stored by rows like the grids of
+/// python-sc2.
+#[pyclass(module = "sc2_helper.sc2_helper")]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(try_from = "RawInfluenceMap")]
+pub struct InfluenceMap {
+    #[pyo3(get)]
+    pub width: usize,
+    #[pyo3(get)]
+    pub height: usize,
+    ground: Vec<f32>,
+    air: Vec<f32>,
+}
+
+/// Deserialized `InfluenceMap` before its size is checked against the tiles.
+#[derive(Deserialize)]
+struct RawInfluenceMap {
+    width: usize,
+    height: usize,
+    ground: Vec<f32>,
+    air: Vec<f32>,
+}
+
+impl TryFrom<RawInfluenceMap> for InfluenceMap {
+    type Error = String;
+
+    fn try_from(raw: RawInfluenceMap) -> Result<Self, String> {
+        let tiles = raw.width.checked_mul(raw.height);
+        if tiles != Some(raw.ground.len()) || tiles != Some(raw.air.len()) {
+            return Err(format!(
+                "Expected {}x{} tiles, got {} ground and {} air tiles",
+                raw.width,
+                raw.height,
+                raw.ground.len(),
+                raw.air.len()
+            ));
+        }
+        Ok(InfluenceMap {
+            width: raw.width,
+            height: raw.height,
+            ground: raw.ground,
+            air: raw.air,
+        })
+    }
+}
+
+/// A unit and where it is, from a python-sc2 `Unit` or a `(CombatUnit, position)` tuple.
+pub(crate) fn extract_positioned(
+    obj: &PyAny,
+    cache: &mut UnitCache,
+) -> PyResult<(CombatUnit, (f32, f32))> {
+    if let Ok((unit, position)) = obj.extract::<(&PyAny, (f32, f32))>() {
+        return Ok((CombatUnit::extract_cached(unit, cache)?, position));
+    }
+    Ok((
+        CombatUnit::extract_cached(obj, cache)?,
+        extract_field(obj, "position")?,
+    ))
+}
+
+#[pymethods]
+impl InfluenceMap {
+    #[new]
+    pub fn new(width: usize, height: usize) -> Self {
+        InfluenceMap {
+            width,
+            height,
+            ground: vec![0.0; width * height],
+            air: vec![0.0; width * height],
+        }
+    }
+
+    /// Removes all threat, e.g. before adding the enemy units of the next step.
+    pub fn clear(&mut self) {
+        self.ground.iter_mut().for_each(|v| *v = 0.0);
+        self.air.iter_mut().for_each(|v| *v = 0.0);
+    }
+
+    /// Adds the threat of python-sc2 `Unit`s or `(CombatUnit, position)` tuples. Tiles within weapon range
+    /// plus the unit's radius and `buffer` get its DPS.
+    #[pyo3(signature = (units, buffer = 0.0))]
+    pub fn add_units(&mut self, units: Vec<&PyAny>, buffer: f32) -> PyResult<()> {
+        let mut cache = UnitCache::new();
+        for obj in units {
+            let (unit, position) = extract_positioned(obj, &mut cache)?;
+            self.add_unit(&unit, position, buffer);
+        }
+        Ok(())
+    }
+
+    /// DPS enemies deal at `position` to ground units, or air units if `air`.
+    #[pyo3(signature = (position, air = false))]
+    pub fn threat_at(&self, position: (f32, f32), air: bool) -> f32 {
+        let (x, y) = (position.0.floor(), position.1.floor());
+        if x < 0.0 || y < 0.0 || x as usize >= self.width || y as usize >= self.height {
+            return 0.0;
+        }
+        self.layer(air)[y as usize * self.width + x as usize]
+    }
+
+    /// Tiles within `radius` of `position` with at most `max_threat` DPS.
+    #[pyo3(signature = (position, radius, air = false, max_threat = 0.0))]
+    pub fn safe_points(
+        &self,
+        position: (f32, f32),
+        radius: f32,
+        air: bool,
+        max_threat: f32,
+    ) -> Vec<(usize, usize)> {
+        let layer = self.layer(air);
+        find_points_inside_circle(
+            (position.0 as f64, position.1 as f64),
+            radius as f64,
+            self.height,
+            self.width,
+        )
+        .into_iter()
+        .filter(|&(x, y)| layer[y * self.width + x] <= max_threat)
+        .collect()
+    }
+
+    /// Center of the closest tile within `max_distance` of `position` with at most `max_threat` DPS, or `None`.
+    /// With a `path_finder` only tiles a unit of `radius` can stand on count.
+    #[pyo3(signature = (position, max_distance, air = false, max_threat = 0.0, path_finder = None, radius = 0.0))]
+    pub fn find_safe_point(
+        &self,
+        position: (f32, f32),
+        max_distance: f32,
+        air: bool,
+        max_threat: f32,
+        path_finder: Option<PyRef<PathFinder>>,
+        radius: f32,
+    ) -> Option<(f32, f32)> {
+        self.safe_points(position, max_distance, air, max_threat)
+            .into_iter()
+            .filter(|&(x, y)| {
+                path_finder.as_ref().map_or(true, |finder| {
+                    finder.is_passable(x as isize, y as isize, radius)
+                })
+            })
+            .map(|(x, y)| (x as f32 + 0.5, y as f32 + 0.5))
+            .min_by(|a, b| {
+                let distance =
+                    |p: &(f32, f32)| (p.0 - position.0).powi(2) + (p.1 - position.1).powi(2);
+                distance(a).total_cmp(&distance(b))
+            })
+    }
+
+    /// Rows of the ground layer, or the air layer if `air`.
+    #[pyo3(signature = (air = false))]
+    pub fn rows(&self, air: bool) -> Vec<Vec<f32>> {
+        self.layer(air)
+            .chunks(self.width.max(1))
+            .map(|row| row.to_vec())
+            .collect()
+    }
+
+    /// Numpy float32 array of the ground layer, or the air layer if `air`, indexed like `[y, x]`.
+    #[pyo3(signature = (air = false))]
+    pub fn to_numpy(&self, py: Python<'_>, air: bool) -> PyResult<PyObject> {
+        grid_ops::to_numpy(py, self.layer(air), &[self.height, self.width], "float32")
+    }
+
+    /// Path from `start` to `goal` found by `path_finder` that avoids threat: each tile costs
+    /// `1 + threat_cost * DPS` per tile travelled.
+    #[pyo3(signature = (path_finder, start, goal, radius = 0.0, air = false, threat_cost = 0.1, smooth = true))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn find_path(
+        &self,
+        py: Python<'_>,
+        path_finder: PyRef<PathFinder>,
+        start: (f32, f32),
+        goal: (f32, f32),
+        radius: f32,
+        air: bool,
+        threat_cost: f32,
+        smooth: bool,
+    ) -> PyResult<Option<Path>> {
+        if (path_finder.width(), path_finder.height()) != (self.width, self.height) {
+            return Err(PyValueError::new_err(format!(
+                "Expected a {}x{} path finder, got {}x{}",
+                self.width,
+                self.height,
+                path_finder.width(),
+                path_finder.height()
+            )));
+        }
+        let weights = self.weights(air, threat_cost);
+        let path_finder: &PathFinder = &path_finder;
+        Ok(py.allow_threads(|| path_finder.path(start, goal, radius, Some(&weights), smooth)))
+    }
+
+    /// JSON representation, also used to pickle it.
+    pub fn to_json(&self) -> PyResult<String> {
+        conversion::to_json(self)
+    }
+
+    #[staticmethod]
+    pub fn from_json(json: &str) -> PyResult<Self> {
+        conversion::from_json(json)
+    }
+
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (String,))> {
+        conversion::reduce_json::<Self, _>(py, self)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("InfluenceMap(width={}, height={})", self.width, self.height)
+    }
+}
+
+impl InfluenceMap {
+    /// Adds the DPS of `unit` at `position` to the tiles in range of its weapons.
+    pub fn add_unit(&mut self, unit: &CombatUnit, position: (f32, f32), buffer: f32) {
+        if unit.ground_dps > 0.0 {
+            let range = unit.ground_range + unit.radius + buffer;
+            Self::paint(
+                &mut self.ground,
+                self.width,
+                self.height,
+                position,
+                range,
+                unit.ground_dps,
+            );
+        }
+        if unit.air_dps > 0.0 {
+            let range = unit.air_range + unit.radius + buffer;
+            Self::paint(
+                &mut self.air,
+                self.width,
+                self.height,
+                position,
+                range,
+                unit.air_dps,
+            );
+        }
+    }
+
+    fn paint(
+        layer: &mut [f32],
+        width: usize,
+        height: usize,
+        position: (f32, f32),
+        radius: f32,
+        value: f32,
+    ) {
+        for (x, y) in find_points_inside_circle(
+            (position.0 as f64, position.1 as f64),
+            radius as f64,
+            height,
+            width,
+        ) {
+            layer[y * width + x] += value;
+        }
+    }
+
+    pub fn layer(&self, air: bool) -> &[f32] {
+        if air {
+            &self.air
+        } else {
+            &self.ground
+        }
+    }
+
+    /// Path finding weights of the ground or air layer, see `find_path`.
+    pub fn weights(&self, air: bool, threat_cost: f32) -> Vec<f32> {
+        self.layer(air)
+            .iter()
+            .map(|threat| 1.0 + threat_cost * threat)
+            .collect()
+    }
+}
@@ -5,10 +5,31 @@ extern crate enum_primitive_derive;
 extern crate num_traits;
 // use num_traits::FromPrimitive;
 
+#[cfg(feature = "replay")]
+pub mod benchmark;
+pub mod calibration;
+pub mod clustering;
 pub mod combat_predictor;
 pub mod combat_unit;
+pub mod composition;
+mod conversion;
 mod enums;
 pub mod generated_enums;
+pub mod grid_ops;
+pub mod influence;
+mod logger;
+pub mod map;
+pub mod matchup;
+#[cfg(feature = "replay")]
+pub mod mpq;
+pub mod pathfinding;
+#[cfg(feature = "replay")]
+pub mod replay;
+pub mod scenario;
+pub mod spatial;
+pub mod terrain;
+pub mod trace;
+pub mod unit_database;
 mod unit_type_data;
 pub mod weapon;
 use pyo3::prelude::*;
@@ -37,6 +58,19 @@ macro_rules! min {
     }}
 }
 
+/// CSV table of the `engagements` of a `BenchmarkReport`.
+#[cfg(feature = "replay")]
+#[pyfunction]
+fn engagements_to_csv(engagements: Vec<benchmark::EngagementResult>) -> String {
+    benchmark::to_csv(&engagements)
+}
+
+/// CSV table of `matchups`, with unit type names.
+#[pyfunction]
+fn matchups_to_csv(matchups: Vec<matchup::Matchup>) -> String {
+    matchup::to_csv(&matchups)
+}
+
 #[pyfunction]
 pub fn circles_intersect(pos1: (f64, f64), pos2: (f64, f64), r1: f64, r2: f64) -> bool {
     let x1 = pos1.0;
@@ -84,31 +118,96 @@ fn find_points_inside_circle(
 }
 
 #[pymodule]
-fn sc2_helper(_py: Python, m: &PyModule) -> PyResult<()> {
+fn sc2_helper(py: Python, m: &PyModule) -> PyResult<()> {
+    logger::init(py)?;
     m.add_class::<combat_predictor::CombatPredictor>()?;
-    // m.add_class::<combat_unit::CombatUnit>()?;
+    m.add_class::<combat_unit::PyCombatUnit>()?;
+    m.add_class::<weapon::Weapon>()?;
+    m.add_class::<weapon::DamageBonus>()?;
+    m.add_class::<unit_type_data::Cost>()?;
     m.add_class::<combat_predictor::CombatSettings>()?;
+    m.add_class::<combat_predictor::CombatModelParams>()?;
+    m.add_class::<terrain::Terrain>()?;
+    m.add_class::<map::MapAnalysis>()?;
+    m.add_class::<map::Region>()?;
+    m.add_class::<map::Ramp>()?;
+    m.add_class::<map::Choke>()?;
+    m.add_class::<pathfinding::PathFinder>()?;
+    m.add_class::<influence::InfluenceMap>()?;
+    m.add_class::<spatial::SpatialIndex>()?;
+    m.add_class::<clustering::UnitCluster>()?;
+    m.add_class::<calibration::CalibrationResult>()?;
+    m.add_class::<combat_predictor::CombatResult>()?;
+    m.add_class::<composition::Composition>()?;
+    m.add_class::<matchup::Matchup>()?;
+    m.add_class::<trace::CombatTrace>()?;
+    m.add_class::<trace::TraceIteration>()?;
+    m.add_class::<trace::UnitSnapshot>()?;
+    #[cfg(feature = "replay")]
+    {
+        m.add_class::<benchmark::BenchmarkReport>()?;
+        m.add_class::<benchmark::EngagementResult>()?;
+        m.add_wrapped(wrap_pyfunction!(engagements_to_csv))?;
+    }
+    enums::add_int_enum(py, m, "UnitTypeId", generated_enums::UnitTypeId::VARIANTS)?;
+    enums::add_int_enum(py, m, "AbilityId", generated_enums::AbilityId::VARIANTS)?;
+    enums::add_int_enum(py, m, "UpgradeId", generated_enums::UpgradeId::VARIANTS)?;
+    enums::add_int_enum(py, m, "BuffId", generated_enums::BuffId::VARIANTS)?;
+    enums::add_int_enum(py, m, "EffectId", generated_enums::EffectId::VARIANTS)?;
+    enums::add_int_enum(py, m, "Attribute", enums::Attribute::VARIANTS)?;
+    enums::add_int_enum(py, m, "WeaponTargetType", weapon::WeaponTargetType::VARIANTS)?;
+    m.add_wrapped(wrap_pyfunction!(matchups_to_csv))?;
     m.add_wrapped(wrap_pyfunction!(circles_intersect))?;
     m.add_wrapped(wrap_pyfunction!(find_points_inside_circle))?;
+    m.add_wrapped(wrap_pyfunction!(grid_ops::circle_indices))?;
+    m.add_wrapped(wrap_pyfunction!(grid_ops::add_inside_circle))?;
+    m.add_wrapped(wrap_pyfunction!(grid_ops::set_inside_circle))?;
+    m.add_wrapped(wrap_pyfunction!(clustering::cluster_units))?;
+    m.add_wrapped(wrap_pyfunction!(clustering::pair_clusters))?;
+    m.add_wrapped(wrap_pyfunction!(logger::update_log_level))?;
     Ok(())
 }
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    use combat_predictor::{CombatPredictor, CombatSettings};
+    use crate::combat_unit::{CombatUnit, PyCombatUnit, UnitCacheKey};
+    use combat_predictor::{CombatModelParams, CombatPredictor, CombatSettings};
     use enums::Attribute;
     use generated_enums::UnitTypeId;
+    use map::{Grid, MapAnalysis};
+    use pathfinding::PathFinder;
+    use pyo3::types::IntoPyDict;
+    use terrain::Terrain;
     use unit_type_data::{Cost, UnitTypeData};
     use weapon::{Weapon, WeaponTargetType};
-    use crate::combat_unit::CombatUnit;
 
-    #[test]
-    fn test_combat_predictor() {
-        let mut combat_settings = CombatSettings::new();
-        combat_settings.debug = true;
-        let mut predictor = CombatPredictor::new();
-        let marine = CombatUnit {
+    /// Wraps units in Python `CombatUnit`s, the way the predictor receives them from Python.
+    fn to_py(py: Python<'_>, units: Vec<CombatUnit>) -> Vec<&PyAny> {
+        units
+            .into_iter()
+            .map(|unit| PyCell::new(py, PyCombatUnit { unit }).unwrap().as_ref())
+            .collect()
+    }
+
+    /// The extension module, registered under its package name so pickle can find its classes.
+    fn extension_module(py: Python<'_>) -> &PyModule {
+        let module = PyModule::new(py, "sc2_helper").unwrap();
+        sc2_helper(py, module).unwrap();
+        let modules = py.import("sys").unwrap().getattr("modules").unwrap();
+        modules.set_item("sc2_helper", module).unwrap();
+        modules.set_item("sc2_helper.sc2_helper", module).unwrap();
+        module
+    }
+
+    /// Runs `f` with the GIL held, starting the interpreter if needed.
+    fn with_gil<R>(f: impl FnOnce(Python) -> R) -> R {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(f)
+    }
+
+    fn marine() -> CombatUnit {
+        CombatUnit {
             type_id: UnitTypeId::MARINE,
             type_data: UnitTypeData::new(
                 vec![Attribute::LIGHT, Attribute::BIOLOGICAL],
@@ -119,6 +218,7 @@ mod tests {
                 },
             ),
             name: "Marine".to_string(),
+            tag: 0,
             is_light: true,
             is_armored: false,
             is_biological: true,
@@ -151,8 +251,11 @@ mod tests {
             armor_upgrade_level: 0,
             buff_timer: 0.0,
             shield_upgrade_level: 0,
-        };
-        let zergling: CombatUnit = CombatUnit {
+        }
+    }
+
+    fn zergling() -> CombatUnit {
+        CombatUnit {
             type_id: UnitTypeId::ZERGLING,
             type_data: UnitTypeData::new(
                 vec![Attribute::LIGHT, Attribute::BIOLOGICAL],
@@ -163,6 +266,7 @@ mod tests {
                 },
             ),
             name: "Zergling".to_string(),
+            tag: 0,
             is_light: true,
             is_armored: false,
             is_biological: true,
@@ -195,8 +299,11 @@ mod tests {
             armor_upgrade_level: 0,
             buff_timer: 0.0,
             shield_upgrade_level: 0,
-        };
-        let battlecruiser: CombatUnit = CombatUnit {
+        }
+    }
+
+    fn battlecruiser() -> CombatUnit {
+        CombatUnit {
             type_id: UnitTypeId::BATTLECRUISER,
             type_data: UnitTypeData::new(
                 vec![
@@ -211,6 +318,7 @@ mod tests {
                 },
             ),
             name: "Battlecruiser".to_string(),
+            tag: 0,
             is_light: false,
             is_armored: true,
             is_biological: false,
@@ -236,7 +344,16 @@ mod tests {
             armor_upgrade_level: 0,
             buff_timer: 0.0,
             shield_upgrade_level: 0,
-        };
+        }
+    }
+
+    #[test]
+    fn test_combat_predictor() {
+        let mut combat_settings = CombatSettings::new();
+        combat_settings.debug = true;
+        let predictor = CombatPredictor::new();
+        let marine = marine();
+        let battlecruiser = battlecruiser();
         let mut units1: Vec<CombatUnit> = vec![];
         let mut units2: Vec<CombatUnit> = vec![];
         for _ in 0..13 {
@@ -250,4 +367,2194 @@ mod tests {
             .unwrap();
         assert!(result.0 == 2u32);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_retreat() {
+        let mut combat_settings = CombatSettings::new();
+        let predictor = CombatPredictor::new();
+        // Marines pull back after losing 30% of their value and the faster zerglings chase them down
+        combat_settings.retreat_threshold = 0.3;
+        combat_settings.retreat_player = 1;
+        let marines: Vec<CombatUnit> = vec![marine(); 10];
+        let zerglings: Vec<CombatUnit> = vec![zergling(); 20];
+        let chased = predictor
+            .predict_engage_result(marines.clone(), zerglings.clone(), 1, &combat_settings)
+            .unwrap();
+        assert_eq!(chased.retreated, vec![1]);
+        assert_eq!(chased.winner, 2);
+
+        combat_settings.chase_time = 0.0;
+        let not_chased = predictor
+            .predict_engage_result(marines, zerglings, 1, &combat_settings)
+            .unwrap();
+        assert_eq!(not_chased.retreated, vec![1]);
+        assert!(not_chased.health_left[0] > chased.health_left[0]);
+    }
+
+    #[test]
+    fn test_reinforcements() {
+        let combat_settings = CombatSettings::new();
+        let predictor = CombatPredictor::new();
+        let marines: Vec<CombatUnit> = vec![marine(); 6];
+        let zerglings: Vec<CombatUnit> = vec![zergling(); 12];
+        let result = predictor
+            .predict_engage_result(marines.clone(), zerglings.clone(), 1, &combat_settings)
+            .unwrap();
+        assert_eq!(result.winner, 2);
+
+        // Holding until the second half of the army arrives turns the fight around
+        let result = predictor
+            .predict_engage_with_reinforcements(
+                marines.clone(),
+                zerglings.clone(),
+                vec![(0.0, vec![marine(); 8])],
+                vec![],
+                1,
+                &combat_settings,
+            )
+            .unwrap();
+        assert_eq!(result.winner, 1);
+
+        // Reinforcements arriving after the fight is over still get to fight the survivors
+        let result = predictor
+            .predict_engage_with_reinforcements(
+                marines,
+                zerglings,
+                vec![(60.0, vec![marine(); 8])],
+                vec![],
+                1,
+                &combat_settings,
+            )
+            .unwrap();
+        assert!(result.time >= 60.0);
+
+        // A few marines tip the fight if they join it early, but die on their own if they come too late
+        let result = predictor
+            .predict_engage_with_reinforcements(
+                vec![marine(); 6],
+                vec![zergling(); 12],
+                vec![(2.0, vec![marine(); 3])],
+                vec![],
+                1,
+                &combat_settings,
+            )
+            .unwrap();
+        assert_eq!(result.winner, 1);
+        assert_eq!(result.pending_reinforcements, vec![0, 0]);
+        let result = predictor
+            .predict_engage_with_reinforcements(
+                vec![marine(); 6],
+                vec![zergling(); 12],
+                vec![(10.0, vec![marine(); 3])],
+                vec![],
+                1,
+                &combat_settings,
+            )
+            .unwrap();
+        assert_eq!(result.winner, 2);
+
+        // Waves arriving after max_time never join and are reported instead
+        let mut short_settings = combat_settings.clone();
+        short_settings.max_time = 30.0;
+        let result = predictor
+            .predict_engage_with_reinforcements(
+                vec![marine(); 6],
+                vec![zergling(); 12],
+                vec![(60.0, vec![marine(); 3])],
+                vec![],
+                1,
+                &short_settings,
+            )
+            .unwrap();
+        assert_eq!(result.winner, 2);
+        assert!(result.time <= 30.0);
+        assert_eq!(result.pending_reinforcements, vec![3, 0]);
+    }
+
+    #[test]
+    fn test_multi_player_engagement() {
+        let combat_settings = CombatSettings::new();
+        let predictor = CombatPredictor::new();
+        let result = predictor
+            .predict_engage(vec![marine(); 7], vec![zergling(); 12], 0, &combat_settings)
+            .unwrap();
+        assert_eq!(result.0, 2);
+
+        // Two allied marine groups fight the zerglings together
+        let result = predictor
+            .predict_engage_multi(
+                vec![vec![marine(); 7], vec![marine(); 7], vec![zergling(); 12]],
+                vec![1, 1, 2],
+                0,
+                &combat_settings,
+                None,
+            )
+            .unwrap();
+        assert_eq!(result.winning_team, 1);
+        assert!(result.winner == 1 || result.winner == 2);
+        assert_eq!(result.health_left.len(), 3);
+        assert_eq!(result.health_left[2], 0.0);
+
+        // Free-for-all, the battlecruiser can't be hit by zerglings and outlasts everyone
+        let result = predictor
+            .predict_engage_multi(
+                vec![vec![marine(); 2], vec![zergling(); 4], vec![battlecruiser()]],
+                vec![],
+                0,
+                &combat_settings,
+                None,
+            )
+            .unwrap();
+        assert_eq!(result.winner, 3);
+        assert_eq!(result.winning_team, 3);
+    }
+
+    #[test]
+    fn test_find_best_composition() {
+        let combat_settings = CombatSettings::new();
+        let predictor = CombatPredictor::new();
+        let enemy: Vec<CombatUnit> = vec![zergling(); 20];
+        let composition = predictor
+            .find_best_composition(
+                enemy.clone(),
+                vec![UnitTypeId::MARINE, UnitTypeId::MARAUDER, UnitTypeId::HELLION],
+                1000,
+                200,
+                30.0,
+                &combat_settings,
+                2,
+                0,
+            )
+            .unwrap()
+            .unwrap();
+        assert!(composition.minerals <= 1000 && composition.vespene <= 200);
+        assert!(composition.supply <= 30.0);
+
+        // The composition wins and is no more expensive than 10 marines, if those already win
+        let candidates: Vec<CombatUnit> = [
+            UnitTypeId::MARINE,
+            UnitTypeId::MARAUDER,
+            UnitTypeId::HELLION,
+        ]
+        .iter()
+        .map(|t| unit_database::get_unit(*t).unwrap())
+        .collect();
+        let army = composition.build_units(&candidates);
+        let result = predictor
+            .predict_engage(army, enemy.clone(), 0, &combat_settings)
+            .unwrap();
+        assert_eq!(result.0, 1);
+        let marines = predictor
+            .predict_engage(vec![marine(); 10], enemy.clone(), 0, &combat_settings)
+            .unwrap();
+        if marines.0 == 1 {
+            assert!(composition.adjusted_cost <= 500);
+        }
+
+        // Nothing affordable beats a battlecruiser with marauders
+        let composition = predictor
+            .find_best_composition(
+                vec![battlecruiser()],
+                vec![UnitTypeId::MARAUDER],
+                1000,
+                1000,
+                200.0,
+                &combat_settings,
+                2,
+                0,
+            )
+            .unwrap();
+        assert!(composition.is_none());
+
+        // Only single unit types and two-type mixes are searched
+        for max_unit_types in [0, 3] {
+            let result = predictor.find_best_composition(
+                vec![zergling(); 10],
+                vec![UnitTypeId::MARINE],
+                1000,
+                0,
+                200.0,
+                &combat_settings,
+                max_unit_types,
+                0,
+            );
+            assert!(result.is_err());
+        }
+    }
+
+    #[test]
+    fn test_find_minimum_force() {
+        let combat_settings = CombatSettings::new();
+        let predictor = CombatPredictor::new();
+        let enemy: Vec<CombatUnit> = vec![zergling(); 10];
+        let force = predictor
+            .find_minimum_force(
+                enemy.clone(),
+                vec![(UnitTypeId::MARINE, 1)],
+                &combat_settings,
+                1.0,
+                0.0,
+                1,
+                100,
+                0,
+            )
+            .unwrap()
+            .unwrap();
+        let count = force.units[0].1;
+        assert!(count > 0);
+
+        // One marine less loses
+        let marine = unit_database::get_unit(UnitTypeId::MARINE).unwrap();
+        let result = predictor
+            .predict_engage(
+                vec![marine.clone(); count as usize],
+                enemy.clone(),
+                0,
+                &combat_settings,
+            )
+            .unwrap();
+        assert_eq!(result.0, 1);
+        let result = predictor
+            .predict_engage(
+                vec![marine; count as usize - 1],
+                enemy.clone(),
+                0,
+                &combat_settings,
+            )
+            .unwrap();
+        assert_eq!(result.0, 2);
+
+        // Keeping half the army alive takes at least as many marines
+        let safe_force = predictor
+            .find_minimum_force(
+                enemy.clone(),
+                vec![(UnitTypeId::MARINE, 1)],
+                &combat_settings,
+                1.0,
+                0.5,
+                1,
+                100,
+                0,
+            )
+            .unwrap()
+            .unwrap();
+        assert!(safe_force.units[0].1 >= count);
+        assert!(safe_force.health_left >= safe_force.supply * 45.0 * 0.5);
+
+        // Not enough marines allowed
+        let force = predictor
+            .find_minimum_force(
+                vec![battlecruiser()],
+                vec![(UnitTypeId::MARINE, 1)],
+                &combat_settings,
+                1.0,
+                0.0,
+                1,
+                2,
+                0,
+            )
+            .unwrap();
+        assert!(force.is_none());
+    }
+
+    #[test]
+    fn test_early_out() {
+        let mut combat_settings = CombatSettings::new();
+        let predictor = CombatPredictor::new();
+        let marines: Vec<CombatUnit> = vec![marine(); 10];
+        let zerglings: Vec<CombatUnit> = vec![zergling(); 5];
+        let full = predictor
+            .predict_engage_result(marines.clone(), zerglings.clone(), 0, &combat_settings)
+            .unwrap();
+        assert_eq!(full.winner, 1);
+
+        // Player 2 is the one losing, the fight stops once the zerglings are below half health
+        combat_settings.early_out_health_fraction = 0.5;
+        let early = predictor
+            .predict_engage_result(marines.clone(), zerglings.clone(), 0, &combat_settings)
+            .unwrap();
+        assert_eq!(early.winner, 1);
+        assert!(early.time < full.time);
+        assert!(early.health_left[1] > 0.0);
+
+        combat_settings.early_out_player = 2;
+        let player2 = predictor
+            .predict_engage_result(marines.clone(), zerglings.clone(), 0, &combat_settings)
+            .unwrap();
+        assert!(player2.time < full.time);
+
+        // The marines never drop below half health, so only checking player 1 runs the whole fight
+        combat_settings.early_out_player = 1;
+        let player1 = predictor
+            .predict_engage_result(marines, zerglings, 0, &combat_settings)
+            .unwrap();
+        assert_eq!(player1.health_left[1], 0.0);
+        assert!(player1.time > early.time);
+    }
+
+    #[test]
+    fn test_trace() {
+        let mut combat_settings = CombatSettings::new();
+        let predictor = CombatPredictor::new();
+        let result = predictor
+            .predict_engage_result(vec![marine(); 5], vec![zergling(); 5], 0, &combat_settings)
+            .unwrap();
+        assert!(result.trace.is_none());
+
+        combat_settings.trace = true;
+        let result = predictor
+            .predict_engage_result(vec![marine(); 5], vec![zergling(); 5], 0, &combat_settings)
+            .unwrap();
+        let trace = result.trace.unwrap();
+        assert!(trace.iterations.len() >= 2);
+        let first = &trace.iterations[0];
+        assert_eq!(first.units.len(), 10);
+        assert_eq!(first.units.iter().filter(|u| u.side == 1).count(), 5);
+        // Every unit got a unique id
+        let mut tags: Vec<u64> = first.units.iter().map(|u| u.tag).collect();
+        tags.sort_unstable();
+        tags.dedup();
+        assert_eq!(tags.len(), 10);
+        // Copies of a tagged unit as well
+        let mut tagged = marine();
+        tagged.tag = 7;
+        let result = predictor
+            .predict_engage_result(vec![tagged; 5], vec![zergling(); 5], 0, &combat_settings)
+            .unwrap();
+        let mut copies: Vec<u64> = result.trace.unwrap().iterations[0]
+            .units
+            .iter()
+            .map(|u| u.tag)
+            .collect();
+        copies.sort_unstable();
+        copies.dedup();
+        assert_eq!(copies.len(), 10);
+
+        // Targets are enemy units and damage adds up to the health lost
+        let damage: f32 = trace
+            .iterations
+            .iter()
+            .flat_map(|i| i.units.iter())
+            .map(|u| u.damage_dealt)
+            .sum();
+        let last = trace.iterations.last().unwrap();
+        let health_lost = 5.0 * 45.0 + 5.0 * 35.0
+            - last.units.iter().map(|u| u.health + u.shield).sum::<f32>();
+        assert!((damage - health_lost).abs() < 0.1);
+        for snapshot in first.units.iter() {
+            if let Some(target) = snapshot.target {
+                let target = first.units.iter().find(|u| u.tag == target).unwrap();
+                assert_ne!(target.side, snapshot.side);
+            }
+        }
+
+        let json = trace.to_json().unwrap();
+        assert!(json.starts_with("{\"iterations\":[{\"iteration\":0"));
+        assert!(json.contains("\"type_id\":\"MARINE\""));
+    }
+
+    #[test]
+    fn test_trace_retreat() {
+        let mut combat_settings = CombatSettings::new();
+        let predictor = CombatPredictor::new();
+        combat_settings.trace = true;
+        combat_settings.retreat_threshold = 0.3;
+        combat_settings.retreat_player = 1;
+        let mut marines: Vec<CombatUnit> = vec![marine(); 10];
+        marines[0].tag = 1;
+        marines[1].tag = 2;
+        marines.push(unit_database::get_unit(UnitTypeId::MEDIVAC).unwrap());
+        let result = predictor
+            .predict_engage_result(marines, vec![zergling(); 20], 1, &combat_settings)
+            .unwrap();
+        assert_eq!(result.retreated, vec![1]);
+        let trace = result.trace.unwrap();
+
+        // Generated ids don't collide with the tags of other units
+        let first = &trace.iterations[0];
+        let mut tags: Vec<u64> = first.units.iter().map(|u| u.tag).collect();
+        tags.sort_unstable();
+        tags.dedup();
+        assert_eq!(tags.len(), 31);
+
+        // The chase and healing are part of the trace, so it ends with the health left in the result
+        let last = trace.iterations.last().unwrap();
+        for side in 1..=2 {
+            let health: f32 = last
+                .units
+                .iter()
+                .filter(|u| u.side == side)
+                .map(|u| u.health + u.shield)
+                .sum();
+            assert!((health - result.health_left[side as usize - 1]).abs() < 0.1);
+        }
+        let snapshots = || trace.iterations.iter().flat_map(|i| i.units.iter());
+        let damage: f32 = snapshots().map(|u| u.damage_dealt).sum();
+        let healing: f32 = snapshots().map(|u| u.healing_done).sum();
+        assert!(healing > 0.0);
+        let health = |i: &trace::TraceIteration| -> f32 {
+            i.units.iter().map(|u| u.health + u.shield).sum()
+        };
+        assert!((damage - healing - (health(first) - health(last))).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_predict_engage_async() {
+        with_gil(|py| {
+            let globals = pyo3::types::PyDict::new(py);
+            globals
+                .set_item("__builtins__", py.import("builtins").unwrap())
+                .unwrap();
+            globals
+                .set_item("predictor", Py::new(py, CombatPredictor::new()).unwrap())
+                .unwrap();
+            globals
+                .set_item("settings", Py::new(py, CombatSettings::new()).unwrap())
+                .unwrap();
+            py.run(
+                r#"
+import asyncio
+
+async def main():
+    futures = [predictor.predict_engage_async([], [], 0, settings) for _ in range(3)]
+    return await asyncio.gather(*futures)
+
+results = asyncio.run(main())
+"#,
+                Some(globals),
+                None,
+            )
+            .unwrap();
+            let results: Vec<combat_predictor::CombatResult> =
+                globals.get_item("results").unwrap().extract().unwrap();
+            assert_eq!(results.len(), 3);
+            assert!(results.iter().all(|r| r.health_left == vec![0.0, 0.0]));
+
+            // Predictions release the GIL, other threads use the same predictor meanwhile
+            globals
+                .set_item("CombatUnit", py.get_type::<PyCombatUnit>())
+                .unwrap();
+            py.run(
+                r#"
+from concurrent.futures import ThreadPoolExecutor
+
+marines = [CombatUnit.from_type_id(48)] * 40
+zerglings = [CombatUnit.from_type_id(105)] * 80
+with ThreadPoolExecutor(4) as executor:
+    winners = list(executor.map(lambda _: predictor.predict_engage(marines, zerglings, 0, settings)[0], range(16)))
+"#,
+                Some(globals),
+                None,
+            )
+            .unwrap();
+            let winners: Vec<u32> = globals.get_item("winners").unwrap().extract().unwrap();
+            assert_eq!(winners.len(), 16);
+        })
+    }
+
+    #[test]
+    fn test_predict_engage_batch() {
+        with_gil(|py| {
+            let combat_settings = CombatSettings::new();
+            let predictor = CombatPredictor::new();
+            let pairs = vec![
+                (vec![marine(); 10], vec![zergling(); 5]),
+                (vec![marine(); 1], vec![zergling(); 10]),
+                (vec![battlecruiser()], vec![marine(); 13]),
+            ];
+            let results = predictor.predict_engage_pairs(pairs, 0, &combat_settings);
+            let winners: Vec<u32> = results.iter().map(|r| r.winner).collect();
+            assert_eq!(winners, vec![1, 2, 2]);
+
+            // Units shared between pairs are converted once, static data comes from the unit cache
+            {
+                let mut cache = predictor.cache.lock().unwrap();
+                for unit in [marine(), zergling()] {
+                    cache.insert(UnitCacheKey::of(&unit), unit);
+                }
+            }
+            let globals = pyo3::types::PyDict::new(py);
+            globals
+                .set_item("__builtins__", py.import("builtins").unwrap())
+                .unwrap();
+            globals
+                .set_item("predictor", Py::new(py, predictor).unwrap())
+                .unwrap();
+            globals
+                .set_item("settings", Py::new(py, combat_settings).unwrap())
+                .unwrap();
+            globals
+                .set_item("CombatUnit", py.get_type::<PyCombatUnit>())
+                .unwrap();
+            py.run(
+                r#"
+from types import SimpleNamespace
+
+def unit(type_id, tag, health, ranges, movement_speed, attack_upgrade_level=0):
+    return SimpleNamespace(type_id=SimpleNamespace(value=type_id), tag=tag, health=health, health_max=health,
+                           shield=0.0, shield_max=0.0, energy=0.0, energy_max=0.0,
+                           ground_range=ranges[0], air_range=ranges[1], movement_speed=movement_speed,
+                           attack_upgrade_level=attack_upgrade_level, armor_upgrade_level=0, shield_upgrade_level=0)
+
+def marine(tag, ground_range=5.0, movement_speed=2.25, attack_upgrade_level=0):
+    return unit(48, tag, 45.0, (ground_range, 5.0), movement_speed, attack_upgrade_level)
+
+marines = [marine(i) for i in range(10)]
+zerglings = [unit(105, 100 + i, 35.0, (0.100097656, 0.0), 2.953125) for i in range(10)]
+results = predictor.predict_engage_batch([(marines, zerglings[:5]), (marines[:1], zerglings)], settings)
+
+# Upgraded units are not served from the cache, so all fields are read
+try:
+    predictor.predict_engage_batch([([marine(0, attack_upgrade_level=1)], zerglings)], settings)
+    upgraded_error = None
+except TypeError as e:
+    upgraded_error = str(e)
+
+# Same for units with a longer range, while the movement speed is always read from the unit
+try:
+    CombatUnit.from_unit(marine(0, ground_range=6.0), predictor)
+    longer_range_error = None
+except TypeError as e:
+    longer_range_error = str(e)
+faster = CombatUnit.from_unit(marine(0, movement_speed=3.0), predictor)
+
+cache_size = predictor.cache_size
+
+# Units converted on their own read the cache of the predictor they are converted for
+converted = CombatUnit.from_unit(marines[0], predictor)
+try:
+    CombatUnit.from_unit(marines[0])
+    uncached_error = None
+except TypeError as e:
+    uncached_error = str(e)
+
+predictor.clear_cache()
+cleared_size = predictor.cache_size
+"#,
+                Some(globals),
+                None,
+            )
+            .unwrap();
+            let results: Vec<combat_predictor::CombatResult> =
+                globals.get_item("results").unwrap().extract().unwrap();
+            let winners: Vec<u32> = results.iter().map(|r| r.winner).collect();
+            assert_eq!(winners, vec![1, 2]);
+            let upgraded_error: String = globals
+                .get_item("upgraded_error")
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert!(upgraded_error.contains("has no field"));
+            let longer_range_error: String = globals
+                .get_item("longer_range_error")
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert!(longer_range_error.contains("has no field"));
+            let faster: PyRef<PyCombatUnit> =
+                globals.get_item("faster").unwrap().extract().unwrap();
+            assert_eq!(faster.unit.movement_speed, 3.0);
+            assert_eq!(faster.unit.ground_range, 5.0);
+            let cache_size: usize = globals.get_item("cache_size").unwrap().extract().unwrap();
+            assert_eq!(cache_size, 2);
+            let cleared_size: usize = globals.get_item("cleared_size").unwrap().extract().unwrap();
+            assert_eq!(cleared_size, 0);
+            let converted: PyRef<PyCombatUnit> =
+                globals.get_item("converted").unwrap().extract().unwrap();
+            assert_eq!(converted.unit.name, marine().name);
+            let uncached_error: String = globals
+                .get_item("uncached_error")
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert!(uncached_error.contains("has no field"));
+
+            // The cache is kept while the game key stays the same
+            let predictor: PyRef<CombatPredictor> =
+                globals.get_item("predictor").unwrap().extract().unwrap();
+            predictor.start_game("5.0.11");
+            {
+                let mut cache = predictor.cache.lock().unwrap();
+                cache.insert(UnitCacheKey::of(&marine()), marine());
+            }
+            predictor.start_game("5.0.11");
+            assert_eq!(predictor.cache_size(), 1);
+            predictor.start_game("5.0.12");
+            assert_eq!(predictor.cache_size(), 0);
+        })
+    }
+
+    #[test]
+    fn test_conversion_errors() {
+        with_gil(|py| {
+            let namespace = py
+                .import("types")
+                .unwrap()
+                .getattr("SimpleNamespace")
+                .unwrap();
+            let kwargs = pyo3::types::PyDict::new(py);
+            kwargs.set_item("type", 1).unwrap();
+            kwargs.set_item("damage", 6.0).unwrap();
+            kwargs.set_item("attacks", 1).unwrap();
+            kwargs.set_item("range", 5.0).unwrap();
+            // Missing field
+            let weapon = namespace.call((), Some(kwargs)).unwrap();
+            let err = Weapon::from_sc2(weapon).unwrap_err();
+            assert!(err.is_instance_of::<pyo3::exceptions::PyTypeError>(py));
+            assert!(err.to_string().contains("'speed'"));
+
+            // Unknown enum value
+            kwargs.set_item("speed", 0.6).unwrap();
+            kwargs.set_item("type", 7).unwrap();
+            let weapon = namespace.call((), Some(kwargs)).unwrap();
+            let err = Weapon::from_sc2(weapon).unwrap_err();
+            assert!(err.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+            assert!(err
+                .to_string()
+                .contains("7 is not a valid WeaponTargetType"));
+
+            // Wrong type
+            kwargs.set_item("type", 1).unwrap();
+            kwargs.set_item("damage", "six").unwrap();
+            let weapon = namespace.call((), Some(kwargs)).unwrap();
+            let err = Weapon::from_sc2(weapon).unwrap_err();
+            assert!(err.is_instance_of::<pyo3::exceptions::PyTypeError>(py));
+            assert!(err.to_string().contains("'damage'"));
+
+            kwargs.set_item("damage", 6.0).unwrap();
+            let weapon = namespace.call((), Some(kwargs)).unwrap();
+            assert_eq!(
+                Weapon::from_sc2(weapon).unwrap().w_type,
+                WeaponTargetType::GROUND
+            );
+        })
+    }
+    #[test]
+    fn test_py_combat_unit() {
+        with_gil(|py| {
+            let globals = pyo3::types::PyDict::new(py);
+            globals
+                .set_item("__builtins__", py.import("builtins").unwrap())
+                .unwrap();
+            globals
+                .set_item("CombatUnit", py.get_type::<combat_unit::PyCombatUnit>())
+                .unwrap();
+            globals.set_item("Weapon", py.get_type::<Weapon>()).unwrap();
+            py.run(
+                r#"
+from enum import IntEnum
+
+class UnitTypeId(IntEnum):
+    NOTAUNIT = 0
+    MARINE = 48
+    ZERGLING = 105
+
+marine = CombatUnit.from_type_id(UnitTypeId.MARINE)
+zergling = CombatUnit(UnitTypeId.ZERGLING, 35.0, weapons=[Weapon(1, 5.0, 1, 0.1, 0.497)], attributes=[1, 3], movement_speed=4.13)
+damaged = marine.__copy__()
+damaged.health = 1.0
+damaged.attack_upgrade_level = 1
+"#,
+                Some(globals),
+                None,
+            )
+            .unwrap();
+            let marine = globals
+                .get_item("marine")
+                .unwrap()
+                .extract::<PyRef<PyCombatUnit>>()
+                .unwrap()
+                .unit
+                .clone();
+            let zergling = globals
+                .get_item("zergling")
+                .unwrap()
+                .extract::<PyRef<PyCombatUnit>>()
+                .unwrap()
+                .unit
+                .clone();
+            let damaged = globals
+                .get_item("damaged")
+                .unwrap()
+                .extract::<PyRef<PyCombatUnit>>()
+                .unwrap()
+                .unit
+                .clone();
+            assert_eq!(marine.health, 45.0);
+            assert_eq!(damaged.health, 1.0);
+            assert_eq!(damaged.attack_upgrade_level, 1);
+            assert!(zergling.is_light && zergling.is_biological);
+            assert!((zergling.ground_dps - 5.0 / 0.497).abs() < 1e-3);
+
+            let predictor = CombatPredictor::new();
+            let settings = CombatSettings::new();
+            let healthy = predictor
+                .py_predict_engage(
+                    py,
+                    to_py(py, vec![marine; 10]),
+                    to_py(py, vec![zergling.clone(); 5]),
+                    0,
+                    &settings,
+                )
+                .unwrap();
+            let hurt = predictor
+                .py_predict_engage(
+                    py,
+                    to_py(py, vec![damaged; 10]),
+                    to_py(py, vec![zergling; 5]),
+                    0,
+                    &settings,
+                )
+                .unwrap();
+            assert_eq!(healthy.0, 1);
+            assert!(hurt.1 < healthy.1);
+
+            let err = py
+                .run(
+                    "CombatUnit.from_type_id(UnitTypeId.NOTAUNIT)",
+                    Some(globals),
+                    None,
+                )
+                .unwrap_err();
+            assert!(err.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+
+            // Plain ints and python-sc2's enums, which are not ints, work as type ids too
+            py.run(
+                r#"
+from enum import Enum
+
+class Sc2UnitTypeId(Enum):
+    MARINE = 48
+
+assert CombatUnit.from_type_id(48).health == 45.0
+assert CombatUnit.from_type_id(Sc2UnitTypeId.MARINE).health == 45.0
+assert CombatUnit(Sc2UnitTypeId.MARINE, 45.0).type_id == 48
+"#,
+                Some(globals),
+                None,
+            )
+            .unwrap();
+            let err = py
+                .run("CombatUnit.from_type_id(123456)", Some(globals), None)
+                .unwrap_err();
+            assert!(err.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+            assert!(err.to_string().contains("123456 is not a valid UnitTypeId"));
+        })
+    }
+    #[test]
+    fn test_pickle() {
+        with_gil(|py| {
+            let module = extension_module(py);
+
+            let globals = pyo3::types::PyDict::new(py);
+            globals
+                .set_item("__builtins__", py.import("builtins").unwrap())
+                .unwrap();
+            globals.set_item("sc2_helper", module).unwrap();
+            globals
+                .set_item("marines", to_py(py, vec![marine(); 5]))
+                .unwrap();
+            globals
+                .set_item("zerglings", to_py(py, vec![zergling(); 5]))
+                .unwrap();
+            py.run(
+                r#"
+import pickle
+
+settings = sc2_helper.CombatSettings()
+settings.retreat_threshold = 0.5
+settings.trace = True
+settings = pickle.loads(pickle.dumps(settings))
+assert settings.retreat_threshold == 0.5 and settings.trace
+
+# Missing fields keep their default
+partial = sc2_helper.CombatSettings.from_json('{"chase_time": 2.0}')
+assert partial.chase_time == 2.0 and partial.enable_splash
+
+predictor = pickle.loads(pickle.dumps(sc2_helper.CombatPredictor()))
+marines, zerglings = pickle.loads(pickle.dumps((marines, zerglings)))
+assert marines[0].health == 45.0 and marines[0].weapons[0].damage == 6.0
+result = predictor.predict_engage_result(marines, zerglings, 0, settings)
+restored = pickle.loads(pickle.dumps(result))
+assert restored.winner == result.winner and restored.health_left == result.health_left
+assert len(restored.trace) == len(result.trace)
+iteration = pickle.loads(pickle.dumps(result.trace.iterations[0]))
+assert iteration.time == 0.0 and len(iteration.units) == len(result.trace.iterations[0].units)
+snapshot = pickle.loads(pickle.dumps(iteration.units[0]))
+assert (snapshot.tag, snapshot.health) == (iteration.units[0].tag, iteration.units[0].health)
+assert sc2_helper.CombatResult.from_json(result.to_json()).to_json() == result.to_json()
+
+weapon = pickle.loads(pickle.dumps(sc2_helper.Weapon(3, 8.0, 1, 6.0, 0.224, sc2_helper.DamageBonus(2, 4.0))))
+assert weapon.type == 3 and weapon.damage_bonus.bonus == 4.0
+cost = pickle.loads(pickle.dumps(sc2_helper.Cost(50, 25, 18.0)))
+assert (cost.minerals, cost.vespene, cost.time) == (50, 25, 18.0)
+composition = sc2_helper.Composition.from_json(
+    '{"units": [["MARINE", 10]], "minerals": 500, "vespene": 0, "supply": 10.0, "adjusted_cost": 500, '
+    '"health_left": 120.0}'
+)
+composition = pickle.loads(pickle.dumps(composition))
+assert composition.units == [(48, 10)] and composition.health_left == 120.0
+"#,
+                Some(globals),
+                None,
+            )
+            .unwrap();
+
+            let err = py
+                .run("sc2_helper.CombatUnit.from_json('{}')", Some(globals), None)
+                .unwrap_err();
+            assert!(err.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+        })
+    }
+
+    #[test]
+    fn test_int_enums() {
+        with_gil(|py| {
+            let module = extension_module(py);
+            let globals = pyo3::types::PyDict::new(py);
+            globals
+                .set_item("__builtins__", py.import("builtins").unwrap())
+                .unwrap();
+            globals.set_item("sc2_helper", module).unwrap();
+            py.run(
+                r#"
+from enum import IntEnum
+from sc2_helper import UnitTypeId, Attribute, WeaponTargetType, CombatUnit
+
+assert issubclass(UnitTypeId, IntEnum) and UnitTypeId.MARINE == 48
+assert UnitTypeId(105) is UnitTypeId.ZERGLING
+marine = CombatUnit.from_type_id(UnitTypeId.MARINE)
+assert marine.type_id == UnitTypeId.MARINE
+assert Attribute.LIGHT in marine.attributes and Attribute.BIOLOGICAL in marine.attributes
+assert marine.weapons[0].type == WeaponTargetType.ANY
+
+# python-sc2's enums are plain Enums, only their values are equal
+from enum import Enum
+Sc2UnitTypeId = Enum("UnitTypeId", [("MARINE", 48)])
+assert UnitTypeId.MARINE != Sc2UnitTypeId.MARINE and UnitTypeId.MARINE == Sc2UnitTypeId.MARINE.value
+
+# Type ids can be passed as plain ints or python-sc2's enums as well
+for type_id in [48, Sc2UnitTypeId.MARINE]:
+    assert CombatUnit.from_type_id(type_id).type_id == UnitTypeId.MARINE
+"#,
+                Some(globals),
+                None,
+            )
+            .unwrap();
+
+            // Everything the module exports is in the stub
+            let stub = include_str!("../sc2_helper/sc2_helper.pyi");
+            let names: Vec<String> = module
+                .dir()
+                .extract::<Vec<String>>()
+                .unwrap()
+                .into_iter()
+                .filter(|name| !name.starts_with('_'))
+                .collect();
+            assert!(names.len() > 10);
+            for name in names {
+                assert!(
+                    stub.contains(&format!("class {}", name))
+                        || stub.contains(&format!("def {}(", name)),
+                    "{} is missing in sc2_helper.pyi",
+                    name
+                );
+            }
+        })
+    }
+
+    #[test]
+    fn test_scenario() {
+        let scenario: scenario::Scenario = serde_json::from_str(
+            r#"{
+                "units1": [{"unit": "MARINE", "count": 10, "attack_upgrade_level": 1}],
+                "units2": [{"unit": "ZERGLING", "count": 20, "health": 20.0}],
+                "settings": {"retreat_threshold": 0.5}
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(scenario.units2[0].count, 20);
+        assert_eq!(scenario.settings.retreat_threshold, 0.5);
+        assert!(scenario.settings.enable_splash);
+
+        let predictor = CombatPredictor::new();
+        let report = scenario.run(&predictor).unwrap();
+        assert_eq!(report.health_start, vec![450.0, 400.0]);
+        assert_eq!(report.winner, 1);
+
+        let unknown: scenario::Scenario =
+            serde_json::from_str(r#"{"units1": [{"unit": "NOTAUNIT"}], "units2": []}"#).unwrap();
+        assert!(unknown.run(&predictor).is_err());
+    }
+
+    #[test]
+    fn test_matchup_matrix() {
+        let settings = CombatSettings::new();
+        let predictor = CombatPredictor::new();
+        let unit_types = [
+            UnitTypeId::MARINE,
+            UnitTypeId::ZERGLING,
+            UnitTypeId::MARAUDER,
+        ];
+        let matchups =
+            matchup::matchup_matrix(&predictor, &unit_types, &[0, 3], 1000, &settings).unwrap();
+        assert_eq!(matchups.len(), 6);
+        let marine_ling = &matchups[0];
+        assert_eq!(
+            (marine_ling.unit1, marine_ling.unit2),
+            (UnitTypeId::MARINE, UnitTypeId::ZERGLING)
+        );
+        // 1000 resources of each
+        assert_eq!((marine_ling.count1, marine_ling.count2), (20, 40));
+        // The winner destroyed more resources than it lost
+        for m in matchups.iter() {
+            assert!(m.cost_efficiency.is_finite());
+            assert_eq!(m.winner == 1, m.cost_efficiency > 1.0, "{:?}", m);
+        }
+        assert!(matchups
+            .iter()
+            .all(|m| m.upgrade_level == 0 || m.upgrade_level == 3));
+
+        let csv = matchup::to_csv(&matchups);
+        assert_eq!(csv.lines().count(), 7);
+        assert!(csv
+            .lines()
+            .nth(1)
+            .unwrap()
+            .starts_with("MARINE,ZERGLING,0,20,40,"));
+
+        assert!(matchup::combat_unit_types().contains(&UnitTypeId::MARINE));
+        assert!(matchup::matchup_matrix(
+            &predictor,
+            &[UnitTypeId::NOTAUNIT],
+            &[0],
+            1000,
+            &settings
+        )
+        .is_err());
+    }
+
+    /// Encodes `value` in the versioned format of replay tracker events.
+    #[cfg(feature = "replay")]
+    fn encode_value(value: &replay::Value, out: &mut Vec<u8>) {
+        fn vint(v: i64, out: &mut Vec<u8>) {
+            let mut n = v.unsigned_abs();
+            let mut b = (((n & 0x3F) << 1) as u8) | (v < 0) as u8;
+            n >>= 6;
+            while n > 0 {
+                out.push(b | 0x80);
+                b = (n & 0x7F) as u8;
+                n >>= 7;
+            }
+            out.push(b);
+        }
+        match value {
+            replay::Value::Array(items) => {
+                out.push(0);
+                vint(items.len() as i64, out);
+                items.iter().for_each(|v| encode_value(v, out));
+            }
+            replay::Value::Blob(bytes) => {
+                out.push(2);
+                vint(bytes.len() as i64, out);
+                out.extend(bytes);
+            }
+            replay::Value::Choice(tag, v) => {
+                out.push(3);
+                vint(*tag, out);
+                encode_value(v, out);
+            }
+            replay::Value::Optional(v) => {
+                out.extend(&[4, v.is_some() as u8]);
+                if let Some(v) = v {
+                    encode_value(v, out);
+                }
+            }
+            replay::Value::Struct(fields) => {
+                out.push(5);
+                vint(fields.len() as i64, out);
+                for (tag, v) in fields {
+                    vint(*tag, out);
+                    encode_value(v, out);
+                }
+            }
+            replay::Value::Int(v) => {
+                out.push(9);
+                vint(*v, out);
+            }
+            replay::Value::BitArray(bits, bytes) => {
+                out.push(1);
+                vint(*bits, out);
+                out.extend(bytes);
+            }
+            replay::Value::Bytes(bytes) => {
+                out.push(match bytes.len() {
+                    1 => 6,
+                    4 => 7,
+                    8 => 8,
+                    n => panic!("No fixed size value has {} bytes", n),
+                });
+                out.extend(bytes);
+            }
+        }
+    }
+
+    /// Tracker events of a fight between 10 marines with +1 weapons and 10 zerglings at (50, 50). The
+    /// zerglings lose 8 units, the marines 2.
+    #[cfg(feature = "replay")]
+    fn tracker_events() -> Vec<u8> {
+        use replay::Value::{Array, BitArray, Blob, Bytes, Choice, Int, Optional, Struct};
+        let name = |s: &str| Blob(s.as_bytes().to_vec());
+        let mut events: Vec<(i64, i64, replay::Value)> = vec![];
+        for index in 0..20 {
+            let (unit_type, player) = if index < 10 {
+                ("Marine", 1)
+            } else {
+                ("Zergling", 2)
+            };
+            let fields = vec![
+                Int(index + 1),
+                Int(1),
+                name(unit_type),
+                Int(player),
+                Int(player),
+                Int(50),
+                Int(50),
+            ];
+            events.push((
+                0,
+                1,
+                Struct(
+                    fields
+                        .into_iter()
+                        .enumerate()
+                        .map(|(t, v)| (t as i64, v))
+                        .collect(),
+                ),
+            ));
+        }
+        events.push((
+            10,
+            5,
+            Struct(vec![
+                (0, Int(1)),
+                (1, name("TerranInfantryWeaponsLevel1")),
+                (2, Int(1)),
+            ]),
+        ));
+        // A game loop per second, marines die first
+        for (i, index) in [1, 2, 11, 12, 13, 14, 15, 16, 17, 18].iter().enumerate() {
+            let killer = if *index <= 10 { 2 } else { 1 };
+            events.push((
+                if i == 0 { 1000 } else { 22 },
+                2,
+                Struct(vec![
+                    (0, Int(*index)),
+                    (1, Int(1)),
+                    (2, Optional(Some(Box::new(Int(killer))))),
+                    (3, Int(50)),
+                    (4, Int(50)),
+                ]),
+            ));
+        }
+        // Positions of the surviving units, divided by 4
+        let mut items = vec![];
+        for index in [3, 4, 5, 6, 7, 8, 9, 10, 19, 20].iter() {
+            items.extend(vec![
+                Int(if items.is_empty() { 0 } else { 1 }),
+                Int(12),
+                Int(13),
+            ]);
+            if *index == 19 {
+                let last = items.len() - 3;
+                items[last] = Int(9);
+            }
+        }
+        events.push((100, 8, Struct(vec![(0, Int(3)), (1, Array(items))])));
+        // Player stats are skipped, but their bit arrays and fixed size values have to be read past
+        events.push((
+            0,
+            0,
+            Struct(vec![
+                (0, BitArray(10, vec![0xFF, 0xC0])),
+                (1, Bytes(vec![1])),
+                (2, Bytes(vec![0; 4])),
+                (3, Bytes(vec![0; 8])),
+            ]),
+        ));
+
+        let mut data = vec![];
+        for (delta, event_id, event) in events {
+            encode_value(&Choice(1, Box::new(Int(delta))), &mut data);
+            encode_value(&Int(event_id), &mut data);
+            encode_value(&event, &mut data);
+        }
+        data
+    }
+
+    /// MPQ archive with a single zlib compressed file.
+    #[cfg(feature = "replay")]
+    fn mpq_archive(name: &str, content: &[u8]) -> Vec<u8> {
+        use flate2::write::ZlibEncoder;
+        use std::io::Write;
+        let mut encoder = ZlibEncoder::new(vec![2u8], flate2::Compression::default());
+        encoder.write_all(content).unwrap();
+        let compressed = encoder.finish().unwrap();
+        mpq_archive_block(name, &compressed, content.len(), 0x8100_0200)
+    }
+
+    /// Archive with the file `name` stored as `block` with the block table `flags`.
+    #[cfg(feature = "replay")]
+    fn mpq_archive_block(name: &str, block: &[u8], size: usize, flags: u32) -> Vec<u8> {
+        let mut table = [0u32; 0x500];
+        let mut seed: u32 = 0x0010_0001;
+        for i in 0..0x100 {
+            for j in 0..5 {
+                seed = (seed * 125 + 3) % 0x002A_AAAB;
+                let high = (seed & 0xFFFF) << 16;
+                seed = (seed * 125 + 3) % 0x002A_AAAB;
+                table[i + j * 0x100] = high | (seed & 0xFFFF);
+            }
+        }
+        let encrypt = |values: &[u32], key: u32| -> Vec<u8> {
+            let (mut seed1, mut seed2) = (key, 0xEEEE_EEEEu32);
+            let mut out = vec![];
+            for &value in values {
+                seed2 = seed2.wrapping_add(table[0x400 + (seed1 & 0xFF) as usize]);
+                out.extend(&(value ^ seed1.wrapping_add(seed2)).to_le_bytes());
+                seed1 = ((!seed1 << 0x15).wrapping_add(0x1111_1111)) | (seed1 >> 0x0B);
+                seed2 = value
+                    .wrapping_add(seed2)
+                    .wrapping_add(seed2 << 5)
+                    .wrapping_add(3);
+            }
+            out
+        };
+
+        let hash_entries = 4;
+        let mut hash_table = vec![0xFFFF_FFFFu32; hash_entries * 4];
+        let slot = mpq::hash_string(name, 0) as usize % hash_entries;
+        hash_table[slot * 4..slot * 4 + 4].copy_from_slice(&[
+            mpq::hash_string(name, 1),
+            mpq::hash_string(name, 2),
+            0,
+            0,
+        ]);
+        let block_table = [32, block.len() as u32, size as u32, flags];
+
+        let hash_table_offset = 32 + block.len() as u32;
+        let block_table_offset = hash_table_offset + hash_entries as u32 * 16;
+        let mut archive = b"MPQ\x1a".to_vec();
+        for value in [32, block_table_offset + 16].iter() {
+            archive.extend(&value.to_le_bytes());
+        }
+        archive.extend(&[0, 0, 3, 0]);
+        for value in [
+            hash_table_offset,
+            block_table_offset,
+            hash_entries as u32,
+            1,
+        ]
+        .iter()
+        {
+            archive.extend(&value.to_le_bytes());
+        }
+        archive.extend(block);
+        archive.extend(encrypt(&hash_table, mpq::hash_string("(hash table)", 3)));
+        archive.extend(encrypt(&block_table, mpq::hash_string("(block table)", 3)));
+        // Replays start with a user data block
+        let mut replay = b"MPQ\x1b".to_vec();
+        for value in [16u32, 16, 16].iter() {
+            replay.extend(&value.to_le_bytes());
+        }
+        replay.extend(archive);
+        replay
+    }
+
+    #[cfg(feature = "replay")]
+    #[test]
+    fn test_replay() {
+        assert_eq!(mpq::hash_string("(hash table)", 3), 0xC3AF_3770);
+        assert_eq!(mpq::hash_string("(block table)", 3), 0xEC83_B3A3);
+
+        let data = tracker_events();
+        let archive =
+            mpq::MpqArchive::from_bytes(mpq_archive("replay.tracker.events", &data)).unwrap();
+        assert_eq!(archive.read_file("replay.tracker.events").unwrap(), data);
+        assert!(archive.read_file("replay.game.events").is_err());
+        assert!(mpq::MpqArchive::from_bytes(b"not an archive".to_vec()).is_err());
+        // Corrupt archives are errors, not panics
+        let mut corrupt = mpq_archive("replay.tracker.events", &data);
+        corrupt[16 + 14] = 23;
+        assert!(mpq::MpqArchive::from_bytes(corrupt).is_err());
+        let mut sectors = vec![];
+        for offset in [8u32, 4].iter() {
+            sectors.extend(&offset.to_le_bytes());
+        }
+        sectors.extend(&[0; 8]);
+        let corrupt = mpq_archive_block("replay.tracker.events", &sectors, 10, 0x8000_0200);
+        let archive = mpq::MpqArchive::from_bytes(corrupt).unwrap();
+        assert!(archive.read_file("replay.tracker.events").is_err());
+
+        let events = replay::decode_tracker_events(&data).unwrap();
+        assert_eq!(events.len(), 32);
+        assert_eq!(
+            events[0],
+            replay::TimedEvent {
+                game_loop: 0,
+                event: replay::TrackerEvent::UnitBorn {
+                    tag: (1 << 18) + 1,
+                    unit_type: "Marine".to_string(),
+                    player: 1,
+                    x: 50.0,
+                    y: 50.0,
+                },
+            }
+        );
+        match &events[31].event {
+            replay::TrackerEvent::UnitPositions { units } => {
+                assert_eq!(units.len(), 10);
+                assert_eq!(units[0], (3, 48.0, 52.0));
+                assert_eq!(units[9].0, 20);
+            }
+            e => panic!("{:?}", e),
+        }
+        assert!(replay::decode_tracker_events(&data[..data.len() - 1]).is_err());
+        // Negative game loop deltas, huge bit arrays and deeply nested values
+        assert!(replay::decode_tracker_events(&[9, 0, 9, 0, 9, 0]).is_ok());
+        assert!(replay::decode_tracker_events(&[9, 3, 9, 0, 9, 0]).is_err());
+        assert!(replay::decode_tracker_events(&[9, 0, 9, 0, 1, 35]).is_err());
+        let mut nested = vec![9, 0, 9, 0];
+        nested.extend([4, 1].repeat(1000));
+        nested.extend(&[9, 0]);
+        assert!(replay::decode_tracker_events(&nested).is_err());
+
+        let replay = replay::Replay::from_events("test".to_string(), &events).unwrap();
+        assert_eq!(replay.players, [1, 2]);
+        assert_eq!(
+            replay.upgrades_at(1, 1000),
+            vec!["TerranInfantryWeaponsLevel1"]
+        );
+        let engagements = replay.engagements(&replay::EngagementOptions::default());
+        assert_eq!(engagements.len(), 1);
+        let engagement = &engagements[0];
+        assert_eq!(
+            (engagement.armies[0].len(), engagement.armies[1].len()),
+            (10, 10)
+        );
+        assert_eq!(engagement.armies[0].iter().filter(|u| u.died).count(), 2);
+        assert!(replay::Replay::from_events("test".to_string(), &events[..10]).is_err());
+        assert_eq!(
+            replay::unit_type_from_name("SiegeTankSieged"),
+            Some(UnitTypeId::SIEGETANKSIEGED)
+        );
+
+        let mut marine = marine();
+        benchmark::apply_upgrades(
+            &mut marine,
+            &[
+                "TerranInfantryWeaponsLevel1",
+                "TerranInfantryWeaponsLevel2",
+                "TerranInfantryArmorsLevel1",
+                "TerranVehicleWeaponsLevel3",
+            ],
+        );
+        assert_eq!(
+            (
+                marine.attack_upgrade_level,
+                marine.armor_upgrade_level,
+                marine.shield_upgrade_level
+            ),
+            (2, 1, 0)
+        );
+    }
+
+    #[cfg(feature = "replay")]
+    #[test]
+    fn test_benchmark_replays() {
+        let path = std::env::temp_dir().join("sc2_helper_test_benchmark.SC2Replay");
+        std::fs::write(
+            &path,
+            mpq_archive("replay.tracker.events", &tracker_events()),
+        )
+        .unwrap();
+        let missing = std::env::temp_dir().join("sc2_helper_missing.SC2Replay");
+        let report = benchmark::benchmark(
+            &CombatPredictor::new(),
+            &[path.clone(), missing],
+            &CombatSettings::new(),
+            &replay::EngagementOptions::default(),
+        );
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(report.replays, 2);
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.engagements.len(), 1);
+        let engagement = &report.engagements[0];
+        assert_eq!(engagement.units1, vec![(UnitTypeId::MARINE, 10)]);
+        assert_eq!(engagement.units2, vec![(UnitTypeId::ZERGLING, 10)]);
+        assert_eq!(engagement.winner, 1);
+        assert_eq!(engagement.predicted_winner, 1);
+        assert_eq!(engagement.health_left, vec![0.8, 0.2]);
+        assert_eq!(report.accuracy, 1.0);
+        assert!(report.health_left_error < 0.5);
+        assert_eq!(benchmark::to_csv(&report.engagements).lines().count(), 2);
+        // Replay paths with separators are quoted
+        let mut engagements = report.engagements.clone();
+        engagements[0].replay = "a \"b\", c.SC2Replay".to_string();
+        let csv = benchmark::to_csv(&engagements);
+        assert!(csv
+            .lines()
+            .nth(1)
+            .unwrap()
+            .starts_with("\"a \"\"b\"\", c.SC2Replay\","));
+    }
+
+    #[test]
+    fn test_calibration() {
+        let settings: CombatSettings =
+            serde_json::from_str(r#"{"params": {"healing_per_second": 0.0}}"#).unwrap();
+        assert_eq!(settings.params.healing_per_second, 0.0);
+        assert_eq!(settings.params.zealot_radius, 0.5);
+        assert_eq!(CombatSettings::new().params, CombatModelParams::default());
+
+        // Medivacs heal the marines
+        let mut units1 = vec![marine(); 8];
+        units1.extend(vec![
+            unit_database::get_unit(UnitTypeId::MEDIVAC).unwrap();
+            2
+        ]);
+        let predict = |settings: &CombatSettings| {
+            CombatPredictor::new()._predict_engage(
+                units1.clone(),
+                vec![zergling(); 16],
+                vec![],
+                vec![],
+                0,
+                settings,
+            )
+        };
+        let mut healing = settings.clone();
+        healing.params.healing_per_second = 20.0;
+        assert!(predict(&healing).health_left[0] > predict(&settings).health_left[0]);
+
+        let dataset = r#"{"engagements": [
+            {"units1": [{"unit": "MARINE", "count": 10}], "units2": [{"unit": "ZERGLING", "count": 20}], "winner": 2},
+            {"units1": [{"unit": "MARINE", "count": 8}, {"unit": "MEDIVAC", "count": 2}],
+             "units2": [{"unit": "ZERGLING", "count": 16}], "winner": 1, "health_left": [0.5, 0.0]},
+            {"units1": [{"unit": "ZEALOT", "count": 6}], "units2": [{"unit": "MARINE", "count": 12}], "winner": 2}
+        ]}"#;
+        let dataset: calibration::Dataset = serde_json::from_str(dataset).unwrap();
+        let predictor = CombatPredictor::new();
+        let params = CombatModelParams::default();
+        let result =
+            calibration::calibrate(&predictor, &dataset.engagements, &params, &[], 3).unwrap();
+        assert!(result.accuracy >= result.initial_accuracy);
+        assert!(result.evaluations > 1);
+        let names = vec!["healing_per_second".to_string()];
+        let result =
+            calibration::calibrate(&predictor, &dataset.engagements, &params, &names, 3).unwrap();
+        assert_eq!(
+            CombatModelParams {
+                healing_per_second: result.params.healing_per_second,
+                ..CombatModelParams::default()
+            },
+            result.params
+        );
+        assert!(calibration::calibrate(
+            &predictor,
+            &dataset.engagements,
+            &params,
+            &["speed".to_string()],
+            1
+        )
+        .is_err());
+        assert!(calibration::calibrate(&predictor, &[], &params, &[], 1).is_err());
+    }
+
+    #[test]
+    fn test_terrain() {
+        let settings: CombatSettings =
+            serde_json::from_str(r#"{"terrain": {"high_ground_player": 2, "creep_players": [1]}}"#)
+                .unwrap();
+        assert_eq!(settings.terrain.high_ground_player, 2);
+        assert_eq!(settings.terrain.swapped().high_ground_player, 1);
+        assert_eq!(settings.terrain.swapped().creep_players, vec![2]);
+        assert_eq!(CombatSettings::new().terrain, Terrain::default());
+
+        let predict = |units1: Vec<CombatUnit>, units2: Vec<CombatUnit>, terrain: &Terrain| {
+            let mut settings = CombatSettings::new();
+            settings.terrain = terrain.clone();
+            CombatPredictor::new()._predict_engage(units1, units2, vec![], vec![], 0, &settings)
+        };
+
+        // Marines below can't shoot marines on the high ground without vision
+        let mut high_ground = Terrain::new();
+        high_ground.high_ground_player = 2;
+        let result = predict(vec![marine(); 10], vec![marine(); 10], &high_ground);
+        assert_eq!(result.winner, 2);
+        assert_eq!(result.health_left[1], 450.0);
+        high_ground.high_ground_vision = true;
+        let result = predict(vec![marine(); 10], vec![marine(); 10], &high_ground);
+        assert!(result.health_left[1] < 450.0);
+        // A medivac gives vision as well
+        high_ground.high_ground_vision = false;
+        let mut units1 = vec![marine(); 10];
+        units1.push(unit_database::get_unit(UnitTypeId::MEDIVAC).unwrap());
+        let result = predict(units1, vec![marine(); 10], &high_ground);
+        assert!(result.health_left[1] < 450.0);
+
+        // Fewer zerglings get to attack through a choke
+        let mut choke = Terrain::new();
+        choke.choke_width = 1.0;
+        let open = predict(vec![zergling(); 16], vec![marine(); 8], &Terrain::new());
+        assert_eq!(open.winner, 1);
+        let narrow = predict(vec![zergling(); 16], vec![marine(); 8], &choke);
+        assert_eq!(narrow.winner, 2);
+
+        let mut creep = Terrain::new();
+        creep.creep_players = vec![1];
+        let mut units = [zergling(), marine()];
+        creep.apply_creep(2, units.iter_mut());
+        assert_eq!(units[0].movement_speed, zergling().movement_speed);
+        creep.apply_creep(1, units.iter_mut());
+        assert_eq!(units[0].movement_speed, zergling().movement_speed * 1.3);
+        assert_eq!(units[1].movement_speed, marine().movement_speed);
+    }
+
+    /// 64x40 map: high ground on the left, a ramp down to the middle and a 4 tiles wide gap in a wall to the
+    /// right. Returns the pathing, placement and terrain height grids.
+    fn map_grids() -> (Grid, Grid, Grid) {
+        let (width, height) = (64, 40);
+        let mut pathing = vec![0; width * height];
+        let mut placement = vec![0; width * height];
+        let mut terrain_height = vec![100; width * height];
+        for y in 1..height - 1 {
+            for x in 1..width - 1 {
+                let i = y * width + x;
+                let ramp = (20..24).contains(&x) && (18..23).contains(&y);
+                let cliff = (20..24).contains(&x) && !ramp;
+                let wall = (40..42).contains(&x) && !(18..22).contains(&y);
+                pathing[i] = (!cliff && !wall) as u8;
+                placement[i] = (!cliff && !wall && !ramp) as u8;
+                terrain_height[i] = match x {
+                    0..=19 => 200,
+                    20..=23 => 200 - 25 * (x as u8 - 19),
+                    _ => 100,
+                };
+            }
+        }
+        (
+            Grid::new(width, height, pathing).unwrap(),
+            Grid::new(width, height, placement).unwrap(),
+            Grid::new(width, height, terrain_height).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_map_analysis() {
+        assert_eq!(
+            Grid::new(8, 1, vec![0b1010_0000]).unwrap().data,
+            vec![1, 0, 1, 0, 0, 0, 0, 0]
+        );
+        assert!(Grid::new(8, 2, vec![0; 3]).is_err());
+
+        let (pathing, placement, terrain_height) = map_grids();
+        let minerals: Vec<(f32, f32)> = (0..6).map(|i| (52.0 + i as f32, 3.5)).collect();
+        let analysis = MapAnalysis::new(
+            pathing.clone(),
+            placement.clone(),
+            terrain_height.clone(),
+            &minerals,
+            &[(60.5, 8.5)],
+            8.0,
+        )
+        .unwrap();
+        assert_eq!(analysis.regions.len(), 3);
+        let high = analysis.region_at((10.0, 20.0)).unwrap();
+        let middle = analysis.region_at((30.0, 20.0)).unwrap();
+        let right = analysis.region_at((50.0, 20.0)).unwrap();
+        assert!(analysis.regions[high].height > analysis.regions[middle].height);
+
+        assert_eq!(analysis.ramps.len(), 1);
+        let ramp = &analysis.ramps[0];
+        assert_eq!(ramp.regions, vec![high, middle]);
+        assert_eq!(ramp.width, 5.0);
+        assert!(ramp.upper.0 < ramp.lower.0);
+        assert_eq!(analysis.regions[high].ramps, vec![0]);
+
+        assert_eq!(analysis.chokes.len(), 1);
+        let choke = &analysis.chokes[0];
+        assert_eq!(choke.regions, vec![middle, right]);
+        assert_eq!(choke.width, 4.0);
+        assert_eq!(analysis.region_at(choke.center), None);
+
+        assert_eq!(analysis.expansion_locations.len(), 1);
+        let expansion = analysis.expansion_locations[0];
+        assert_eq!(analysis.region_at(expansion), Some(right));
+        assert!(minerals.iter().all(|m| {
+            ((m.0 - expansion.0).powi(2) + (m.1 - expansion.1).powi(2)).sqrt() >= 6.0
+        }));
+
+        let terrain = analysis.terrain((10.0, 20.0), (30.0, 20.0));
+        assert_eq!(terrain.high_ground_player, 1);
+        assert_eq!(terrain.choke_width, 5.0);
+        let terrain = analysis.terrain((30.0, 10.0), (30.0, 30.0));
+        assert_eq!(terrain, Terrain::new());
+        assert!(analysis
+            .pathable_points_inside_circle((22.0, 10.0), 3.0)
+            .iter()
+            .all(|&(x, _)| !(20..24).contains(&x)));
+
+        // From Python, as bytes and as 2-dimensional buffers
+        with_gil(|py| {
+            let module = extension_module(py);
+            let class = module.getattr("MapAnalysis").unwrap();
+            let bytes = |grid: &Grid| pyo3::types::PyBytes::new(py, &grid.data);
+            let from_bytes = class
+                .call(
+                    (
+                        bytes(&pathing),
+                        bytes(&placement),
+                        bytes(&terrain_height),
+                        (64, 40),
+                    ),
+                    None,
+                )
+                .unwrap();
+            assert_eq!(from_bytes.getattr("regions").unwrap().len().unwrap(), 3);
+            let memoryview = py
+                .import("builtins")
+                .unwrap()
+                .getattr("memoryview")
+                .unwrap();
+            let buffer = |grid: &Grid| {
+                memoryview
+                    .call1((bytes(grid),))
+                    .unwrap()
+                    .call_method1("cast", ("B", (40, 64)))
+                    .unwrap()
+            };
+            let from_buffers = class
+                .call1((
+                    buffer(&pathing),
+                    buffer(&placement),
+                    buffer(&terrain_height),
+                ))
+                .unwrap();
+            assert_eq!(
+                from_buffers
+                    .getattr("width")
+                    .unwrap()
+                    .extract::<usize>()
+                    .unwrap(),
+                64
+            );
+            assert_eq!(from_buffers.getattr("chokes").unwrap().len().unwrap(), 1);
+            assert!(class
+                .call1((bytes(&pathing), bytes(&placement), bytes(&terrain_height)))
+                .is_err());
+            let pickle = py.import("pickle").unwrap();
+            let copy = pickle
+                .call_method1(
+                    "loads",
+                    (pickle.call_method1("dumps", (from_buffers,)).unwrap(),),
+                )
+                .unwrap();
+            assert_eq!(copy.getattr("ramps").unwrap().len().unwrap(), 1);
+        });
+
+        // Unpickled grids are checked against their size
+        let json = analysis.to_json().unwrap();
+        assert!(MapAnalysis::from_json(&json).is_ok());
+        let short_grid = json.replacen("\"data\":[0,", "\"data\":[", 1);
+        assert!(MapAnalysis::from_json(&short_grid).is_err());
+        let short_regions = json.replacen("\"region_grid\":[-1,", "\"region_grid\":[", 1);
+        assert!(MapAnalysis::from_json(&short_regions).is_err());
+    }
+
+    #[test]
+    fn test_pathfinding() {
+        let length = |path: &[(f32, f32)]| -> f32 {
+            path.windows(2)
+                .map(|w| ((w[1].0 - w[0].0).powi(2) + (w[1].1 - w[0].1).powi(2)).sqrt())
+                .sum()
+        };
+        let (pathing, placement, terrain_height) = map_grids();
+        let finder = MapAnalysis::new(pathing.clone(), placement, terrain_height, &[], &[], 8.0)
+            .unwrap()
+            .path_finder();
+        // Down the ramp and through the gap in the wall
+        let path = finder
+            .path((10.5, 30.5), (50.5, 30.5), 0.0, None, true)
+            .unwrap();
+        assert_eq!(path[0], (10.5, 30.5));
+        assert_eq!(*path.last().unwrap(), (50.5, 30.5));
+        assert!(path.len() <= 6);
+        assert!(path
+            .iter()
+            .all(|p| finder.is_passable(p.0 as isize, p.1 as isize, 0.0)));
+        let unsmoothed = finder
+            .path((10.5, 30.5), (50.5, 30.5), 0.0, None, false)
+            .unwrap();
+        assert!(length(&path) <= length(&unsmoothed) + 1e-3);
+        // The gap is 4 tiles wide
+        assert!(finder
+            .path((30.5, 20.5), (50.5, 20.5), 1.5, None, true)
+            .is_some());
+        assert!(finder
+            .path((30.5, 20.5), (50.5, 20.5), 2.0, None, true)
+            .is_none());
+        assert!(finder
+            .path((30.5, 20.5), (41.5, 10.5), 0.0, None, true)
+            .is_none());
+
+        // Jump point search finds paths as short as A*
+        let (width, height) = (48, 48);
+        let mut seed: u32 = 12345;
+        let maze: Vec<u8> = (0..width * height)
+            .map(|_| {
+                seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+                ((seed >> 16) % 100 >= 30) as u8
+            })
+            .collect();
+        let finder = PathFinder::new(Grid::new(width, height, maze).unwrap());
+        let ones = vec![1.0; width * height];
+        let mut found = 0;
+        for i in 0..40 {
+            let start = ((i * 7 % width) as f32 + 0.5, (i * 13 % height) as f32 + 0.5);
+            let goal = (
+                (i * 29 % width) as f32 + 0.5,
+                (i * 31 % height) as f32 + 0.5,
+            );
+            let jps = finder.path(start, goal, 0.0, None, false);
+            let astar = finder.path(start, goal, 0.0, Some(&ones), false);
+            assert_eq!(jps.is_some(), astar.is_some());
+            if let (Some(jps), Some(astar)) = (jps, astar) {
+                assert!((length(&jps) - length(&astar)).abs() < 1e-3);
+                found += 1;
+            }
+        }
+        assert!(found > 10);
+        let queries: Vec<pathfinding::PathQuery> = (0..8)
+            .map(|i| ((0.5 + i as f32, 0.5), (47.5 - i as f32, 47.5), 0.0))
+            .collect();
+        let batch = finder.paths(&queries, None, true);
+        for (query, path) in queries.iter().zip(batch) {
+            assert_eq!(path, finder.path(query.0, query.1, query.2, None, true));
+        }
+
+        // Expensive tiles are avoided
+        let open = PathFinder::new(Grid::new(20, 20, vec![1; 400]).unwrap());
+        let mut weights = vec![1.0; 400];
+        for y in 3..20 {
+            weights[y * 20 + 10] = 20.0;
+        }
+        let direct = open
+            .path((2.5, 10.5), (18.5, 10.5), 0.0, None, true)
+            .unwrap();
+        assert_eq!(direct.len(), 2);
+        let detour = open
+            .path((2.5, 10.5), (18.5, 10.5), 0.0, Some(&weights), true)
+            .unwrap();
+        assert!(detour.iter().any(|p| p.1 < 3.0));
+        weights[2 * 20 + 10] = 0.0;
+        weights[20 + 10] = 0.0;
+        weights[10] = f32::INFINITY;
+        let blocked = open
+            .path((2.5, 10.5), (18.5, 10.5), 0.0, Some(&weights), true)
+            .unwrap();
+        assert!(blocked.iter().all(|p| p.1 >= 3.0));
+
+        with_gil(|py| {
+            let module = extension_module(py);
+            let class = module.getattr("PathFinder").unwrap();
+            let finder = class
+                .call1((pyo3::types::PyBytes::new(py, &pathing.data), (64, 40)))
+                .unwrap();
+            let path: Option<pathfinding::Path> = finder
+                .call_method1("find_path", ((10.5, 30.5), (50.5, 30.5)))
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert!(path.is_some());
+            let weights = vec![vec![1.0; 64]; 40];
+            let kwargs = [("weights", weights)].into_py_dict(py);
+            let paths: Vec<Option<pathfinding::Path>> = finder
+                .call_method(
+                    "find_paths",
+                    (vec![((10.5, 30.5), (50.5, 30.5), 0.5)],),
+                    Some(kwargs),
+                )
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(paths.len(), 1);
+            assert!(paths[0].is_some());
+            let kwargs = [("weights", vec![vec![1.0; 3]; 3])].into_py_dict(py);
+            assert!(finder
+                .call_method("find_path", ((10.5, 30.5), (50.5, 30.5)), Some(kwargs))
+                .is_err());
+        });
+    }
+
+    #[test]
+    fn test_influence_map() {
+        let mut influence = influence::InfluenceMap::new(32, 32);
+        influence.add_unit(&marine(), (16.0, 16.0), 0.0);
+        influence.add_unit(&zergling(), (16.0, 16.0), 1.0);
+        let marine_dps = marine().ground_dps;
+        assert!(
+            (influence.threat_at((16.5, 16.5), false) - marine_dps - zergling().ground_dps).abs()
+                < 1e-4
+        );
+        assert!((influence.threat_at((16.5, 16.5), true) - marine_dps).abs() < 1e-4);
+        assert!(influence.threat_at((20.5, 16.5), false) > 0.0);
+        assert_eq!(influence.threat_at((23.5, 16.5), false), 0.0);
+        assert_eq!(influence.threat_at((-1.0, 16.5), true), 0.0);
+        let safe = influence
+            .find_safe_point((16.0, 16.0), 10.0, false, 0.0, None, 0.0)
+            .unwrap();
+        assert_eq!(influence.threat_at(safe, false), 0.0);
+        assert!(((safe.0 - 16.0).powi(2) + (safe.1 - 16.0).powi(2)).sqrt() < 7.0);
+        assert!(influence
+            .safe_points((16.0, 16.0), 5.0, true, 0.0)
+            .is_empty());
+
+        // Threat-weighted paths go around the marine
+        let finder = PathFinder::new(Grid::new(32, 32, vec![1; 32 * 32]).unwrap());
+        let direct = finder
+            .path((2.5, 16.5), (29.5, 16.5), 0.0, None, true)
+            .unwrap();
+        assert_eq!(direct.len(), 2);
+        let weights = influence.weights(false, 1.0);
+        let detour = finder
+            .path((2.5, 16.5), (29.5, 16.5), 0.0, Some(&weights), true)
+            .unwrap();
+        assert!(detour.iter().any(|p| (p.1 - 16.0).abs() > 5.0));
+
+        // Unpickled maps are checked against their size
+        let restored = influence::InfluenceMap::from_json(&influence.to_json().unwrap()).unwrap();
+        assert_eq!(
+            restored.threat_at((16.5, 16.5), true),
+            influence.threat_at((16.5, 16.5), true)
+        );
+        assert!(influence::InfluenceMap::from_json(
+            r#"{"width": 32, "height": 32, "ground": [0.0], "air": [0.0]}"#
+        )
+        .is_err());
+
+        with_gil(|py| {
+            let module = extension_module(py);
+            let unit = PyCell::new(py, PyCombatUnit { unit: marine() }).unwrap();
+            let influence = module
+                .getattr("InfluenceMap")
+                .unwrap()
+                .call1((32, 32))
+                .unwrap();
+            influence
+                .call_method1("add_units", (vec![(unit, (16.0, 16.0))],))
+                .unwrap();
+            let threat: f32 = influence
+                .call_method1("threat_at", ((16.5, 16.5),))
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert!((threat - marine_dps).abs() < 1e-4);
+            let finder = PyCell::new(py, finder).unwrap();
+            let path: Option<pathfinding::Path> = influence
+                .call_method1("find_path", (finder, (2.5, 16.5), (29.5, 16.5)))
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert!(path.unwrap().len() > 2);
+            let small = PathFinder::new(Grid::new(8, 8, vec![1; 64]).unwrap());
+            assert!(influence
+                .call_method1(
+                    "find_path",
+                    (PyCell::new(py, small).unwrap(), (2.5, 2.5), (5.5, 5.5))
+                )
+                .is_err());
+            influence.call_method0("clear").unwrap();
+            let rows: Vec<Vec<f32>> = influence
+                .call_method1("rows", (true,))
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(rows.len(), 32);
+            assert!(rows.iter().flatten().all(|&v| v == 0.0));
+        });
+    }
+
+    #[test]
+    fn test_grid_ops() {
+        with_gil(|py| {
+            let module = extension_module(py);
+            let builtins = py.import("builtins").unwrap();
+            let grid = |format: &str, item_size: usize| {
+                builtins
+                    .getattr("memoryview")
+                    .unwrap()
+                    .call1((pyo3::types::PyByteArray::new(
+                        py,
+                        &vec![0; 12 * 10 * item_size],
+                    ),))
+                    .unwrap()
+                    .call_method1("cast", (format, (10, 12)))
+                    .unwrap()
+            };
+            let points = find_points_inside_circle((5.0, 4.0), 3.0, 10, 12);
+            let floats = grid("f", 4);
+            for _ in 0..2 {
+                module
+                    .getattr("add_inside_circle")
+                    .unwrap()
+                    .call1((floats, (5.0, 4.0), 3.0, 1.5))
+                    .unwrap();
+            }
+            let values: Vec<Vec<f32>> = floats.call_method0("tolist").unwrap().extract().unwrap();
+            for (y, row) in values.iter().enumerate() {
+                for (x, &value) in row.iter().enumerate() {
+                    let expected = if points.contains(&(x, y)) { 3.0 } else { 0.0 };
+                    assert_eq!(value, expected);
+                }
+            }
+            let bytes = grid("B", 1);
+            module
+                .getattr("set_inside_circle")
+                .unwrap()
+                .call1((bytes, (5.0, 4.0), 3.0, 300.0))
+                .unwrap();
+            let values: Vec<Vec<u8>> = bytes.call_method0("tolist").unwrap().extract().unwrap();
+            assert_eq!(
+                values.iter().flatten().filter(|&&v| v == 255).count(),
+                points.len()
+            );
+            // Read-only, 1-dimensional and non-contiguous buffers are rejected
+            let set = module.getattr("set_inside_circle").unwrap();
+            assert!(set
+                .call1((
+                    pyo3::types::PyBytes::new(py, &[0; 120]),
+                    (5.0, 4.0),
+                    3.0,
+                    1.0
+                ))
+                .is_err());
+            assert!(set
+                .call1((
+                    floats.call_method1("cast", ("B",)).unwrap(),
+                    (5.0, 4.0),
+                    3.0,
+                    1.0
+                ))
+                .is_err());
+            let read_only = builtins
+                .getattr("memoryview")
+                .unwrap()
+                .call1((pyo3::types::PyBytes::new(py, &[0; 120]),))
+                .unwrap()
+                .call_method1("cast", ("B", (10, 12)))
+                .unwrap();
+            assert!(set.call1((read_only, (5.0, 4.0), 3.0, 1.0)).is_err());
+
+            let indices =
+                module
+                    .getattr("circle_indices")
+                    .unwrap()
+                    .call1(((5.0, 4.0), 3.0, 10, 12));
+            if py.import("numpy").is_ok() {
+                let (ys, xs): (Vec<usize>, Vec<usize>) = indices
+                    .unwrap()
+                    .extract::<(&PyAny, &PyAny)>()
+                    .map(|(ys, xs)| (ys.extract().unwrap(), xs.extract().unwrap()))
+                    .unwrap();
+                assert_eq!(xs.into_iter().zip(ys).collect::<Vec<_>>(), points);
+                // Views with gaps between the cells would need copying
+                let strided = py
+                    .eval(
+                        "__import__('numpy').zeros((10, 24), 'float32')[:, ::2]",
+                        None,
+                        None,
+                    )
+                    .unwrap();
+                assert!(set.call1((strided, (5.0, 4.0), 3.0, 1.0)).is_err());
+            } else {
+                assert!(indices.is_err());
+            }
+        });
+    }
+
+    #[test]
+    fn test_spatial_index() {
+        let mut seed: u32 = 777;
+        let mut random = move || {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            (seed >> 8) as f32 / (1 << 24) as f32
+        };
+        let units: Vec<spatial::IndexedUnit> = (0..300)
+            .map(|tag| spatial::IndexedUnit {
+                tag,
+                position: (random() * 100.0, random() * 60.0),
+                radius: 0.25 + random(),
+                is_flying: tag % 5 == 0,
+            })
+            .collect();
+        let index = spatial::SpatialIndex::new(units.clone(), 4.0).unwrap();
+        let distance = |unit: &spatial::IndexedUnit, position: (f32, f32)| {
+            ((unit.position.0 - position.0).powi(2) + (unit.position.1 - position.1).powi(2)).sqrt()
+        };
+        let sorted = |mut tags: Vec<u64>| {
+            tags.sort_unstable();
+            tags
+        };
+        for i in 0..20 {
+            let position = (i as f32 * 5.3 - 3.0, i as f32 * 3.1);
+            let expected: Vec<u64> = units
+                .iter()
+                .filter(|u| distance(u, position) < 7.5)
+                .map(|u| u.tag)
+                .collect();
+            assert_eq!(sorted(index.closer_than(position, 7.5, false)), expected);
+            let expected: Vec<u64> = units
+                .iter()
+                .filter(|u| distance(u, position) < 7.5 + u.radius)
+                .map(|u| u.tag)
+                .collect();
+            assert_eq!(sorted(index.closer_than(position, 7.5, true)), expected);
+
+            let mut by_distance = units.clone();
+            by_distance.sort_by(|a, b| {
+                distance(a, position)
+                    .total_cmp(&distance(b, position))
+                    .then(a.tag.cmp(&b.tag))
+            });
+            let expected: Vec<u64> = by_distance.iter().take(6).map(|u| u.tag).collect();
+            assert_eq!(index.k_nearest(position, 6, None), expected);
+            assert!(index
+                .k_nearest(position, 6, Some(3.0))
+                .iter()
+                .all(|&tag| distance(&units[tag as usize], position) <= 3.0));
+
+            // Marines hit air and ground, zerglings only ground
+            for attacker in [marine(), zergling()] {
+                let expected: Vec<u64> = units
+                    .iter()
+                    .filter(|u| {
+                        u.is_flying
+                            && attacker.air_dps > 0.0
+                            && distance(u, position)
+                                <= attacker.radius + u.radius + attacker.air_range + 1.0
+                            || !u.is_flying
+                                && distance(u, position)
+                                    <= attacker.radius + u.radius + attacker.ground_range + 1.0
+                    })
+                    .map(|u| u.tag)
+                    .collect();
+                let found: Vec<u64> = index
+                    .in_range(&attacker, position, 1.0)
+                    .iter()
+                    .map(|u| u.tag)
+                    .collect();
+                assert_eq!(sorted(found), expected);
+            }
+        }
+        assert_eq!(index.k_nearest((50.0, 30.0), 500, None).len(), 300);
+        assert!(spatial::SpatialIndex::new(vec![], 4.0)
+            .unwrap()
+            .k_nearest((0.0, 0.0), 3, None)
+            .is_empty());
+        assert!(spatial::SpatialIndex::new(vec![], 0.0).is_err());
+        // Tiny cells are made larger instead of allocating billions of them
+        let fine = spatial::SpatialIndex::new(units.clone(), 1e-6).unwrap();
+        assert_eq!(
+            sorted(fine.closer_than((50.0, 30.0), 7.5, false)),
+            sorted(index.closer_than((50.0, 30.0), 7.5, false))
+        );
+        let far = vec![
+            spatial::IndexedUnit {
+                position: (-3e38, 0.0),
+                ..units[0]
+            },
+            spatial::IndexedUnit {
+                position: (3e38, 0.0),
+                ..units[1]
+            },
+        ];
+        assert!(spatial::SpatialIndex::new(far, 1.0).is_err());
+
+        with_gil(|py| {
+            let module = extension_module(py);
+            let index = module
+                .getattr("SpatialIndex")
+                .unwrap()
+                .call1((vec![
+                    (1, 10.0, 10.0, 0.5),
+                    (2, 15.5, 10.0, 0.5),
+                    (3, 30.0, 10.0, 0.5),
+                ],))
+                .unwrap();
+            assert_eq!(index.len().unwrap(), 3);
+            let tags: Vec<u64> = index
+                .call_method1("closer_than", ((11.0, 10.0), 6.0))
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(sorted(tags), vec![1, 2]);
+            let unit = PyCell::new(py, PyCombatUnit { unit: marine() }).unwrap();
+            let tags: Vec<u64> = index
+                .call_method1("in_attack_range_of", (unit, (10.0, 10.0)))
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(tags, vec![1, 2]);
+            // Without a position the unit needs one
+            assert!(index.call_method1("in_attack_range_of", (unit,)).is_err());
+        });
+    }
+
+    #[test]
+    fn test_clustering() {
+        let group = |unit: CombatUnit, center: (f32, f32), count: usize| {
+            (0..count)
+                .map(|i| {
+                    let position = (center.0 + (i % 4) as f32, center.1 + (i / 4) as f32);
+                    (unit.clone(), position)
+                })
+                .collect::<Vec<_>>()
+        };
+        let mut own = group(marine(), (10.0, 10.0), 8);
+        own.extend(group(marine(), (60.0, 10.0), 4));
+        // A lone marine far from both groups
+        own.push((marine(), (35.0, 40.0)));
+        let mut enemy = group(zergling(), (20.0, 12.0), 12);
+        enemy.extend(group(zergling(), (90.0, 50.0), 6));
+
+        let clusters = clustering::cluster(&own, 3.0, 1).unwrap();
+        assert_eq!(
+            clusters.iter().map(|c| c.indices.len()).collect::<Vec<_>>(),
+            vec![8, 4, 1]
+        );
+        assert_eq!(clusters[0].indices, (0..8).collect::<Vec<_>>());
+        assert_eq!(clusters[0].center, (11.5, 10.5));
+        assert_eq!(clusters[0].adjusted_cost, 400);
+        assert_eq!(clusters[0].ground_units, 8);
+        assert_eq!(clusters[0].air_units, 0);
+        assert!((clusters[0].air_dps - 8.0 * marine().air_dps).abs() < 1e-3);
+        // Core units need 3 neighbours within the distance, the lone marine is left out
+        let dense = clustering::cluster(&own, 3.0, 3).unwrap();
+        assert_eq!(dense.len(), 2);
+        assert!(clustering::cluster(&own, 100.0, 1).unwrap().len() == 1);
+
+        let enemy_clusters = clustering::cluster(&enemy, 3.0, 1).unwrap();
+        assert_eq!(enemy_clusters.len(), 2);
+        assert_eq!(enemy_clusters[0].air_dps, 0.0);
+        let pairs = clustering::pair(&clusters, &enemy_clusters, 15.0);
+        assert_eq!(pairs[0].0, 0);
+        assert_eq!(pairs[0].1, 0);
+        assert!(pairs.windows(2).all(|w| w[0].2 <= w[1].2));
+        assert!(pairs.iter().all(|&(_, j, _)| j == 0));
+
+        with_gil(|py| {
+            let module = extension_module(py);
+            let units: Vec<(&PyCell<PyCombatUnit>, (f32, f32))> = own
+                .iter()
+                .map(|(unit, position)| {
+                    let unit = PyCell::new(py, PyCombatUnit { unit: unit.clone() }).unwrap();
+                    (unit, *position)
+                })
+                .collect();
+            let own = module
+                .getattr("cluster_units")
+                .unwrap()
+                .call1((units, 3.0))
+                .unwrap();
+            assert_eq!(own.len().unwrap(), 3);
+            let first = own.get_item(0).unwrap();
+            assert_eq!(first.len().unwrap(), 8);
+            let cost: i32 = first.getattr("adjusted_cost").unwrap().extract().unwrap();
+            assert_eq!(cost, 400);
+            let pickle = py.import("pickle").unwrap();
+            let copy = pickle
+                .call_method1("loads", (pickle.call_method1("dumps", (first,)).unwrap(),))
+                .unwrap();
+            assert_eq!(copy.len().unwrap(), 8);
+            let copy_cost: i32 = copy.getattr("adjusted_cost").unwrap().extract().unwrap();
+            assert_eq!(copy_cost, cost);
+            let pairs: Vec<clustering::ClusterPair> = module
+                .getattr("pair_clusters")
+                .unwrap()
+                .call1((own, own, 0.0))
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(pairs.len(), 3);
+        });
+    }
+
+    #[test]
+    fn test_logger() {
+        with_gil(|py| {
+            extension_module(py);
+            let set_levels = |code: &str| {
+                py.run(code, None, None).unwrap();
+                logger::update_log_level(py).unwrap();
+            };
+            set_levels(
+                r#"
+import logging
+logging.getLogger("sc2_helper").setLevel(logging.WARNING)
+logging.getLogger("sc2_helper.combat_predictor").setLevel(logging.DEBUG)
+"#,
+            );
+            assert_eq!(log::max_level(), log::LevelFilter::Debug);
+            assert!(log::log_enabled!(target: "sc2_helper::combat_predictor", log::Level::Debug));
+            assert!(!log::log_enabled!(target: "sc2_helper::combat_predictor", log::Level::Trace));
+
+            set_levels(
+                r#"logging.getLogger("sc2_helper.combat_predictor").setLevel(logging.NOTSET)"#,
+            );
+            assert_eq!(log::max_level(), log::LevelFilter::Warn);
+            assert!(!log::log_enabled!(target: "sc2_helper::combat_predictor", log::Level::Info));
+
+            set_levels("logging.disable(logging.CRITICAL)");
+            assert_eq!(log::max_level(), log::LevelFilter::Off);
+            set_levels("logging.disable(logging.NOTSET)");
+            assert_eq!(log::max_level(), log::LevelFilter::Warn);
+        })
+    }
+    #[test]
+    fn test_hidden_target_dies() {
+        let mut settings = CombatSettings::new();
+        settings.terrain.high_ground_player = 2;
+        settings.trace = true;
+        let mut hidden = marine();
+        hidden.health = 1.0;
+        let mut tough = marine();
+        tough.health = 400.0;
+        // The zergling kills the marine on the high ground, the marines below keep shooting the marine of
+        // player 3 in the same iteration. Units attack in random order, so repeat the fight a few times.
+        for _ in 0..20 {
+            let result = CombatPredictor::new()
+                .predict_engage_multi(
+                    vec![
+                        vec![marine(), marine(), marine(), marine(), marine(), zergling()],
+                        vec![hidden.clone()],
+                        vec![tough.clone()],
+                    ],
+                    vec![],
+                    0,
+                    &settings,
+                    None,
+                )
+                .unwrap();
+            let trace = result.trace.unwrap();
+            let first = &trace.iterations[0];
+            let visible = first.units.iter().find(|u| u.side == 3).unwrap().tag;
+            for unit in first.units.iter() {
+                if unit.side == 1 && unit.type_id == UnitTypeId::MARINE {
+                    assert_eq!(unit.target, Some(visible));
+                }
+            }
+            assert!(trace.iterations[1].units.iter().all(|u| u.side != 2));
+        }
+    }
+}
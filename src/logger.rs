@@ -0,0 +1,109 @@
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+/// Forwards `log` records, and `tracing` events through its `log` feature, to Python's `logging` module.
+/// The record target is used as logger name, e.g. `sc2_helper.combat_predictor`, so diagnostics can be
+/// filtered with the usual logging configuration.
+struct PythonLogger;
+
+static LOGGER: PythonLogger = PythonLogger;
+
+/// Lowest Python logging level enabled for the `sc2_helper` loggers, see `update_log_level`.
+static LEVEL: AtomicU32 = AtomicU32::new(0);
+
+/// Python logging level of a record. `Trace` maps to 5, below `logging.DEBUG`.
+fn python_level(level: Level) -> u32 {
+    match level {
+        Level::Error => 40,
+        Level::Warn => 30,
+        Level::Info => 20,
+        Level::Debug => 10,
+        Level::Trace => 5,
+    }
+}
+
+/// Most verbose `log` level of records at or above the Python logging `level`.
+fn level_filter(level: u32) -> LevelFilter {
+    match level {
+        0..=5 => LevelFilter::Trace,
+        6..=10 => LevelFilter::Debug,
+        11..=20 => LevelFilter::Info,
+        21..=30 => LevelFilter::Warn,
+        31..=40 => LevelFilter::Error,
+        _ => LevelFilter::Off,
+    }
+}
+
+impl Log for PythonLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        python_level(metadata.level()) >= LEVEL.load(Ordering::Relaxed)
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let name = record.target().replace("::", ".");
+        let message = record.args().to_string();
+        Python::with_gil(|py| {
+            let result = py
+                .import("logging")
+                .and_then(|logging| logging.call_method1("getLogger", (name,)))
+                .and_then(|logger| {
+                    logger.call_method1("log", (python_level(record.level()), message))
+                });
+            if let Err(e) = result {
+                e.print(py);
+            }
+        });
+    }
+
+    fn flush(&self) {}
+}
+
+/// Lowest level `isEnabledFor` accepts on the `sc2_helper` logger or one of its children.
+fn enabled_level(py: Python<'_>) -> PyResult<u32> {
+    let logging = py.import("logging")?;
+    let mut level: u32 = logging
+        .call_method1("getLogger", ("sc2_helper",))?
+        .call_method0("getEffectiveLevel")?
+        .extract()?;
+    let manager = logging.getattr("Logger")?.getattr("manager")?;
+    let loggers: &PyDict = manager.getattr("loggerDict")?.downcast()?;
+    for (name, logger) in loggers {
+        if !name.extract::<&str>()?.starts_with("sc2_helper.") {
+            continue;
+        }
+        // Placeholders of parents that were never created themselves have no level
+        if let Ok(effective) = logger.call_method0("getEffectiveLevel") {
+            level = level.min(effective.extract()?);
+        }
+    }
+    // `logging.disable` turns off everything up to its level
+    let disabled: u32 = manager.getattr("disable")?.extract()?;
+    Ok(if disabled > 0 {
+        level.max(disabled + 1)
+    } else {
+        level
+    })
+}
+
+/// Reads the enabled levels from Python's logging configuration. Records below them are dropped without
+/// taking the GIL, call it again after changing the level of the `sc2_helper` loggers.
+#[pyfunction]
+pub fn update_log_level(py: Python<'_>) -> PyResult<()> {
+    let level = enabled_level(py)?;
+    LEVEL.store(level, Ordering::Relaxed);
+    log::set_max_level(level_filter(level));
+    Ok(())
+}
+
+/// Installs the Python logger, unless another logger was set already.
+pub fn init(py: Python<'_>) -> PyResult<()> {
+    if log::set_logger(&LOGGER).is_ok() {
+        update_log_level(py)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,868 @@
+//! Analysis of the map grids the game sends at the start: regions, ramps, chokes and expansion locations.
+use crate::conversion;
+use crate::pathfinding::PathFinder;
+use crate::terrain::Terrain;
+use crate::{find_points_inside_circle, in_circle};
+use pyo3::buffer::PyBuffer;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::convert::TryFrom;
+
+/// Regions with fewer tiles are left out, e.g. pockets between rocks.
+const MIN_REGION_AREA: usize = 32;
+/// Ramps with fewer tiles are left out, python-sc2 uses the same limit.
+const MIN_RAMP_AREA: usize = 8;
+/// Resources closer than this belong to the same base.
+const RESOURCE_SPREAD: f32 = 8.5;
+/// Tiles around a base's resources searched for its town hall.
+const EXPANSION_SEARCH_RADIUS: f64 = 10.0;
+/// Chokes and ramps closer than this to the line between two armies are fought in.
+const TERRAIN_CHOKE_DISTANCE: f32 = 3.0;
+/// Height difference between two positions, in game units, that puts one of them on high ground.
+const HIGH_GROUND_DIFFERENCE: f32 = 1.0;
+
+const DIRECTIONS: [(isize, isize); 4] = [(1, 0), (0, 1), (1, 1), (1, -1)];
+const NEIGHBOURS_4: [(isize, isize); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (1, 0),
+    (-1, 0),
+    (0, 1),
+    (0, -1),
+    (1, 1),
+    (1, -1),
+    (-1, 1),
+    (-1, -1),
+];
+
+/// A map grid with one byte per tile, stored by rows like the grids of python-sc2, so the tile at `(x, y)`
+/// is `data[y * width + x]`.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(try_from = "RawGrid")]
+pub struct Grid {
+    pub width: usize,
+    pub height: usize,
+    pub data: Vec<u8>,
+}
+
+/// Deserialized `Grid` before its size is checked against the data.
+#[derive(Deserialize)]
+struct RawGrid {
+    width: usize,
+    height: usize,
+    data: Vec<u8>,
+}
+
+impl TryFrom<RawGrid> for Grid {
+    type Error = String;
+
+    fn try_from(raw: RawGrid) -> Result<Self, String> {
+        if raw.width.checked_mul(raw.height) != Some(raw.data.len()) {
+            return Err(format!(
+                "Expected {}x{} tiles, got {}",
+                raw.width,
+                raw.height,
+                raw.data.len()
+            ));
+        }
+        Ok(Grid {
+            width: raw.width,
+            height: raw.height,
+            data: raw.data,
+        })
+    }
+}
+
+impl Grid {
+    /// Grid of `data` with one byte per tile, or one bit per tile as the game packs the pathing and
+    /// placement grids.
+    pub fn new(width: usize, height: usize, data: Vec<u8>) -> Result<Self, String> {
+        let tiles = width * height;
+        let data = if data.len() == tiles {
+            data
+        } else if data.len() == tiles.div_ceil(8) {
+            (0..tiles)
+                .map(|i| (data[i / 8] >> (7 - i % 8)) & 1)
+                .collect()
+        } else {
+            return Err(format!(
+                "Expected {} or {} bytes for a {}x{} grid, got {}",
+                tiles,
+                tiles.div_ceil(8),
+                width,
+                height,
+                data.len()
+            ));
+        };
+        Ok(Grid {
+            width,
+            height,
+            data,
+        })
+    }
+
+    /// Grid from `bytes` of the size `map_size` (width, height), or from a 2-dimensional buffer like a numpy
+    /// array with rows of tiles.
+    pub fn extract(obj: &PyAny, map_size: Option<(usize, usize)>) -> PyResult<Self> {
+        if let Ok(bytes) = obj.downcast::<PyBytes>() {
+            let (width, height) =
+                map_size.ok_or_else(|| PyValueError::new_err("map_size is required for bytes"))?;
+            return Grid::new(width, height, bytes.as_bytes().to_vec())
+                .map_err(PyValueError::new_err);
+        }
+        let buffer = match PyBuffer::<u8>::get(obj) {
+            Ok(buffer) => buffer,
+            // Other dtypes, e.g. bool arrays
+            Err(e) => match obj.call_method1("astype", ("uint8",)) {
+                Ok(converted) => PyBuffer::<u8>::get(converted)?,
+                Err(_) => return Err(e),
+            },
+        };
+        let (width, height) = match buffer.shape() {
+            &[height, width] => (width, height),
+            &[_] => map_size.ok_or_else(|| {
+                PyValueError::new_err("map_size is required for 1-dimensional grids")
+            })?,
+            shape => {
+                return Err(PyValueError::new_err(format!(
+                    "Expected a 2-dimensional grid, got shape {:?}",
+                    shape
+                )))
+            }
+        };
+        if map_size.is_some_and(|size| size != (width, height)) {
+            return Err(PyValueError::new_err(format!(
+                "Grid is {}x{}, map_size is {:?}",
+                width,
+                height,
+                map_size.unwrap()
+            )));
+        }
+        Grid::new(width, height, buffer.to_vec(obj.py())?).map_err(PyValueError::new_err)
+    }
+
+    /// Value of the tile at `(x, y)`, 0 outside the grid.
+    pub fn get(&self, x: isize, y: isize) -> u8 {
+        if x < 0 || y < 0 || x as usize >= self.width || y as usize >= self.height {
+            0
+        } else {
+            self.data[y as usize * self.width + x as usize]
+        }
+    }
+
+    fn neighbours<'a>(
+        &self,
+        index: usize,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = usize> + 'a {
+        let (width, height) = (self.width as isize, self.height as isize);
+        let (x, y) = ((index % self.width) as isize, (index / self.width) as isize);
+        offsets
+            .iter()
+            .map(move |(dx, dy)| (x + dx, y + dy))
+            .filter(move |&(x, y)| x >= 0 && y >= 0 && x < width && y < height)
+            .map(move |(x, y)| (y * width + x) as usize)
+    }
+
+    /// Groups of connected tiles in `mask`, each in scan order.
+    fn components(&self, mask: &[bool], offsets: &[(isize, isize)]) -> Vec<Vec<usize>> {
+        let mut seen = vec![false; mask.len()];
+        let mut components = vec![];
+        for start in 0..mask.len() {
+            if !mask[start] || seen[start] {
+                continue;
+            }
+            seen[start] = true;
+            let mut component = vec![];
+            let mut queue = VecDeque::from(vec![start]);
+            while let Some(index) = queue.pop_front() {
+                component.push(index);
+                for next in self.neighbours(index, offsets) {
+                    if mask[next] && !seen[next] {
+                        seen[next] = true;
+                        queue.push_back(next);
+                    }
+                }
+            }
+            component.sort_unstable();
+            components.push(component);
+        }
+        components
+    }
+
+    fn tile(&self, index: usize) -> (usize, usize) {
+        (index % self.width, index / self.width)
+    }
+}
+
+/// Game height of a terrain height grid value.
+fn game_height(value: u8) -> f32 {
+    -16.0 + 32.0 * value as f32 / 255.0
+}
+
+fn distance(a: (f32, f32), b: (f32, f32)) -> f32 {
+    ((a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)).sqrt()
+}
+
+/// Distance of `point` to the segment from `a` to `b`.
+fn distance_to_segment(point: (f32, f32), a: (f32, f32), b: (f32, f32)) -> f32 {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let length_sq = dx * dx + dy * dy;
+    let t = if length_sq > 0.0 {
+        (((point.0 - a.0) * dx + (point.1 - a.1) * dy) / length_sq).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    distance(point, (a.0 + t * dx, a.1 + t * dy))
+}
+
+/// Center of `tiles`, in game coordinates.
+fn center(tiles: &[(usize, usize)]) -> (f32, f32) {
+    let n = tiles.len().max(1) as f32;
+    (
+        tiles.iter().map(|t| t.0 as f32 + 0.5).sum::<f32>() / n,
+        tiles.iter().map(|t| t.1 as f32 + 0.5).sum::<f32>() / n,
+    )
+}
+
+/// Part of the map between cliffs, ramps and chokes.
+#[pyclass(module = "sc2_helper.sc2_helper")]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Region {
+    #[pyo3(get)]
+    pub id: usize,
+    /// Number of pathable tiles.
+    #[pyo3(get)]
+    pub area: usize,
+    #[pyo3(get)]
+    pub center: (f32, f32),
+    /// Average game height.
+    #[pyo3(get)]
+    pub height: f32,
+    /// Ramps leading out of the region.
+    #[pyo3(get)]
+    pub ramps: Vec<usize>,
+    /// Chokes leading out of the region.
+    #[pyo3(get)]
+    pub chokes: Vec<usize>,
+}
+
+/// Pathable but unbuildable slope between two heights.
+#[pyclass(module = "sc2_helper.sc2_helper")]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Ramp {
+    #[pyo3(get)]
+    pub id: usize,
+    #[pyo3(get)]
+    pub points: Vec<(usize, usize)>,
+    #[pyo3(get)]
+    pub center: (f32, f32),
+    /// Center of the highest tiles of the ramp.
+    #[pyo3(get)]
+    pub upper: (f32, f32),
+    /// Center of the lowest tiles of the ramp.
+    #[pyo3(get)]
+    pub lower: (f32, f32),
+    /// Narrowest width in tiles.
+    #[pyo3(get)]
+    pub width: f32,
+    /// Regions the ramp connects.
+    #[pyo3(get)]
+    pub regions: Vec<usize>,
+}
+
+/// Narrow passage between two regions on the same height.
+#[pyclass(module = "sc2_helper.sc2_helper")]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Choke {
+    #[pyo3(get)]
+    pub id: usize,
+    #[pyo3(get)]
+    pub points: Vec<(usize, usize)>,
+    #[pyo3(get)]
+    pub center: (f32, f32),
+    /// Narrowest width in tiles.
+    #[pyo3(get)]
+    pub width: f32,
+    /// Regions the choke connects.
+    #[pyo3(get)]
+    pub regions: Vec<usize>,
+}
+
+#[pymethods]
+impl Region {
+    /// JSON representation, also used to pickle it.
+    pub fn to_json(&self) -> PyResult<String> {
+        conversion::to_json(self)
+    }
+
+    #[staticmethod]
+    pub fn from_json(json: &str) -> PyResult<Self> {
+        conversion::from_json(json)
+    }
+
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (String,))> {
+        conversion::reduce_json::<Self, _>(py, self)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+#[pymethods]
+impl Ramp {
+    /// JSON representation, also used to pickle it.
+    pub fn to_json(&self) -> PyResult<String> {
+        conversion::to_json(self)
+    }
+
+    #[staticmethod]
+    pub fn from_json(json: &str) -> PyResult<Self> {
+        conversion::from_json(json)
+    }
+
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (String,))> {
+        conversion::reduce_json::<Self, _>(py, self)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+#[pymethods]
+impl Choke {
+    /// JSON representation, also used to pickle it.
+    pub fn to_json(&self) -> PyResult<String> {
+        conversion::to_json(self)
+    }
+
+    #[staticmethod]
+    pub fn from_json(json: &str) -> PyResult<Self> {
+        conversion::from_json(json)
+    }
+
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (String,))> {
+        conversion::reduce_json::<Self, _>(py, self)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+/// Regions, ramps, chokes and expansion locations of a map, computed once from the grids of the game info.
+#[pyclass(module = "sc2_helper.sc2_helper")]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(try_from = "RawMapAnalysis")]
+pub struct MapAnalysis {
+    pathing: Grid,
+    placement: Grid,
+    terrain_height: Grid,
+    /// Region of each tile, -1 for tiles outside of regions.
+    region_grid: Vec<i32>,
+    #[pyo3(get)]
+    pub regions: Vec<Region>,
+    #[pyo3(get)]
+    pub ramps: Vec<Ramp>,
+    #[pyo3(get)]
+    pub chokes: Vec<Choke>,
+    /// Town hall positions of the bases, closest to their resources.
+    #[pyo3(get)]
+    pub expansion_locations: Vec<(f32, f32)>,
+}
+
+/// Deserialized `MapAnalysis` before the sizes of its grids are checked.
+#[derive(Deserialize)]
+struct RawMapAnalysis {
+    pathing: Grid,
+    placement: Grid,
+    terrain_height: Grid,
+    region_grid: Vec<i32>,
+    regions: Vec<Region>,
+    ramps: Vec<Ramp>,
+    chokes: Vec<Choke>,
+    expansion_locations: Vec<(f32, f32)>,
+}
+
+impl TryFrom<RawMapAnalysis> for MapAnalysis {
+    type Error = String;
+
+    fn try_from(raw: RawMapAnalysis) -> Result<Self, String> {
+        check_sizes(&raw.pathing, &raw.placement, &raw.terrain_height)?;
+        if raw.region_grid.len() != raw.pathing.data.len() {
+            return Err(format!(
+                "Expected {} tiles in the region grid, got {}",
+                raw.pathing.data.len(),
+                raw.region_grid.len()
+            ));
+        }
+        Ok(MapAnalysis {
+            pathing: raw.pathing,
+            placement: raw.placement,
+            terrain_height: raw.terrain_height,
+            region_grid: raw.region_grid,
+            regions: raw.regions,
+            ramps: raw.ramps,
+            chokes: raw.chokes,
+            expansion_locations: raw.expansion_locations,
+        })
+    }
+}
+
+/// Checks that the placement and terrain height grids have the size of the pathing grid.
+fn check_sizes(pathing: &Grid, placement: &Grid, terrain_height: &Grid) -> Result<(), String> {
+    let size = (pathing.width, pathing.height);
+    for (name, grid) in [("placement", placement), ("terrain_height", terrain_height)] {
+        if (grid.width, grid.height) != size {
+            return Err(format!(
+                "{} grid is {}x{}, pathing grid is {}x{}",
+                name, grid.width, grid.height, size.0, size.1
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[pymethods]
+impl MapAnalysis {
+    /// `pathing`, `placement` and `terrain_height` are numpy arrays, e.g. `game_info.pathing_grid.data_numpy`,
+    /// or the raw bytes of the grids with `map_size` (width, height). Expansion locations are found for the
+    /// `minerals` and `geysers` positions. Passages up to `max_choke_width` tiles wide are chokes.
+    #[new]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (pathing, placement, terrain_height, map_size = None, minerals = None, geysers = None, max_choke_width = 8.0))]
+    pub fn py_new(
+        py: Python<'_>,
+        pathing: &PyAny,
+        placement: &PyAny,
+        terrain_height: &PyAny,
+        map_size: Option<(usize, usize)>,
+        minerals: Option<Vec<(f32, f32)>>,
+        geysers: Option<Vec<(f32, f32)>>,
+        max_choke_width: f32,
+    ) -> PyResult<Self> {
+        let pathing = Grid::extract(pathing, map_size)?;
+        let placement = Grid::extract(placement, map_size)?;
+        let terrain_height = Grid::extract(terrain_height, map_size)?;
+        let minerals = minerals.unwrap_or_default();
+        let geysers = geysers.unwrap_or_default();
+        py.allow_threads(|| {
+            MapAnalysis::new(
+                pathing,
+                placement,
+                terrain_height,
+                &minerals,
+                &geysers,
+                max_choke_width,
+            )
+        })
+        .map_err(PyValueError::new_err)
+    }
+
+    #[getter]
+    pub fn width(&self) -> usize {
+        self.pathing.width
+    }
+
+    #[getter]
+    pub fn height(&self) -> usize {
+        self.pathing.height
+    }
+
+    pub fn is_pathable(&self, x: isize, y: isize) -> bool {
+        self.pathing.get(x, y) != 0
+    }
+
+    pub fn is_placeable(&self, x: isize, y: isize) -> bool {
+        self.placement.get(x, y) != 0
+    }
+
+    /// Game height of the tile at `position`, like the z coordinate of units standing there.
+    pub fn height_at(&self, position: (f32, f32)) -> f32 {
+        game_height(
+            self.terrain_height
+                .get(position.0.floor() as isize, position.1.floor() as isize),
+        )
+    }
+
+    /// Region `position` is in, `None` on ramps, chokes and unpathable tiles.
+    pub fn region_at(&self, position: (f32, f32)) -> Option<usize> {
+        let (x, y) = (position.0.floor() as isize, position.1.floor() as isize);
+        if self.pathing.get(x, y) == 0 {
+            return None;
+        }
+        let region = self.region_grid[y as usize * self.pathing.width + x as usize];
+        if region < 0 {
+            None
+        } else {
+            Some(region as usize)
+        }
+    }
+
+    /// Pathable tiles within `radius` of `position`.
+    pub fn pathable_points_inside_circle(
+        &self,
+        position: (f64, f64),
+        radius: f64,
+    ) -> Vec<(usize, usize)> {
+        find_points_inside_circle(position, radius, self.pathing.height, self.pathing.width)
+            .into_iter()
+            .filter(|&(x, y)| self.is_pathable(x as isize, y as isize))
+            .collect()
+    }
+
+    /// Path finder on the pathing grid of the map.
+    pub fn path_finder(&self) -> PathFinder {
+        PathFinder::new(self.pathing.clone())
+    }
+
+    /// Terrain of a fight between armies at `position1` (player 1) and `position2` (player 2): which one is on
+    /// high ground and the width of the narrowest ramp or choke between them.
+    pub fn terrain(&self, position1: (f32, f32), position2: (f32, f32)) -> Terrain {
+        let mut terrain = Terrain::new();
+        let (height1, height2) = (self.height_at(position1), self.height_at(position2));
+        if height1 - height2 >= HIGH_GROUND_DIFFERENCE {
+            terrain.high_ground_player = 1;
+        } else if height2 - height1 >= HIGH_GROUND_DIFFERENCE {
+            terrain.high_ground_player = 2;
+        }
+        let near = |points: &[(usize, usize)]| {
+            points.iter().any(|&(x, y)| {
+                let tile = (x as f32 + 0.5, y as f32 + 0.5);
+                distance_to_segment(tile, position1, position2) <= TERRAIN_CHOKE_DISTANCE
+            })
+        };
+        terrain.choke_width = self
+            .ramps
+            .iter()
+            .filter(|r| near(&r.points))
+            .map(|r| r.width)
+            .chain(
+                self.chokes
+                    .iter()
+                    .filter(|c| near(&c.points))
+                    .map(|c| c.width),
+            )
+            .reduce(f32::min)
+            .unwrap_or(0.0);
+        terrain
+    }
+
+    /// JSON representation, also used to pickle it.
+    pub fn to_json(&self) -> PyResult<String> {
+        conversion::to_json(self)
+    }
+
+    #[staticmethod]
+    pub fn from_json(json: &str) -> PyResult<Self> {
+        conversion::from_json(json)
+    }
+
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (String,))> {
+        conversion::reduce_json::<Self, _>(py, self)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "MapAnalysis(width={}, height={}, regions={}, ramps={}, chokes={}, expansion_locations={})",
+            self.width(),
+            self.height(),
+            self.regions.len(),
+            self.ramps.len(),
+            self.chokes.len(),
+            self.expansion_locations.len()
+        )
+    }
+}
+
+impl MapAnalysis {
+    pub fn new(
+        pathing: Grid,
+        placement: Grid,
+        terrain_height: Grid,
+        minerals: &[(f32, f32)],
+        geysers: &[(f32, f32)],
+        max_choke_width: f32,
+    ) -> Result<Self, String> {
+        check_sizes(&pathing, &placement, &terrain_height)?;
+        let mut analysis = MapAnalysis {
+            region_grid: vec![-1; pathing.data.len()],
+            pathing,
+            placement,
+            terrain_height,
+            regions: vec![],
+            ramps: vec![],
+            chokes: vec![],
+            expansion_locations: vec![],
+        };
+        analysis.find_regions(max_choke_width);
+        analysis.expansion_locations = analysis.find_expansion_locations(minerals, geysers);
+        Ok(analysis)
+    }
+
+    fn pathable(&self, index: usize) -> bool {
+        self.pathing.data[index] != 0
+    }
+
+    /// Width of the narrowest straight line of pathable tiles through `index`, horizontally, vertically or
+    /// diagonally. Lines are followed for at most `limit` tiles.
+    fn passage_width(&self, index: usize, limit: f32) -> f32 {
+        let (x, y) = self.pathing.tile(index);
+        let (x, y) = (x as isize, y as isize);
+        let steps = limit.ceil() as isize;
+        DIRECTIONS
+            .iter()
+            .map(|&(dx, dy)| {
+                let count = |sign: isize| {
+                    (1..=steps)
+                        .take_while(|i| self.pathing.get(x + sign * i * dx, y + sign * i * dy) != 0)
+                        .count()
+                };
+                let tiles = (count(1) + count(-1) + 1) as f32;
+                tiles * ((dx * dx + dy * dy) as f32).sqrt()
+            })
+            .fold(f32::MAX, f32::min)
+    }
+
+    /// Regions between the tiles in `walls`, which only take pathable tiles.
+    fn label_regions(&self, walls: &[bool]) -> (Vec<i32>, Vec<Vec<usize>>) {
+        let mask: Vec<bool> = (0..walls.len())
+            .map(|i| self.pathable(i) && !walls[i])
+            .collect();
+        let mut region_grid = vec![-1; mask.len()];
+        let regions: Vec<Vec<usize>> = self
+            .pathing
+            .components(&mask, &NEIGHBOURS_4)
+            .into_iter()
+            .filter(|tiles| tiles.len() >= MIN_REGION_AREA)
+            .collect();
+        for (id, tiles) in regions.iter().enumerate() {
+            for &i in tiles {
+                region_grid[i] = id as i32;
+            }
+        }
+        (region_grid, regions)
+    }
+
+    /// Regions next to `tiles`.
+    fn adjacent_regions(&self, region_grid: &[i32], tiles: &[usize]) -> Vec<usize> {
+        let mut regions: Vec<usize> = tiles
+            .iter()
+            .flat_map(|&i| self.pathing.neighbours(i, &NEIGHBOURS_8))
+            .filter(|&i| region_grid[i] >= 0)
+            .map(|i| region_grid[i] as usize)
+            .collect();
+        regions.sort_unstable();
+        regions.dedup();
+        regions
+    }
+
+    fn find_regions(&mut self, max_choke_width: f32) {
+        let grid = &self.pathing;
+        let tiles = grid.data.len();
+
+        // Ramps are pathable, unbuildable and not flat, python-sc2 finds them the same way
+        let ramp_mask: Vec<bool> = (0..tiles)
+            .map(|i| {
+                let height = self.terrain_height.data[i];
+                self.pathable(i)
+                    && self.placement.data[i] == 0
+                    && grid
+                        .neighbours(i, &NEIGHBOURS_8)
+                        .any(|n| self.terrain_height.data[n] != height)
+            })
+            .collect();
+        let ramp_tiles: Vec<Vec<usize>> = grid
+            .components(&ramp_mask, &NEIGHBOURS_8)
+            .into_iter()
+            .filter(|tiles| tiles.len() >= MIN_RAMP_AREA)
+            .collect();
+        let mut walls = vec![false; tiles];
+        for &i in ramp_tiles.iter().flatten() {
+            walls[i] = true;
+        }
+
+        // Narrow tiles are choke candidates, only the ones that separate regions are kept
+        let widths: Vec<f32> = (0..tiles)
+            .map(|i| {
+                if self.pathable(i) && !walls[i] {
+                    self.passage_width(i, max_choke_width * 2.0)
+                } else {
+                    f32::MAX
+                }
+            })
+            .collect();
+        let choke_mask: Vec<bool> = widths.iter().map(|&w| w <= max_choke_width).collect();
+        let mut choke_tiles = grid.components(&choke_mask, &NEIGHBOURS_8);
+        let (region_grid, region_tiles) = loop {
+            let mut choke_walls = walls.clone();
+            for &i in choke_tiles.iter().flatten() {
+                choke_walls[i] = true;
+            }
+            let (region_grid, region_tiles) = self.label_regions(&choke_walls);
+            let count = choke_tiles.len();
+            choke_tiles.retain(|tiles| self.adjacent_regions(&region_grid, tiles).len() >= 2);
+            if choke_tiles.len() == count {
+                break (region_grid, region_tiles);
+            }
+        };
+
+        let tile = |i: usize| grid.tile(i);
+        self.ramps = ramp_tiles
+            .iter()
+            .enumerate()
+            .map(|(id, tiles)| {
+                let points: Vec<(usize, usize)> = tiles.iter().map(|&i| tile(i)).collect();
+                let heights = tiles.iter().map(|&i| self.terrain_height.data[i]);
+                let (min, max) = (heights.clone().min().unwrap(), heights.max().unwrap());
+                let at_height = |h: u8| -> Vec<(usize, usize)> {
+                    tiles
+                        .iter()
+                        .filter(|&&i| self.terrain_height.data[i] == h)
+                        .map(|&i| tile(i))
+                        .collect()
+                };
+                Ramp {
+                    id,
+                    center: center(&points),
+                    upper: center(&at_height(max)),
+                    lower: center(&at_height(min)),
+                    width: tiles
+                        .iter()
+                        .map(|&i| self.passage_width(i, max_choke_width * 2.0))
+                        .fold(f32::MAX, f32::min),
+                    regions: self.adjacent_regions(&region_grid, tiles),
+                    points,
+                }
+            })
+            .collect();
+        self.chokes = choke_tiles
+            .iter()
+            .enumerate()
+            .map(|(id, tiles)| {
+                let points: Vec<(usize, usize)> = tiles.iter().map(|&i| tile(i)).collect();
+                Choke {
+                    id,
+                    center: center(&points),
+                    width: tiles.iter().map(|&i| widths[i]).fold(f32::MAX, f32::min),
+                    regions: self.adjacent_regions(&region_grid, tiles),
+                    points,
+                }
+            })
+            .collect();
+        self.regions = region_tiles
+            .iter()
+            .enumerate()
+            .map(|(id, tiles)| {
+                let points: Vec<(usize, usize)> = tiles.iter().map(|&i| tile(i)).collect();
+                Region {
+                    id,
+                    area: tiles.len(),
+                    center: center(&points),
+                    height: tiles
+                        .iter()
+                        .map(|&i| game_height(self.terrain_height.data[i]))
+                        .sum::<f32>()
+                        / tiles.len() as f32,
+                    ramps: self
+                        .ramps
+                        .iter()
+                        .filter(|r| r.regions.contains(&id))
+                        .map(|r| r.id)
+                        .collect(),
+                    chokes: self
+                        .chokes
+                        .iter()
+                        .filter(|c| c.regions.contains(&id))
+                        .map(|c| c.id)
+                        .collect(),
+                }
+            })
+            .collect();
+        self.region_grid = region_grid;
+    }
+
+    /// Whether a 5x5 town hall centered on tile `(x, y)` fits.
+    fn town_hall_fits(&self, x: usize, y: usize) -> bool {
+        let (x, y) = (x as isize, y as isize);
+        (x - 2..=x + 2).all(|tx| (y - 2..=y + 2).all(|ty| self.placement.get(tx, ty) != 0))
+    }
+
+    fn find_expansion_locations(
+        &self,
+        minerals: &[(f32, f32)],
+        geysers: &[(f32, f32)],
+    ) -> Vec<(f32, f32)> {
+        let resources: Vec<((f32, f32), bool)> = minerals
+            .iter()
+            .map(|&p| (p, false))
+            .chain(geysers.iter().map(|&p| (p, true)))
+            .collect();
+        // Resources of a base are chained together by short gaps
+        let mut base = vec![usize::MAX; resources.len()];
+        let mut bases = 0;
+        for start in 0..resources.len() {
+            if base[start] != usize::MAX {
+                continue;
+            }
+            base[start] = bases;
+            let mut stack = vec![start];
+            while let Some(i) = stack.pop() {
+                for j in 0..resources.len() {
+                    if base[j] == usize::MAX
+                        && distance(resources[i].0, resources[j].0) <= RESOURCE_SPREAD
+                    {
+                        base[j] = bases;
+                        stack.push(j);
+                    }
+                }
+            }
+            bases += 1;
+        }
+
+        (0..bases)
+            .filter_map(|b| {
+                let base_resources: Vec<&((f32, f32), bool)> = resources
+                    .iter()
+                    .zip(base.iter())
+                    .filter(|(_, &rb)| rb == b)
+                    .map(|(r, _)| r)
+                    .collect();
+                let n = base_resources.len() as f32;
+                let middle = (
+                    base_resources.iter().map(|r| r.0 .0).sum::<f32>() / n,
+                    base_resources.iter().map(|r| r.0 .1).sum::<f32>() / n,
+                );
+                find_points_inside_circle(
+                    (middle.0 as f64, middle.1 as f64),
+                    EXPANSION_SEARCH_RADIUS,
+                    self.pathing.height,
+                    self.pathing.width,
+                )
+                .into_iter()
+                .filter(|&(x, y)| {
+                    // Town halls keep 6 tiles from minerals and 7 from geysers, as python-sc2 assumes
+                    self.town_hall_fits(x, y)
+                        && base_resources.iter().all(|&&(p, geyser)| {
+                            !in_circle(
+                                (p.0 as f64 - 0.5, p.1 as f64 - 0.5),
+                                (x, y),
+                                if geyser { 7.0 } else { 6.0 },
+                            )
+                        })
+                })
+                .map(|(x, y)| (x as f32 + 0.5, y as f32 + 0.5))
+                .min_by(|a, b| {
+                    let total = |p: (f32, f32)| -> f32 {
+                        base_resources.iter().map(|r| distance(r.0, p)).sum()
+                    };
+                    total(*a).total_cmp(&total(*b))
+                })
+            })
+            .collect()
+    }
+}
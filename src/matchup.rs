@@ -0,0 +1,158 @@
+use crate::combat_predictor::{CombatPredictor, CombatSettings};
+use crate::combat_unit::CombatUnit;
+use crate::conversion;
+use crate::generated_enums::UnitTypeId;
+use crate::unit_database;
+use pyo3::prelude::*;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Outcome of a fight between equal amounts of resources spent on two unit types, with both sides at the
+/// same upgrade level.
+#[pyclass(module = "sc2_helper.sc2_helper")]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Matchup {
+    #[pyo3(get)]
+    pub unit1: UnitTypeId,
+    #[pyo3(get)]
+    pub unit2: UnitTypeId,
+    /// Attack, armor and shield upgrade level of both sides.
+    #[pyo3(get)]
+    pub upgrade_level: i64,
+    #[pyo3(get)]
+    pub count1: u32,
+    #[pyo3(get)]
+    pub count2: u32,
+    /// 1 if `unit1` wins, 2 if `unit2` wins.
+    #[pyo3(get)]
+    pub winner: u32,
+    /// Resources of `unit2` destroyed per resource of `unit1` lost, where the resources lost by a side are
+    /// its army value times the fraction of health + shield it lost. Above 1.0 favours `unit1`.
+    #[pyo3(get)]
+    pub cost_efficiency: f32,
+    /// Seconds until the losing side was destroyed, or `max_time` if neither side could win.
+    #[pyo3(get)]
+    pub time: f32,
+    /// Health + shield left per side.
+    #[pyo3(get)]
+    pub health_left: Vec<f32>,
+}
+
+#[pymethods]
+impl Matchup {
+    /// JSON representation, also used to pickle it.
+    pub fn to_json(&self) -> PyResult<String> {
+        conversion::to_json(self)
+    }
+
+    #[staticmethod]
+    pub fn from_json(json: &str) -> PyResult<Self> {
+        conversion::from_json(json)
+    }
+
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (String,))> {
+        conversion::reduce_json::<Self, _>(py, self)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+/// Header of the CSV written by `to_csv`.
+pub const CSV_HEADER: &str =
+    "unit1,unit2,upgrade_level,count1,count2,winner,cost_efficiency,time,health_left1,health_left2";
+
+/// One line per matchup, with unit type names.
+pub fn to_csv(matchups: &[Matchup]) -> String {
+    let mut csv = format!("{}\n", CSV_HEADER);
+    for m in matchups {
+        csv += &format!(
+            "{:?},{:?},{},{},{},{},{:.3},{:.2},{:.1},{:.1}\n",
+            m.unit1,
+            m.unit2,
+            m.upgrade_level,
+            m.count1,
+            m.count2,
+            m.winner,
+            m.cost_efficiency,
+            m.time,
+            m.health_left[0],
+            m.health_left[1]
+        );
+    }
+    csv
+}
+
+/// Unit types in the unit database that have a weapon.
+pub fn combat_unit_types() -> Vec<UnitTypeId> {
+    unit_database::unit_types()
+        .into_iter()
+        .filter(|t| unit_database::get_unit(*t).is_some_and(|u| u.can_attack()))
+        .collect()
+}
+
+/// Army of `unit` worth at most `resources`, at least one unit.
+fn army(unit: &CombatUnit, upgrade_level: i64, resources: i32) -> Vec<CombatUnit> {
+    let mut unit = unit.clone();
+    unit.attack_upgrade_level = upgrade_level;
+    unit.armor_upgrade_level = upgrade_level;
+    unit.shield_upgrade_level = upgrade_level;
+    let count = (resources / unit.get_adjusted_cost().max(1)).max(1);
+    vec![unit; count as usize]
+}
+
+fn army_value(units: &[CombatUnit]) -> f32 {
+    units.iter().map(|u| u.get_adjusted_cost() as f32).sum()
+}
+
+fn army_health(units: &[CombatUnit]) -> f32 {
+    units.iter().map(|u| u.health + u.shield).sum()
+}
+
+/// Resource-equal matchup of every pair of `unit_types` at every upgrade level. Armies are worth up to
+/// `resources` adjusted cost (minerals + 1.5 * vespene). The engagements are predicted in parallel.
+pub fn matchup_matrix(
+    predictor: &CombatPredictor,
+    unit_types: &[UnitTypeId],
+    upgrade_levels: &[i64],
+    resources: i32,
+    settings: &CombatSettings,
+) -> Result<Vec<Matchup>, String> {
+    let units = unit_types
+        .iter()
+        .map(|t| unit_database::get_unit(*t).ok_or_else(|| format!("No unit data for {:?}", t)))
+        .collect::<Result<Vec<CombatUnit>, String>>()?;
+    let mut pairs: Vec<(usize, usize, i64)> = vec![];
+    for &level in upgrade_levels {
+        for i in 0..units.len() {
+            for j in i + 1..units.len() {
+                pairs.push((i, j, level));
+            }
+        }
+    }
+    Ok(pairs
+        .into_par_iter()
+        .map(|(i, j, level)| {
+            let units1 = army(&units[i], level, resources);
+            let units2 = army(&units[j], level, resources);
+            let (count1, count2) = (units1.len() as u32, units2.len() as u32);
+            let (value1, value2) = (army_value(&units1), army_value(&units2));
+            let (health1, health2) = (army_health(&units1), army_health(&units2));
+            let result = predictor._predict_engage(units1, units2, vec![], vec![], 0, settings);
+            let lost1 = value1 * (1.0 - result.health_left[0] / health1);
+            let lost2 = value2 * (1.0 - result.health_left[1] / health2);
+            Matchup {
+                unit1: units[i].type_id,
+                unit2: units[j].type_id,
+                upgrade_level: level,
+                count1,
+                count2,
+                winner: result.winner,
+                cost_efficiency: lost2 / lost1.max(1.0),
+                time: result.time,
+                health_left: result.health_left,
+            }
+        })
+        .collect())
+}
@@ -0,0 +1,288 @@
+//! Reader for the MPQ archives `.SC2Replay` files are stored in.
+use bzip2::read::BzDecoder;
+use flate2::read::ZlibDecoder;
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+
+const USER_DATA_MAGIC: &[u8] = b"MPQ\x1b";
+const HEADER_MAGIC: &[u8] = b"MPQ\x1a";
+
+const HASH_TABLE_OFFSET: u32 = 0;
+const HASH_A: u32 = 1;
+const HASH_B: u32 = 2;
+const HASH_FILE_KEY: u32 = 3;
+
+const FILE_IMPLODE: u32 = 0x0000_0100;
+const FILE_COMPRESS: u32 = 0x0000_0200;
+const FILE_ENCRYPTED: u32 = 0x0001_0000;
+const FILE_SINGLE_UNIT: u32 = 0x0100_0000;
+const FILE_SECTOR_CRC: u32 = 0x0400_0000;
+const FILE_EXISTS: u32 = 0x8000_0000;
+
+/// Largest sector size shift accepted, sectors of 512 << 16 bytes.
+const MAX_SECTOR_SHIFT: u16 = 16;
+
+const COMPRESSION_NONE: u8 = 0x00;
+const COMPRESSION_ZLIB: u8 = 0x02;
+const COMPRESSION_BZIP2: u8 = 0x10;
+
+lazy_static! {
+    static ref CRYPT_TABLE: [u32; 0x500] = {
+        let mut table = [0u32; 0x500];
+        let mut seed: u32 = 0x0010_0001;
+        for i in 0..0x100 {
+            let mut index = i;
+            for _ in 0..5 {
+                seed = (seed * 125 + 3) % 0x002A_AAAB;
+                let high = (seed & 0xFFFF) << 16;
+                seed = (seed * 125 + 3) % 0x002A_AAAB;
+                table[index] = high | (seed & 0xFFFF);
+                index += 0x100;
+            }
+        }
+        table
+    };
+}
+
+/// Hash of a file name, `hash_type` selects the part of the crypt table that is used.
+pub fn hash_string(name: &str, hash_type: u32) -> u32 {
+    let mut seed1: u32 = 0x7FED_7FED;
+    let mut seed2: u32 = 0xEEEE_EEEE;
+    for c in name.bytes() {
+        let c = if c == b'/' {
+            b'\\'
+        } else {
+            c.to_ascii_uppercase()
+        } as u32;
+        let value = CRYPT_TABLE[((hash_type << 8) + c) as usize];
+        seed1 = value ^ seed1.wrapping_add(seed2);
+        seed2 = c
+            .wrapping_add(seed1)
+            .wrapping_add(seed2)
+            .wrapping_add(seed2 << 5)
+            .wrapping_add(3);
+    }
+    seed1
+}
+
+fn decrypt(data: &[u8], key: u32) -> Vec<u32> {
+    let mut seed1 = key;
+    let mut seed2: u32 = 0xEEEE_EEEE;
+    data.chunks_exact(4)
+        .map(|chunk| {
+            seed2 = seed2.wrapping_add(CRYPT_TABLE[0x400 + (seed1 & 0xFF) as usize]);
+            let value = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]])
+                ^ seed1.wrapping_add(seed2);
+            seed1 = ((!seed1 << 0x15).wrapping_add(0x1111_1111)) | (seed1 >> 0x0B);
+            seed2 = value
+                .wrapping_add(seed2)
+                .wrapping_add(seed2 << 5)
+                .wrapping_add(3);
+            value
+        })
+        .collect()
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Result<u16, String> {
+    data.get(offset..offset + 2)
+        .map(|b| u16::from_le_bytes([b[0], b[1]]))
+        .ok_or_else(|| "Unexpected end of archive".to_string())
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Result<u32, String> {
+    data.get(offset..offset + 4)
+        .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .ok_or_else(|| "Unexpected end of archive".to_string())
+}
+
+fn slice(data: &[u8], offset: usize, len: usize) -> Result<&[u8], String> {
+    offset
+        .checked_add(len)
+        .and_then(|end| data.get(offset..end))
+        .ok_or_else(|| "Unexpected end of archive".to_string())
+}
+
+struct HashEntry {
+    hash_a: u32,
+    hash_b: u32,
+    block_index: u32,
+}
+
+struct BlockEntry {
+    offset: u32,
+    archived_size: u32,
+    size: u32,
+    flags: u32,
+}
+
+/// An MPQ archive held in memory. Only what replays use is supported: files can be zlib or bzip2
+/// compressed but not encrypted or imploded.
+pub struct MpqArchive {
+    data: Vec<u8>,
+    header_offset: usize,
+    sector_size: usize,
+    hash_table: Vec<HashEntry>,
+    block_table: Vec<BlockEntry>,
+}
+
+impl MpqArchive {
+    pub fn open(path: &Path) -> Result<Self, String> {
+        let data = fs::read(path).map_err(|e| e.to_string())?;
+        MpqArchive::from_bytes(data)
+    }
+
+    pub fn from_bytes(data: Vec<u8>) -> Result<Self, String> {
+        // Replays start with a user data block that points at the archive header
+        let header_offset = match data.get(0..4) {
+            Some(USER_DATA_MAGIC) => read_u32(&data, 8)? as usize,
+            Some(HEADER_MAGIC) => 0,
+            _ => return Err("Not an MPQ archive".to_string()),
+        };
+        if data.get(header_offset..header_offset + 4) != Some(HEADER_MAGIC) {
+            return Err("MPQ header not found".to_string());
+        }
+        // Real archives use a shift of 3, a corrupt one could overflow the sector size
+        let sector_shift = read_u16(&data, header_offset + 14)?;
+        if sector_shift > MAX_SECTOR_SHIFT {
+            return Err(format!("Invalid sector size shift {}", sector_shift));
+        }
+        let sector_size = 512usize << sector_shift;
+        let hash_table_offset = read_u32(&data, header_offset + 16)? as usize;
+        let block_table_offset = read_u32(&data, header_offset + 20)? as usize;
+        let hash_table_entries = read_u32(&data, header_offset + 24)? as usize;
+        let block_table_entries = read_u32(&data, header_offset + 28)? as usize;
+
+        let hash_table = decrypt(
+            slice(
+                &data,
+                header_offset + hash_table_offset,
+                hash_table_entries * 16,
+            )?,
+            hash_string("(hash table)", HASH_FILE_KEY),
+        )
+        .chunks_exact(4)
+        .map(|e| HashEntry {
+            hash_a: e[0],
+            hash_b: e[1],
+            block_index: e[3],
+        })
+        .collect();
+        let block_table = decrypt(
+            slice(
+                &data,
+                header_offset + block_table_offset,
+                block_table_entries * 16,
+            )?,
+            hash_string("(block table)", HASH_FILE_KEY),
+        )
+        .chunks_exact(4)
+        .map(|e| BlockEntry {
+            offset: e[0],
+            archived_size: e[1],
+            size: e[2],
+            flags: e[3],
+        })
+        .collect();
+        Ok(MpqArchive {
+            data,
+            header_offset,
+            sector_size,
+            hash_table,
+            block_table,
+        })
+    }
+
+    fn find_block(&self, name: &str) -> Option<&BlockEntry> {
+        let hash_a = hash_string(name, HASH_A);
+        let hash_b = hash_string(name, HASH_B);
+        let len = self.hash_table.len();
+        if len == 0 {
+            return None;
+        }
+        let start = hash_string(name, HASH_TABLE_OFFSET) as usize % len;
+        for i in 0..len {
+            let entry = &self.hash_table[(start + i) % len];
+            match entry.block_index {
+                // Empty entry, the file is not in the archive
+                0xFFFF_FFFF => return None,
+                // Deleted entry
+                0xFFFF_FFFE => continue,
+                index if entry.hash_a == hash_a && entry.hash_b == hash_b => {
+                    return self.block_table.get(index as usize)
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    /// Contents of the file `name`, e.g. `"replay.tracker.events"`.
+    pub fn read_file(&self, name: &str) -> Result<Vec<u8>, String> {
+        let block = self
+            .find_block(name)
+            .filter(|b| b.flags & FILE_EXISTS != 0)
+            .ok_or_else(|| format!("{} not found in archive", name))?;
+        if block.flags & (FILE_ENCRYPTED | FILE_IMPLODE) != 0 {
+            return Err(format!("{} is encrypted or imploded", name));
+        }
+        let offset = self.header_offset + block.offset as usize;
+        let size = block.size as usize;
+        let compressed = block.flags & FILE_COMPRESS != 0;
+        if block.flags & FILE_SINGLE_UNIT != 0 {
+            let data = slice(&self.data, offset, block.archived_size as usize)?;
+            return if compressed && data.len() < size {
+                decompress(data, size)
+            } else {
+                Ok(data.to_vec())
+            };
+        }
+
+        let mut sectors = size.div_ceil(self.sector_size) + 1;
+        if block.flags & FILE_SECTOR_CRC != 0 {
+            sectors += 1;
+        }
+        let positions = (0..sectors)
+            .map(|i| read_u32(&self.data, offset + i * 4).map(|p| p as usize))
+            .collect::<Result<Vec<usize>, String>>()?;
+        let mut file = Vec::with_capacity(size.min(self.data.len()));
+        for window in positions.windows(2) {
+            if file.len() >= size {
+                break;
+            }
+            let expected = (size - file.len()).min(self.sector_size);
+            let len = window[1]
+                .checked_sub(window[0])
+                .ok_or_else(|| format!("Invalid sector offsets in {}", name))?;
+            let sector = slice(&self.data, offset + window[0], len)?;
+            if compressed && sector.len() < expected {
+                file.extend(decompress(sector, expected)?);
+            } else {
+                file.extend_from_slice(sector);
+            }
+        }
+        Ok(file)
+    }
+}
+
+/// Data of a compressed file or sector, the first byte is the compression type.
+fn decompress(data: &[u8], size: usize) -> Result<Vec<u8>, String> {
+    // `size` comes from the archive, don't trust it for the allocation
+    let mut result = Vec::with_capacity(size.min(data.len() * 8));
+    match data.first() {
+        Some(&COMPRESSION_NONE) => result.extend_from_slice(&data[1..]),
+        Some(&COMPRESSION_ZLIB) => {
+            ZlibDecoder::new(&data[1..])
+                .read_to_end(&mut result)
+                .map_err(|e| e.to_string())?;
+        }
+        Some(&COMPRESSION_BZIP2) => {
+            BzDecoder::new(&data[1..])
+                .read_to_end(&mut result)
+                .map_err(|e| e.to_string())?;
+        }
+        Some(c) => return Err(format!("Unsupported compression type {:#x}", c)),
+        None => return Err("Empty compressed sector".to_string()),
+    }
+    Ok(result)
+}
@@ -0,0 +1,621 @@
+//! Shortest paths on the pathing grid for units of a given radius.
+use crate::map::Grid;
+use pyo3::buffer::PyBuffer;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use rayon::prelude::*;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::f32::consts::SQRT_2;
+
+/// Smoothing may not make a path more expensive by more than this.
+const SMOOTHING_TOLERANCE: f32 = 1e-3;
+
+const NEIGHBOURS: [(isize, isize); 8] = [
+    (1, 0),
+    (-1, 0),
+    (0, 1),
+    (0, -1),
+    (1, 1),
+    (1, -1),
+    (-1, 1),
+    (-1, -1),
+];
+
+/// Open list entry, ordered so `BinaryHeap` pops the lowest estimate first.
+#[derive(Clone, Copy, PartialEq)]
+struct Node {
+    estimate: f32,
+    index: usize,
+}
+
+impl Eq for Node {}
+
+impl Ord for Node {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.total_cmp(&self.estimate)
+    }
+}
+
+impl PartialOrd for Node {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn octile(dx: isize, dy: isize) -> f32 {
+    let (dx, dy) = (dx.abs() as f32, dy.abs() as f32);
+    dx.max(dy) + (SQRT_2 - 1.0) * dx.min(dy)
+}
+
+fn tile_center(tile: (isize, isize)) -> (f32, f32) {
+    (tile.0 as f32 + 0.5, tile.1 as f32 + 0.5)
+}
+
+/// Weight grid of a numpy array or a list of rows with the size of the pathing grid.
+pub fn extract_weights(obj: &PyAny, width: usize, height: usize) -> PyResult<Vec<f32>> {
+    let weights: Vec<f32> = match PyBuffer::<f32>::get(obj) {
+        Ok(buffer) => buffer.to_vec(obj.py())?,
+        Err(_) => match obj.call_method1("astype", ("float32",)) {
+            Ok(converted) => PyBuffer::<f32>::get(converted)?.to_vec(obj.py())?,
+            Err(_) => obj
+                .extract::<Vec<Vec<f32>>>()?
+                .into_iter()
+                .flatten()
+                .collect(),
+        },
+    };
+    if weights.len() != width * height {
+        return Err(PyValueError::new_err(format!(
+            "Expected {}x{} weights, got {}",
+            width,
+            height,
+            weights.len()
+        )));
+    }
+    Ok(weights)
+}
+
+/// Start, goal and unit radius of a path search.
+pub type PathQuery = ((f32, f32), (f32, f32), f32);
+/// Waypoints of a path.
+pub type Path = Vec<(f32, f32)>;
+
+/// Parameters of one path search.
+struct Query<'a> {
+    start: (isize, isize),
+    goal: (isize, isize),
+    radius: f32,
+    weights: Option<&'a [f32]>,
+}
+
+/// Finds paths on a pathing grid, stored by rows like `find_points_inside_circle` expects.
+#[pyclass(module = "sc2_helper.sc2_helper")]
+#[derive(Clone, Debug)]
+pub struct PathFinder {
+    pathing: Grid,
+    /// Distance from the center of each tile to the closest unpathable tile or the edge of the map, 0 for
+    /// unpathable tiles.
+    clearance: Vec<f32>,
+}
+
+#[pymethods]
+impl PathFinder {
+    /// `pathing` is a numpy array like `game_info.pathing_grid.data_numpy`, or the raw bytes of the grid with
+    /// `map_size` (width, height).
+    #[new]
+    #[pyo3(signature = (pathing, map_size = None))]
+    pub fn py_new(pathing: &PyAny, map_size: Option<(usize, usize)>) -> PyResult<Self> {
+        Ok(PathFinder::new(Grid::extract(pathing, map_size)?))
+    }
+
+    #[getter]
+    pub fn width(&self) -> usize {
+        self.pathing.width
+    }
+
+    #[getter]
+    pub fn height(&self) -> usize {
+        self.pathing.height
+    }
+
+    /// Whether a unit of `radius` fits on the tile `(x, y)`.
+    #[pyo3(signature = (x, y, radius = 0.0))]
+    pub fn is_passable(&self, x: isize, y: isize, radius: f32) -> bool {
+        self.clearance_at(x, y) >= radius.max(f32::MIN_POSITIVE)
+    }
+
+    /// Waypoints from `start` to `goal` for a unit of `radius`, or `None` if the goal can't be reached.
+    /// `weights` is an optional grid of costs per tile travelled, e.g. an influence map. 1.0 is the cost of
+    /// normal ground, tiles with weights that aren't positive are avoided. Without weights jump point search
+    /// is used, otherwise A*. `smooth` cuts corners where a straight line is free and not more expensive.
+    #[pyo3(signature = (start, goal, radius = 0.0, weights = None, smooth = true))]
+    pub fn find_path(
+        &self,
+        py: Python<'_>,
+        start: (f32, f32),
+        goal: (f32, f32),
+        radius: f32,
+        weights: Option<&PyAny>,
+        smooth: bool,
+    ) -> PyResult<Option<Path>> {
+        let weights = weights
+            .map(|w| extract_weights(w, self.pathing.width, self.pathing.height))
+            .transpose()?;
+        Ok(py.allow_threads(|| self.path(start, goal, radius, weights.as_deref(), smooth)))
+    }
+
+    /// `find_path` for many `(start, goal, radius)` queries at once, searched in parallel.
+    #[pyo3(signature = (queries, weights = None, smooth = true))]
+    pub fn find_paths(
+        &self,
+        py: Python<'_>,
+        queries: Vec<PathQuery>,
+        weights: Option<&PyAny>,
+        smooth: bool,
+    ) -> PyResult<Vec<Option<Path>>> {
+        let weights = weights
+            .map(|w| extract_weights(w, self.pathing.width, self.pathing.height))
+            .transpose()?;
+        Ok(py.allow_threads(|| self.paths(&queries, weights.as_deref(), smooth)))
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "PathFinder(width={}, height={})",
+            self.pathing.width, self.pathing.height
+        )
+    }
+}
+
+impl PathFinder {
+    pub fn new(pathing: Grid) -> Self {
+        let (width, height) = (pathing.width, pathing.height);
+        // Chamfer distance transform, the edge of the map counts as unpathable
+        let mut clearance: Vec<f32> = (0..width * height)
+            .map(|i| {
+                let (x, y) = (i % width, i / width);
+                if pathing.data[i] == 0 {
+                    0.0
+                } else {
+                    (x + 1).min(y + 1).min(width - x).min(height - y) as f32
+                }
+            })
+            .collect();
+        let mut relax = |x: usize, y: usize, offsets: &[(isize, isize)]| {
+            let i = y * width + x;
+            for &(dx, dy) in offsets {
+                let (nx, ny) = (x as isize + dx, y as isize + dy);
+                if nx >= 0 && ny >= 0 && (nx as usize) < width && (ny as usize) < height {
+                    let distance = if dx != 0 && dy != 0 { SQRT_2 } else { 1.0 };
+                    let through = clearance[ny as usize * width + nx as usize] + distance;
+                    if through < clearance[i] {
+                        clearance[i] = through;
+                    }
+                }
+            }
+        };
+        for y in 0..height {
+            for x in 0..width {
+                relax(x, y, &[(-1, 0), (-1, -1), (0, -1), (1, -1)]);
+            }
+        }
+        for y in (0..height).rev() {
+            for x in (0..width).rev() {
+                relax(x, y, &[(1, 0), (1, 1), (0, 1), (-1, 1)]);
+            }
+        }
+        // Distances are between tile centers, units are blocked at the edge of the tile
+        for c in clearance.iter_mut() {
+            if *c > 0.0 {
+                *c -= 0.5;
+            }
+        }
+        PathFinder { pathing, clearance }
+    }
+
+    fn clearance_at(&self, x: isize, y: isize) -> f32 {
+        if x < 0 || y < 0 || x as usize >= self.pathing.width || y as usize >= self.pathing.height {
+            0.0
+        } else {
+            self.clearance[y as usize * self.pathing.width + x as usize]
+        }
+    }
+
+    fn index(&self, tile: (isize, isize)) -> usize {
+        tile.1 as usize * self.pathing.width + tile.0 as usize
+    }
+
+    /// Cost per tile travelled on `(x, y)`, `None` if the unit can't go there. The start is always allowed so
+    /// units can leave tiles that are too narrow for them.
+    fn weight(&self, query: &Query, x: isize, y: isize) -> Option<f32> {
+        if x < 0 || y < 0 || x as usize >= self.pathing.width || y as usize >= self.pathing.height {
+            return None;
+        }
+        let is_start = (x, y) == query.start;
+        if !is_start && !self.is_passable(x, y, query.radius) {
+            return None;
+        }
+        let weight = query
+            .weights
+            .map_or(1.0, |weights| weights[self.index((x, y))]);
+        if weight > 0.0 && weight.is_finite() {
+            Some(weight)
+        } else if is_start {
+            Some(1.0)
+        } else {
+            None
+        }
+    }
+
+    fn walkable(&self, query: &Query, x: isize, y: isize) -> bool {
+        self.weight(query, x, y).is_some()
+    }
+
+    /// Cost of the straight line from `a` to `b`, `None` if it crosses a tile the unit can't enter or passes
+    /// between two diagonal obstacles.
+    fn line_cost(&self, query: &Query, a: (f32, f32), b: (f32, f32)) -> Option<f32> {
+        let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+        let length = (dx * dx + dy * dy).sqrt();
+        let (mut x, mut y) = (a.0.floor() as isize, a.1.floor() as isize);
+        let end = (b.0.floor() as isize, b.1.floor() as isize);
+        let step_x = if dx > 0.0 { 1 } else { -1 };
+        let step_y = if dy > 0.0 { 1 } else { -1 };
+        let t_delta_x = if dx != 0.0 {
+            1.0 / dx.abs()
+        } else {
+            f32::INFINITY
+        };
+        let t_delta_y = if dy != 0.0 {
+            1.0 / dy.abs()
+        } else {
+            f32::INFINITY
+        };
+        let mut t_max_x = match dx.partial_cmp(&0.0) {
+            Some(Ordering::Greater) => (x as f32 + 1.0 - a.0) / dx,
+            Some(Ordering::Less) => (a.0 - x as f32) / -dx,
+            _ => f32::INFINITY,
+        };
+        let mut t_max_y = match dy.partial_cmp(&0.0) {
+            Some(Ordering::Greater) => (y as f32 + 1.0 - a.1) / dy,
+            Some(Ordering::Less) => (a.1 - y as f32) / -dy,
+            _ => f32::INFINITY,
+        };
+        let mut t = 0.0;
+        let mut cost = 0.0;
+        loop {
+            let weight = self.weight(query, x, y)?;
+            let t_next = t_max_x.min(t_max_y).min(1.0);
+            cost += (t_next - t) * length * weight;
+            t = t_next;
+            if (x, y) == end || t >= 1.0 {
+                return Some(cost);
+            }
+            if (t_max_x - t_max_y).abs() < 1e-5 {
+                // Through a corner, both tiles next to it have to be free
+                if !self.walkable(query, x + step_x, y) || !self.walkable(query, x, y + step_y) {
+                    return None;
+                }
+                x += step_x;
+                y += step_y;
+                t_max_x += t_delta_x;
+                t_max_y += t_delta_y;
+            } else if t_max_x < t_max_y {
+                x += step_x;
+                t_max_x += t_delta_x;
+            } else {
+                y += step_y;
+                t_max_y += t_delta_y;
+            }
+        }
+    }
+
+    /// Neighbours of `tile` a unit can move to, diagonals only if both tiles beside them are free.
+    fn neighbours(&self, query: &Query, tile: (isize, isize)) -> Vec<(isize, isize)> {
+        NEIGHBOURS
+            .iter()
+            .filter(|&&(dx, dy)| {
+                self.walkable(query, tile.0 + dx, tile.1 + dy)
+                    && (dx == 0
+                        || dy == 0
+                        || (self.walkable(query, tile.0 + dx, tile.1)
+                            && self.walkable(query, tile.0, tile.1 + dy)))
+            })
+            .map(|&(dx, dy)| (tile.0 + dx, tile.1 + dy))
+            .collect()
+    }
+
+    /// Next jump point from `tile` in direction `(dx, dy)`, for jump point search without corner cutting.
+    fn jump(
+        &self,
+        query: &Query,
+        mut tile: (isize, isize),
+        dx: isize,
+        dy: isize,
+    ) -> Option<(isize, isize)> {
+        loop {
+            let (x, y) = tile;
+            if !self.walkable(query, x, y) {
+                return None;
+            }
+            if tile == query.goal {
+                return Some(tile);
+            }
+            if dx != 0 && dy != 0 {
+                if self.jump(query, (x + dx, y), dx, 0).is_some()
+                    || self.jump(query, (x, y + dy), 0, dy).is_some()
+                {
+                    return Some(tile);
+                }
+            } else if dx != 0 {
+                if (self.walkable(query, x, y - 1) && !self.walkable(query, x - dx, y - 1))
+                    || (self.walkable(query, x, y + 1) && !self.walkable(query, x - dx, y + 1))
+                {
+                    return Some(tile);
+                }
+            } else if (self.walkable(query, x - 1, y) && !self.walkable(query, x - 1, y - dy))
+                || (self.walkable(query, x + 1, y) && !self.walkable(query, x + 1, y - dy))
+            {
+                return Some(tile);
+            }
+            if self.walkable(query, x + dx, y) && self.walkable(query, x, y + dy) {
+                tile = (x + dx, y + dy);
+            } else {
+                return None;
+            }
+        }
+    }
+
+    /// Directions jump point search continues in from `tile`, reached from `parent`.
+    fn pruned_neighbours(
+        &self,
+        query: &Query,
+        tile: (isize, isize),
+        parent: Option<(isize, isize)>,
+    ) -> Vec<(isize, isize)> {
+        let parent = match parent {
+            Some(parent) => parent,
+            None => return self.neighbours(query, tile),
+        };
+        let (x, y) = tile;
+        let dx = (x - parent.0).signum();
+        let dy = (y - parent.1).signum();
+        let walkable = |x: isize, y: isize| self.walkable(query, x, y);
+        let mut neighbours = vec![];
+        if dx != 0 && dy != 0 {
+            if walkable(x, y + dy) {
+                neighbours.push((x, y + dy));
+            }
+            if walkable(x + dx, y) {
+                neighbours.push((x + dx, y));
+            }
+            if walkable(x, y + dy) && walkable(x + dx, y) {
+                neighbours.push((x + dx, y + dy));
+            }
+        } else if dx != 0 {
+            let (next, top, bottom) = (walkable(x + dx, y), walkable(x, y + 1), walkable(x, y - 1));
+            if next {
+                neighbours.push((x + dx, y));
+                if top {
+                    neighbours.push((x + dx, y + 1));
+                }
+                if bottom {
+                    neighbours.push((x + dx, y - 1));
+                }
+            }
+            if top {
+                neighbours.push((x, y + 1));
+            }
+            if bottom {
+                neighbours.push((x, y - 1));
+            }
+        } else {
+            let (next, right, left) = (walkable(x, y + dy), walkable(x + 1, y), walkable(x - 1, y));
+            if next {
+                neighbours.push((x, y + dy));
+                if right {
+                    neighbours.push((x + 1, y + dy));
+                }
+                if left {
+                    neighbours.push((x - 1, y + dy));
+                }
+            }
+            if right {
+                neighbours.push((x + 1, y));
+            }
+            if left {
+                neighbours.push((x - 1, y));
+            }
+        }
+        neighbours
+    }
+
+    /// Tiles from the start to the goal, `None` if the goal can't be reached.
+    fn search(&self, query: &Query) -> Option<Vec<(isize, isize)>> {
+        if !self.walkable(query, query.goal.0, query.goal.1)
+            || !self.walkable(query, query.start.0, query.start.1)
+        {
+            return None;
+        }
+        let width = self.pathing.width;
+        let tile = |index: usize| ((index % width) as isize, (index / width) as isize);
+        let min_weight = query.weights.map_or(1.0, |weights| {
+            weights
+                .iter()
+                .filter(|w| **w > 0.0 && w.is_finite())
+                .fold(f32::INFINITY, |a, &b| a.min(b))
+        });
+        let heuristic =
+            |t: (isize, isize)| octile(query.goal.0 - t.0, query.goal.1 - t.1) * min_weight;
+
+        let mut cost = vec![f32::INFINITY; self.clearance.len()];
+        let mut parent = vec![usize::MAX; self.clearance.len()];
+        let mut closed = vec![false; self.clearance.len()];
+        let mut open = BinaryHeap::new();
+        let start = self.index(query.start);
+        cost[start] = 0.0;
+        open.push(Node {
+            estimate: heuristic(query.start),
+            index: start,
+        });
+        let goal = self.index(query.goal);
+        while let Some(Node { index, .. }) = open.pop() {
+            if closed[index] {
+                continue;
+            }
+            if index == goal {
+                break;
+            }
+            closed[index] = true;
+            let current = tile(index);
+            let successors: Vec<((isize, isize), f32)> = match query.weights {
+                // Jump point search for uniform costs
+                None => {
+                    let from = if parent[index] == usize::MAX {
+                        None
+                    } else {
+                        Some(tile(parent[index]))
+                    };
+                    self.pruned_neighbours(query, current, from)
+                        .into_iter()
+                        .filter_map(|n| self.jump(query, n, n.0 - current.0, n.1 - current.1))
+                        .map(|j| (j, octile(j.0 - current.0, j.1 - current.1)))
+                        .collect()
+                }
+                Some(_) => self
+                    .neighbours(query, current)
+                    .into_iter()
+                    .filter_map(|n| {
+                        self.line_cost(query, tile_center(current), tile_center(n))
+                            .map(|c| (n, c))
+                    })
+                    .collect(),
+            };
+            for (next, step) in successors {
+                let next_index = self.index(next);
+                let next_cost = cost[index] + step;
+                if !closed[next_index] && next_cost < cost[next_index] {
+                    cost[next_index] = next_cost;
+                    parent[next_index] = index;
+                    open.push(Node {
+                        estimate: next_cost + heuristic(next),
+                        index: next_index,
+                    });
+                }
+            }
+        }
+        if cost[goal].is_infinite() {
+            return None;
+        }
+
+        // Jump points are filled in with the tiles between them
+        let mut jump_points = vec![goal];
+        while let Some(&last) = jump_points.last() {
+            if parent[last] == usize::MAX {
+                break;
+            }
+            jump_points.push(parent[last]);
+        }
+        jump_points.reverse();
+        let mut tiles = vec![query.start];
+        for pair in jump_points.windows(2) {
+            let (mut current, to) = (tile(pair[0]), tile(pair[1]));
+            let (dx, dy) = ((to.0 - current.0).signum(), (to.1 - current.1).signum());
+            while current != to {
+                current = (current.0 + dx, current.1 + dy);
+                tiles.push(current);
+            }
+        }
+        Some(tiles)
+    }
+
+    /// Indices of the tiles the path turns at, with the first and last tile.
+    fn turns(tiles: &[(isize, isize)]) -> Vec<usize> {
+        let mut turns = vec![0];
+        for i in 1..tiles.len() - 1 {
+            let before = (tiles[i].0 - tiles[i - 1].0, tiles[i].1 - tiles[i - 1].1);
+            let after = (tiles[i + 1].0 - tiles[i].0, tiles[i + 1].1 - tiles[i].1);
+            if before != after {
+                turns.push(i);
+            }
+        }
+        if tiles.len() > 1 {
+            turns.push(tiles.len() - 1);
+        }
+        turns
+    }
+
+    /// Indices of the tiles left after string pulling: each waypoint goes straight to the furthest tile of
+    /// the path that can be reached in a line without costing more.
+    fn smooth(&self, query: &Query, tiles: &[(isize, isize)]) -> Vec<usize> {
+        let mut travelled = vec![0.0];
+        for pair in tiles.windows(2) {
+            let step = self
+                .line_cost(query, tile_center(pair[0]), tile_center(pair[1]))
+                .unwrap_or(f32::INFINITY);
+            travelled.push(travelled.last().unwrap() + step);
+        }
+        let mut waypoints = vec![0];
+        let mut i = 0;
+        while i < tiles.len() - 1 {
+            let mut j = i + 1;
+            for k in i + 2..tiles.len() {
+                match self.line_cost(query, tile_center(tiles[i]), tile_center(tiles[k])) {
+                    Some(cost) if cost <= travelled[k] - travelled[i] + SMOOTHING_TOLERANCE => {
+                        j = k
+                    }
+                    _ => break,
+                }
+            }
+            waypoints.push(j);
+            i = j;
+        }
+        waypoints
+    }
+
+    /// Waypoints from `start` to `goal`, see `find_path`.
+    pub fn path(
+        &self,
+        start: (f32, f32),
+        goal: (f32, f32),
+        radius: f32,
+        weights: Option<&[f32]>,
+        smooth: bool,
+    ) -> Option<Path> {
+        let query = Query {
+            start: (start.0.floor() as isize, start.1.floor() as isize),
+            goal: (goal.0.floor() as isize, goal.1.floor() as isize),
+            radius,
+            weights,
+        };
+        let tiles = self.search(&query)?;
+        let indices = if smooth {
+            self.smooth(&query, &tiles)
+        } else {
+            PathFinder::turns(&tiles)
+        };
+        let mut waypoints: Vec<(f32, f32)> =
+            indices.iter().map(|&i| tile_center(tiles[i])).collect();
+        // Start and goal keep their exact positions
+        waypoints[0] = start;
+        if waypoints.len() > 1 {
+            *waypoints.last_mut().unwrap() = goal;
+        } else {
+            waypoints.push(goal);
+        }
+        Some(waypoints)
+    }
+
+    /// `path` for each `(start, goal, radius)` of `queries`, in parallel.
+    pub fn paths(
+        &self,
+        queries: &[PathQuery],
+        weights: Option<&[f32]>,
+        smooth: bool,
+    ) -> Vec<Option<Path>> {
+        queries
+            .par_iter()
+            .map(|&(start, goal, radius)| self.path(start, goal, radius, weights, smooth))
+            .collect()
+    }
+}
@@ -0,0 +1,619 @@
+//! Reads the tracker events of `.SC2Replay` files and finds the engagements in them.
+//!
+//! Tracker events are stored in the self describing "versioned" format of s2protocol, so they can be
+//! decoded without the protocol definitions of the game version that recorded the replay.
+use crate::generated_enums::UnitTypeId;
+use crate::mpq::MpqArchive;
+use std::collections::{HashMap, HashSet};
+use std::convert::TryFrom;
+use std::path::Path;
+
+/// Game loops per second at faster game speed, like python-sc2's `time`.
+pub const LOOPS_PER_SECOND: f32 = 22.4;
+
+/// A value in the versioned format.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Array(Vec<Value>),
+    BitArray(i64, Vec<u8>),
+    Blob(Vec<u8>),
+    Choice(i64, Box<Value>),
+    Optional(Option<Box<Value>>),
+    Struct(Vec<(i64, Value)>),
+    /// Bools, fourccs, reals and fixed size ints, which can't be told apart without the protocol.
+    Bytes(Vec<u8>),
+    Int(i64),
+}
+
+impl Value {
+    pub fn field(&self, tag: i64) -> Option<&Value> {
+        match self {
+            Value::Struct(fields) => fields.iter().find(|(t, _)| *t == tag).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    /// Ints, also inside choices and optionals.
+    pub fn int(&self) -> Option<i64> {
+        match self {
+            Value::Int(value) => Some(*value),
+            Value::Choice(_, value) => value.int(),
+            Value::Optional(value) => value.as_ref().and_then(|v| v.int()),
+            _ => None,
+        }
+    }
+
+    pub fn string(&self) -> Option<String> {
+        match self {
+            Value::Blob(bytes) => Some(String::from_utf8_lossy(bytes).into_owned()),
+            _ => None,
+        }
+    }
+
+    fn int_field(&self, tag: i64) -> Result<i64, String> {
+        self.field(tag)
+            .and_then(|v| v.int())
+            .ok_or_else(|| format!("Missing int field {}", tag))
+    }
+
+    fn string_field(&self, tag: i64) -> Result<String, String> {
+        self.field(tag)
+            .and_then(|v| v.string())
+            .ok_or_else(|| format!("Missing blob field {}", tag))
+    }
+}
+
+/// Deepest nesting of values accepted, tracker events are only a few levels deep.
+const MAX_DEPTH: usize = 32;
+
+struct VersionedDecoder<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> VersionedDecoder<'a> {
+    fn done(&self) -> bool {
+        self.position >= self.data.len()
+    }
+
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8], String> {
+        let bytes = self
+            .position
+            .checked_add(len)
+            .and_then(|end| self.data.get(self.position..end))
+            .ok_or_else(|| "Unexpected end of tracker events".to_string())?;
+        self.position += len;
+        Ok(bytes)
+    }
+
+    fn byte(&mut self) -> Result<u8, String> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    fn vint(&mut self) -> Result<i64, String> {
+        let mut b = self.byte()?;
+        let negative = b & 1 != 0;
+        let mut result = ((b >> 1) & 0x3F) as i64;
+        let mut bits = 6;
+        while b & 0x80 != 0 {
+            if bits > 56 {
+                return Err("Invalid variable length int".to_string());
+            }
+            b = self.byte()?;
+            result |= ((b & 0x7F) as i64) << bits;
+            bits += 7;
+        }
+        Ok(if negative { -result } else { result })
+    }
+
+    fn len(&mut self) -> Result<usize, String> {
+        let len = self.vint()?;
+        if len < 0 || len as usize > self.data.len() - self.position {
+            return Err(format!("Invalid length {}", len));
+        }
+        Ok(len as usize)
+    }
+
+    fn value(&mut self) -> Result<Value, String> {
+        self.nested_value(0)
+    }
+
+    /// Value `depth` levels inside arrays, choices, optionals and structs.
+    fn nested_value(&mut self, depth: usize) -> Result<Value, String> {
+        if depth > MAX_DEPTH {
+            return Err(format!("Values nested deeper than {} levels", MAX_DEPTH));
+        }
+        Ok(match self.byte()? {
+            0 => {
+                let len = self.len()?;
+                Value::Array(
+                    (0..len)
+                        .map(|_| self.nested_value(depth + 1))
+                        .collect::<Result<_, _>>()?,
+                )
+            }
+            1 => {
+                let bits = self.vint()?;
+                if bits < 0 {
+                    return Err(format!("Invalid bit array length {}", bits));
+                }
+                Value::BitArray(
+                    bits,
+                    self.bytes(((bits as u64).div_ceil(8)) as usize)?.to_vec(),
+                )
+            }
+            2 => {
+                let len = self.len()?;
+                Value::Blob(self.bytes(len)?.to_vec())
+            }
+            3 => {
+                let tag = self.vint()?;
+                Value::Choice(tag, Box::new(self.nested_value(depth + 1)?))
+            }
+            4 => match self.byte()? {
+                0 => Value::Optional(None),
+                _ => Value::Optional(Some(Box::new(self.nested_value(depth + 1)?))),
+            },
+            5 => {
+                let len = self.len()?;
+                let mut fields = Vec::with_capacity(len);
+                for _ in 0..len {
+                    let tag = self.vint()?;
+                    fields.push((tag, self.nested_value(depth + 1)?));
+                }
+                Value::Struct(fields)
+            }
+            6 => Value::Bytes(self.bytes(1)?.to_vec()),
+            7 => Value::Bytes(self.bytes(4)?.to_vec()),
+            8 => Value::Bytes(self.bytes(8)?.to_vec()),
+            9 => Value::Int(self.vint()?),
+            t => return Err(format!("Unknown type {} at byte {}", t, self.position - 1)),
+        })
+    }
+}
+
+/// Unit tags in replays combine the index and recycle count, like the tags python-sc2 reports.
+fn unit_tag(index: i64, recycle: i64) -> Result<u64, String> {
+    if !(0..1 << 46).contains(&index) || !(0..1 << 18).contains(&recycle) {
+        return Err(format!(
+            "Invalid unit tag index {} recycle {}",
+            index, recycle
+        ));
+    }
+    Ok(((index as u64) << 18) + recycle as u64)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum TrackerEvent {
+    /// A unit was created, or a structure started building.
+    UnitBorn {
+        tag: u64,
+        unit_type: String,
+        player: u32,
+        x: f32,
+        y: f32,
+    },
+    UnitDied {
+        tag: u64,
+        killer_player: Option<u32>,
+        x: f32,
+        y: f32,
+    },
+    UnitOwnerChange {
+        tag: u64,
+        player: u32,
+    },
+    UnitTypeChange {
+        tag: u64,
+        unit_type: String,
+    },
+    Upgrade {
+        player: u32,
+        name: String,
+        count: i64,
+    },
+    /// Approximate positions of the units that dealt or took damage since the last positions event,
+    /// by unit index.
+    UnitPositions {
+        units: Vec<(u32, f32, f32)>,
+    },
+    Other(i64),
+}
+
+const PLAYER_STATS_EVENT: i64 = 0;
+const UNIT_BORN_EVENT: i64 = 1;
+const UNIT_DIED_EVENT: i64 = 2;
+const UNIT_OWNER_CHANGE_EVENT: i64 = 3;
+const UNIT_TYPE_CHANGE_EVENT: i64 = 4;
+const UPGRADE_EVENT: i64 = 5;
+const UNIT_INIT_EVENT: i64 = 6;
+const UNIT_POSITIONS_EVENT: i64 = 8;
+
+impl TrackerEvent {
+    fn decode(event_id: i64, value: &Value) -> Result<Self, String> {
+        Ok(match event_id {
+            UNIT_BORN_EVENT | UNIT_INIT_EVENT => TrackerEvent::UnitBorn {
+                tag: unit_tag(value.int_field(0)?, value.int_field(1)?)?,
+                unit_type: value.string_field(2)?,
+                player: value.int_field(3)? as u32,
+                x: value.int_field(5)? as f32,
+                y: value.int_field(6)? as f32,
+            },
+            UNIT_DIED_EVENT => TrackerEvent::UnitDied {
+                tag: unit_tag(value.int_field(0)?, value.int_field(1)?)?,
+                killer_player: value.field(2).and_then(|v| v.int()).map(|p| p as u32),
+                x: value.int_field(3)? as f32,
+                y: value.int_field(4)? as f32,
+            },
+            UNIT_OWNER_CHANGE_EVENT => TrackerEvent::UnitOwnerChange {
+                tag: unit_tag(value.int_field(0)?, value.int_field(1)?)?,
+                player: value.int_field(2)? as u32,
+            },
+            UNIT_TYPE_CHANGE_EVENT => TrackerEvent::UnitTypeChange {
+                tag: unit_tag(value.int_field(0)?, value.int_field(1)?)?,
+                unit_type: value.string_field(2)?,
+            },
+            UPGRADE_EVENT => TrackerEvent::Upgrade {
+                player: value.int_field(0)? as u32,
+                name: value.string_field(1)?,
+                count: value.int_field(2)?,
+            },
+            UNIT_POSITIONS_EVENT => {
+                let mut index = value.int_field(0)?;
+                let items = match value.field(1) {
+                    Some(Value::Array(items)) => items,
+                    _ => return Err("Missing unit positions".to_string()),
+                };
+                let mut units = Vec::with_capacity(items.len() / 3);
+                for item in items.chunks_exact(3) {
+                    let ints: Vec<i64> = item.iter().filter_map(|v| v.int()).collect();
+                    if ints.len() != 3 {
+                        return Err("Invalid unit position".to_string());
+                    }
+                    index = index
+                        .checked_add(ints[0])
+                        .filter(|i| u32::try_from(*i).is_ok())
+                        .ok_or_else(|| "Invalid unit index".to_string())?;
+                    units.push((index as u32, ints[1] as f32 * 4.0, ints[2] as f32 * 4.0));
+                }
+                TrackerEvent::UnitPositions { units }
+            }
+            id => TrackerEvent::Other(id),
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimedEvent {
+    pub game_loop: u32,
+    pub event: TrackerEvent,
+}
+
+/// Decodes the contents of `replay.tracker.events`.
+pub fn decode_tracker_events(data: &[u8]) -> Result<Vec<TimedEvent>, String> {
+    let mut decoder = VersionedDecoder { data, position: 0 };
+    let mut events = vec![];
+    let mut game_loop: u32 = 0;
+    while !decoder.done() {
+        let delta = decoder
+            .value()?
+            .int()
+            .ok_or_else(|| "Invalid game loop delta".to_string())?;
+        game_loop = u32::try_from(delta)
+            .ok()
+            .and_then(|delta| game_loop.checked_add(delta))
+            .ok_or_else(|| format!("Invalid game loop delta {}", delta))?;
+        let event_id = decoder
+            .value()?
+            .int()
+            .ok_or_else(|| "Invalid event id".to_string())?;
+        let value = decoder.value()?;
+        if event_id == PLAYER_STATS_EVENT {
+            continue;
+        }
+        events.push(TimedEvent {
+            game_loop,
+            event: TrackerEvent::decode(event_id, &value)?,
+        });
+    }
+    Ok(events)
+}
+
+/// Unit type of a replay unit type name like `"SiegeTankSieged"`.
+pub fn unit_type_from_name(name: &str) -> Option<UnitTypeId> {
+    serde_json::from_value(serde_json::Value::String(name.to_uppercase())).ok()
+}
+
+/// Everything that happened to one unit during the replay.
+#[derive(Clone, Debug, Default)]
+pub struct UnitHistory {
+    pub tag: u64,
+    pub born: u32,
+    /// Game loop, killing player and position of its death.
+    pub died: Option<(u32, Option<u32>, f32, f32)>,
+    /// Owner from each game loop on.
+    pub owners: Vec<(u32, u32)>,
+    /// Unit type name from each game loop on.
+    pub types: Vec<(u32, String)>,
+    /// Known positions, from unit born and unit positions events.
+    pub positions: Vec<(u32, f32, f32)>,
+}
+
+fn at<T: Clone>(changes: &[(u32, T)], game_loop: u32) -> Option<T> {
+    changes
+        .iter()
+        .take_while(|(l, _)| *l <= game_loop)
+        .last()
+        .or_else(|| changes.first())
+        .map(|(_, v)| v.clone())
+}
+
+impl UnitHistory {
+    pub fn owner_at(&self, game_loop: u32) -> u32 {
+        at(&self.owners, game_loop).unwrap_or(0)
+    }
+
+    pub fn unit_type_at(&self, game_loop: u32) -> String {
+        at(&self.types, game_loop).unwrap_or_default()
+    }
+
+    pub fn is_alive_at(&self, game_loop: u32) -> bool {
+        self.born <= game_loop && self.died.map_or(true, |(died, ..)| died >= game_loop)
+    }
+}
+
+/// Units and upgrades of a 1v1 replay.
+pub struct Replay {
+    pub name: String,
+    /// The two player ids.
+    pub players: [u32; 2],
+    pub units: Vec<UnitHistory>,
+    /// Game loop, player and name of each researched upgrade.
+    pub upgrades: Vec<(u32, u32, String)>,
+}
+
+/// Player ids of neutral units.
+const NEUTRAL_PLAYERS: [u32; 2] = [0, 16];
+
+impl Replay {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let archive = MpqArchive::open(path)?;
+        let events = decode_tracker_events(&archive.read_file("replay.tracker.events")?)?;
+        let name = path
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .into_owned();
+        Replay::from_events(name, &events)
+    }
+
+    pub fn from_events(name: String, events: &[TimedEvent]) -> Result<Self, String> {
+        let mut units: Vec<UnitHistory> = vec![];
+        let mut by_tag: HashMap<u64, usize> = HashMap::new();
+        // Positions events only have the unit index, the tag without its recycle count
+        let mut by_index: HashMap<u32, usize> = HashMap::new();
+        let mut upgrades = vec![];
+        let mut players: HashSet<u32> = HashSet::new();
+        for TimedEvent { game_loop, event } in events {
+            let game_loop = *game_loop;
+            match event {
+                TrackerEvent::UnitBorn {
+                    tag,
+                    unit_type,
+                    player,
+                    x,
+                    y,
+                } => {
+                    if !NEUTRAL_PLAYERS.contains(player) {
+                        players.insert(*player);
+                    }
+                    by_tag.insert(*tag, units.len());
+                    by_index.insert((*tag >> 18) as u32, units.len());
+                    units.push(UnitHistory {
+                        tag: *tag,
+                        born: game_loop,
+                        died: None,
+                        owners: vec![(game_loop, *player)],
+                        types: vec![(game_loop, unit_type.clone())],
+                        positions: vec![(game_loop, *x, *y)],
+                    });
+                }
+                TrackerEvent::UnitDied {
+                    tag,
+                    killer_player,
+                    x,
+                    y,
+                } => {
+                    if let Some(&i) = by_tag.get(tag) {
+                        units[i].died = Some((game_loop, *killer_player, *x, *y));
+                        units[i].positions.push((game_loop, *x, *y));
+                    }
+                }
+                TrackerEvent::UnitOwnerChange { tag, player } => {
+                    if let Some(&i) = by_tag.get(tag) {
+                        units[i].owners.push((game_loop, *player));
+                    }
+                }
+                TrackerEvent::UnitTypeChange { tag, unit_type } => {
+                    if let Some(&i) = by_tag.get(tag) {
+                        units[i].types.push((game_loop, unit_type.clone()));
+                    }
+                }
+                TrackerEvent::Upgrade { player, name, .. } => {
+                    upgrades.push((game_loop, *player, name.clone()));
+                }
+                TrackerEvent::UnitPositions { units: positions } => {
+                    for (index, x, y) in positions {
+                        if let Some(&i) = by_index.get(index) {
+                            units[i].positions.push((game_loop, *x, *y));
+                        }
+                    }
+                }
+                TrackerEvent::Other(_) => {}
+            }
+        }
+        let mut players: Vec<u32> = players.into_iter().collect();
+        players.sort_unstable();
+        if players.len() != 2 {
+            return Err(format!(
+                "{}: only 1v1 replays are supported, found players {:?}",
+                name, players
+            ));
+        }
+        Ok(Replay {
+            name,
+            players: [players[0], players[1]],
+            units,
+            upgrades,
+        })
+    }
+
+    /// Names of the upgrades `player` finished by `game_loop`.
+    pub fn upgrades_at(&self, player: u32, game_loop: u32) -> Vec<&str> {
+        self.upgrades
+            .iter()
+            .filter(|(l, p, _)| *p == player && *l <= game_loop)
+            .map(|(_, _, name)| name.as_str())
+            .collect()
+    }
+
+    /// Engagements between the two players, found by grouping the deaths of units killed by the other
+    /// player that are close in time and space.
+    pub fn engagements(&self, options: &EngagementOptions) -> Vec<Engagement> {
+        let gap = (options.gap * LOOPS_PER_SECOND) as u32;
+        let lead = (options.lead * LOOPS_PER_SECOND) as u32;
+        let mut deaths: Vec<(u32, f32, f32)> = self
+            .units
+            .iter()
+            .filter_map(|u| {
+                let (game_loop, killer, x, y) = u.died?;
+                let owner = u.owner_at(game_loop);
+                let killer = killer?;
+                if killer != owner
+                    && self.players.contains(&killer)
+                    && self.players.contains(&owner)
+                {
+                    Some((game_loop, x, y))
+                } else {
+                    None
+                }
+            })
+            .collect();
+        deaths.sort_by_key(|d| d.0);
+
+        // Deaths of each cluster, clusters are closed once no unit died in them for `gap` loops
+        let mut clusters: Vec<Vec<(u32, f32, f32)>> = vec![];
+        let mut open: Vec<usize> = vec![];
+        for death in deaths {
+            open.retain(|&c| clusters[c].last().unwrap().0 + gap >= death.0);
+            let near = open.iter().copied().find(|&c| {
+                let (x, y) = centroid(&clusters[c]);
+                (x - death.1).hypot(y - death.2) <= options.radius
+            });
+            match near {
+                Some(c) => clusters[c].push(death),
+                None => {
+                    open.push(clusters.len());
+                    clusters.push(vec![death]);
+                }
+            }
+        }
+
+        clusters
+            .iter()
+            .filter(|deaths| deaths.len() >= options.min_deaths as usize)
+            .map(|deaths| {
+                let first = deaths.first().unwrap().0;
+                let end = deaths.last().unwrap().0;
+                let start = first.saturating_sub(lead);
+                let (x, y) = centroid(deaths);
+                let mut armies: [Vec<EngagementUnit>; 2] = [vec![], vec![]];
+                for unit in self.units.iter().filter(|u| u.is_alive_at(start)) {
+                    let side = match self.players.iter().position(|&p| p == unit.owner_at(start)) {
+                        Some(side) => side,
+                        None => continue,
+                    };
+                    // Units that took or dealt damage close to the fight
+                    let fought = unit.positions.iter().any(|&(l, ux, uy)| {
+                        l > start
+                            && l <= end + options.positions_interval
+                            && (ux - x).hypot(uy - y) <= options.radius
+                    });
+                    if fought {
+                        let died = unit.died.is_some_and(|(l, ..)| l <= end);
+                        armies[side].push(EngagementUnit {
+                            tag: unit.tag,
+                            unit_type: unit.unit_type_at(start),
+                            died,
+                        });
+                    }
+                }
+                Engagement {
+                    start,
+                    end,
+                    x,
+                    y,
+                    armies,
+                }
+            })
+            .collect()
+    }
+}
+
+fn centroid(deaths: &[(u32, f32, f32)]) -> (f32, f32) {
+    let n = deaths.len() as f32;
+    (
+        deaths.iter().map(|d| d.1).sum::<f32>() / n,
+        deaths.iter().map(|d| d.2).sum::<f32>() / n,
+    )
+}
+
+/// How deaths are grouped into engagements.
+#[derive(Clone, Debug)]
+pub struct EngagementOptions {
+    /// Seconds without deaths that end an engagement.
+    pub gap: f32,
+    /// Max distance of deaths and participating units to the centre of the engagement.
+    pub radius: f32,
+    /// Engagements with fewer deaths are ignored.
+    pub min_deaths: u32,
+    /// Seconds before the first death the engagement starts.
+    pub lead: f32,
+    /// Game loops between unit positions events, units that fought show up in the first one after
+    /// the engagement.
+    pub positions_interval: u32,
+}
+
+impl Default for EngagementOptions {
+    fn default() -> Self {
+        EngagementOptions {
+            gap: 10.0,
+            radius: 15.0,
+            min_deaths: 3,
+            lead: 5.0,
+            positions_interval: 240,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct EngagementUnit {
+    pub tag: u64,
+    /// Replay unit type name at the start of the engagement.
+    pub unit_type: String,
+    /// Whether the unit died during the engagement.
+    pub died: bool,
+}
+
+/// A fight between the two players of a replay.
+#[derive(Clone, Debug)]
+pub struct Engagement {
+    /// Game loop before the first death.
+    pub start: u32,
+    /// Game loop of the last death.
+    pub end: u32,
+    pub x: f32,
+    pub y: f32,
+    /// Units of each player that fought.
+    pub armies: [Vec<EngagementUnit>; 2],
+}
@@ -0,0 +1,109 @@
+use crate::combat_predictor::{CombatPredictor, CombatSettings};
+use crate::combat_unit::CombatUnit;
+use crate::generated_enums::UnitTypeId;
+use crate::unit_database;
+use serde::{Deserialize, Serialize};
+
+/// Units of one type in a `Scenario`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct UnitGroup {
+    /// Unit type name, e.g. `"MARINE"`.
+    pub unit: UnitTypeId,
+    #[serde(default = "default_count")]
+    pub count: u32,
+    #[serde(default)]
+    pub attack_upgrade_level: i64,
+    #[serde(default)]
+    pub armor_upgrade_level: i64,
+    #[serde(default)]
+    pub shield_upgrade_level: i64,
+    /// Health of each unit, full health if not given.
+    #[serde(default)]
+    pub health: Option<f32>,
+}
+
+fn default_count() -> u32 {
+    1
+}
+
+impl UnitGroup {
+    fn build(&self) -> Result<Vec<CombatUnit>, String> {
+        let mut unit = unit_database::get_unit(self.unit)
+            .ok_or_else(|| format!("No unit data for {:?}", self.unit))?;
+        unit.attack_upgrade_level = self.attack_upgrade_level;
+        unit.armor_upgrade_level = self.armor_upgrade_level;
+        unit.shield_upgrade_level = self.shield_upgrade_level;
+        if let Some(health) = self.health {
+            unit.health = health.min(unit.health_max);
+        }
+        Ok(vec![unit; self.count as usize])
+    }
+}
+
+/// An engagement between two armies, as read from a scenario file.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Scenario {
+    #[serde(default)]
+    pub name: String,
+    pub units1: Vec<UnitGroup>,
+    pub units2: Vec<UnitGroup>,
+    #[serde(default)]
+    pub defender_player: u32,
+    /// Settings not given keep their default.
+    #[serde(default)]
+    pub settings: CombatSettings,
+}
+
+/// Outcome of a `Scenario`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ScenarioReport {
+    pub name: String,
+    pub winner: u32,
+    /// Health + shield left per side.
+    pub health_left: Vec<f32>,
+    /// Health + shield per side at the start.
+    pub health_start: Vec<f32>,
+    pub retreated: Vec<u32>,
+    pub time: f32,
+}
+
+impl Scenario {
+    /// Units of both sides.
+    pub fn armies(&self) -> Result<(Vec<CombatUnit>, Vec<CombatUnit>), String> {
+        let mut armies: Vec<Vec<CombatUnit>> = Vec::with_capacity(2);
+        for groups in [&self.units1, &self.units2].iter() {
+            let mut army: Vec<CombatUnit> = vec![];
+            for group in groups.iter() {
+                army.extend(group.build()?);
+            }
+            armies.push(army);
+        }
+        let units2 = armies.pop().unwrap();
+        let units1 = armies.pop().unwrap();
+        Ok((units1, units2))
+    }
+
+    pub fn run(&self, predictor: &CombatPredictor) -> Result<ScenarioReport, String> {
+        let (units1, units2) = self.armies()?;
+        let health_start = [&units1, &units2]
+            .iter()
+            .map(|army| army.iter().map(|u| u.health + u.shield).sum())
+            .collect();
+        let result = predictor._predict_engage(
+            units1,
+            units2,
+            vec![],
+            vec![],
+            self.defender_player,
+            &self.settings,
+        );
+        Ok(ScenarioReport {
+            name: self.name.clone(),
+            winner: result.winner,
+            health_left: result.health_left,
+            health_start,
+            retreated: result.retreated,
+            time: result.time,
+        })
+    }
+}
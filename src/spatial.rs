@@ -0,0 +1,293 @@
+//! Proximity queries over many units, rebuilt every step.
+use crate::combat_unit::{CombatUnit, UnitCache};
+use crate::conversion::extract_field;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+/// A unit in a `SpatialIndex`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IndexedUnit {
+    pub tag: u64,
+    pub position: (f32, f32),
+    pub radius: f32,
+    pub is_flying: bool,
+}
+
+impl<'source> FromPyObject<'source> for IndexedUnit {
+    /// `(tag, x, y, radius)` or `(tag, x, y, radius, is_flying)` tuples, or python-sc2 `Unit`s.
+    fn extract(obj: &'source PyAny) -> PyResult<Self> {
+        if let Ok((tag, x, y, radius)) = obj.extract::<(u64, f32, f32, f32)>() {
+            return Ok(IndexedUnit {
+                tag,
+                position: (x, y),
+                radius,
+                is_flying: false,
+            });
+        }
+        if let Ok((tag, x, y, radius, is_flying)) = obj.extract::<(u64, f32, f32, f32, bool)>() {
+            return Ok(IndexedUnit {
+                tag,
+                position: (x, y),
+                radius,
+                is_flying,
+            });
+        }
+        Ok(IndexedUnit {
+            tag: extract_field(obj, "tag")?,
+            position: extract_field(obj, "position")?,
+            radius: extract_field(obj, "radius")?,
+            is_flying: extract_field(obj, "is_flying")?,
+        })
+    }
+}
+
+/// Most cells of a `SpatialIndex`, larger cells are used if the units are spread over more.
+const MAX_CELLS: usize = 1 << 18;
+
+fn distance_squared(a: (f32, f32), b: (f32, f32)) -> f32 {
+    (a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)
+}
+
+/// Uniform grid of units for radius, nearest neighbour and attack range queries. Building it sorts the units
+/// into cells in linear time, so it's cheap to rebuild every step.
+#[pyclass(module = "sc2_helper.sc2_helper")]
+#[derive(Clone, Debug)]
+pub struct SpatialIndex {
+    units: Vec<IndexedUnit>,
+    cell_size: f32,
+    origin: (f32, f32),
+    columns: usize,
+    rows: usize,
+    /// Start of each cell in `order`, with an extra end offset.
+    offsets: Vec<usize>,
+    /// Indices into `units`, grouped by cell.
+    order: Vec<usize>,
+    max_radius: f32,
+}
+
+#[pymethods]
+impl SpatialIndex {
+    /// `units` are `(tag, x, y, radius)` tuples, optionally with `is_flying`, or python-sc2 `Unit`s.
+    /// `cell_size` is raised if the units are spread over too many cells of that size.
+    #[new]
+    #[pyo3(signature = (units, cell_size = 4.0))]
+    pub fn py_new(units: Vec<IndexedUnit>, cell_size: f32) -> PyResult<Self> {
+        SpatialIndex::new(units, cell_size).map_err(PyValueError::new_err)
+    }
+
+    fn __len__(&self) -> usize {
+        self.units.len()
+    }
+
+    /// Tags of the units whose centers are closer than `distance` to `position`, like `closer_than` of
+    /// python-sc2. With `include_radius` the distance is measured to their edges instead.
+    #[pyo3(signature = (position, distance, include_radius = false))]
+    pub fn closer_than(
+        &self,
+        position: (f32, f32),
+        distance: f32,
+        include_radius: bool,
+    ) -> Vec<u64> {
+        self.query(position, distance + self.max_radius)
+            .filter(|unit| {
+                let reach = if include_radius {
+                    distance + unit.radius
+                } else {
+                    distance
+                };
+                distance_squared(unit.position, position) < reach * reach
+            })
+            .map(|unit| unit.tag)
+            .collect()
+    }
+
+    /// Tags of the `k` units closest to `position`, closest first, within `max_distance` if given.
+    #[pyo3(signature = (position, k, max_distance = None))]
+    pub fn k_nearest(&self, position: (f32, f32), k: usize, max_distance: Option<f32>) -> Vec<u64> {
+        self.nearest(position, k, max_distance.unwrap_or(f32::INFINITY))
+            .into_iter()
+            .map(|unit| unit.tag)
+            .collect()
+    }
+
+    /// Tags of the units `unit` at `position` can shoot, like `in_attack_range_of` of python-sc2: the
+    /// distance between the centers is at most the radii plus the range of the weapon against air or ground
+    /// plus `bonus_distance`. `unit` is a `CombatUnit` or a python-sc2 `Unit`, whose position is used if
+    /// `position` isn't given.
+    #[pyo3(signature = (unit, position = None, bonus_distance = 0.0))]
+    pub fn in_attack_range_of(
+        &self,
+        unit: &PyAny,
+        position: Option<(f32, f32)>,
+        bonus_distance: f32,
+    ) -> PyResult<Vec<u64>> {
+        let position = match position {
+            Some(position) => position,
+            None => extract_field(unit, "position")?,
+        };
+        let unit = CombatUnit::extract_cached(unit, &mut UnitCache::new())?;
+        Ok(self
+            .in_range(&unit, position, bonus_distance)
+            .into_iter()
+            .map(|target| target.tag)
+            .collect())
+    }
+}
+
+impl SpatialIndex {
+    pub fn new(units: Vec<IndexedUnit>, cell_size: f32) -> Result<Self, String> {
+        if cell_size.is_nan() || cell_size <= 0.0 {
+            return Err(format!("Cell size must be positive, got {}", cell_size));
+        }
+        if let Some(unit) = units
+            .iter()
+            .find(|u| !u.position.0.is_finite() || !u.position.1.is_finite())
+        {
+            return Err(format!("Unit {} has no valid position", unit.tag));
+        }
+        let (mut min, mut max) = ((f32::MAX, f32::MAX), (f32::MIN, f32::MIN));
+        for unit in units.iter() {
+            min = (min.0.min(unit.position.0), min.1.min(unit.position.1));
+            max = (max.0.max(unit.position.0), max.1.max(unit.position.1));
+        }
+        let origin = if units.is_empty() { (0.0, 0.0) } else { min };
+        let extent = ((max.0 - origin.0).max(0.0), (max.1 - origin.1).max(0.0));
+        if !extent.0.is_finite() || !extent.1.is_finite() {
+            return Err("Units are spread too far apart".to_string());
+        }
+        // Coarser cells if small ones would take too much memory
+        let cells = |size: f32| {
+            ((extent.0 / size).floor() as f64 + 1.0) * ((extent.1 / size).floor() as f64 + 1.0)
+        };
+        let mut cell_size = cell_size;
+        while cells(cell_size) > MAX_CELLS as f64 {
+            cell_size *= 2.0;
+        }
+        let columns = (extent.0 / cell_size) as usize + 1;
+        let rows = (extent.1 / cell_size) as usize + 1;
+        let cells = columns
+            .checked_mul(rows)
+            .filter(|&cells| cells <= MAX_CELLS)
+            .ok_or_else(|| format!("Too many cells for cell size {}", cell_size))?;
+        let mut index = SpatialIndex {
+            units,
+            cell_size,
+            origin,
+            columns,
+            rows,
+            offsets: vec![0; cells + 1],
+            order: vec![],
+            max_radius: 0.0,
+        };
+        // Counting sort of the units by cell
+        let cells: Vec<usize> = index
+            .units
+            .iter()
+            .map(|unit| {
+                let (x, y) = index.cell(unit.position);
+                y * columns + x
+            })
+            .collect();
+        for &cell in cells.iter() {
+            index.offsets[cell + 1] += 1;
+        }
+        for i in 1..index.offsets.len() {
+            index.offsets[i] += index.offsets[i - 1];
+        }
+        let mut next = index.offsets.clone();
+        index.order = vec![0; index.units.len()];
+        for (i, &cell) in cells.iter().enumerate() {
+            index.order[next[cell]] = i;
+            next[cell] += 1;
+        }
+        index.max_radius = index.units.iter().map(|u| u.radius).fold(0.0, f32::max);
+        Ok(index)
+    }
+
+    pub fn units(&self) -> &[IndexedUnit] {
+        &self.units
+    }
+
+    /// Cell of `position`, clamped to the grid.
+    fn cell(&self, position: (f32, f32)) -> (usize, usize) {
+        let clamp = |value: f32, origin: f32, count: usize| {
+            (((value - origin) / self.cell_size).max(0.0) as usize).min(count - 1)
+        };
+        (
+            clamp(position.0, self.origin.0, self.columns),
+            clamp(position.1, self.origin.1, self.rows),
+        )
+    }
+
+    /// Units in the cells overlapping the square around `position`, a superset of those within `distance`.
+    fn query(&self, position: (f32, f32), distance: f32) -> impl Iterator<Item = &IndexedUnit> {
+        let distance = distance.max(0.0);
+        let (left, top) = self.cell((position.0 - distance, position.1 - distance));
+        let (right, bottom) = self.cell((position.0 + distance, position.1 + distance));
+        (top..=bottom).flat_map(move |y| {
+            let start = self.offsets[y * self.columns + left];
+            let end = self.offsets[y * self.columns + right + 1];
+            self.order[start..end].iter().map(move |&i| &self.units[i])
+        })
+    }
+
+    /// The `k` units with centers closest to `position` and not further than `max_distance`, closest first.
+    pub fn nearest(&self, position: (f32, f32), k: usize, max_distance: f32) -> Vec<&IndexedUnit> {
+        if k == 0 || self.units.is_empty() {
+            return vec![];
+        }
+        // Double the search distance until it holds k units or covers the whole grid
+        let extent = self.cell_size * (self.columns + self.rows) as f32
+            + (position.0 - self.origin.0).abs()
+            + (position.1 - self.origin.1).abs();
+        let mut distance = self.cell_size;
+        loop {
+            let limit = distance.min(max_distance);
+            let mut found: Vec<(f32, &IndexedUnit)> = self
+                .query(position, limit)
+                .map(|unit| (distance_squared(unit.position, position), unit))
+                .filter(|(d, _)| *d <= limit * limit)
+                .collect();
+            if found.len() >= k || limit >= extent || limit >= max_distance {
+                found.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.tag.cmp(&b.1.tag)));
+                return found.into_iter().take(k).map(|(_, unit)| unit).collect();
+            }
+            distance *= 2.0;
+        }
+    }
+
+    /// Units `unit` at `position` can attack, see `in_attack_range_of`.
+    pub fn in_range(
+        &self,
+        unit: &CombatUnit,
+        position: (f32, f32),
+        bonus_distance: f32,
+    ) -> Vec<&IndexedUnit> {
+        let range = |is_flying: bool| {
+            if unit.get_dps(is_flying) > 0.0 {
+                Some(if is_flying {
+                    unit.air_range
+                } else {
+                    unit.ground_range
+                })
+            } else {
+                None
+            }
+        };
+        let max_range = match (range(false), range(true)) {
+            (None, None) => return vec![],
+            (ground, air) => ground.unwrap_or(0.0).max(air.unwrap_or(0.0)),
+        };
+        self.query(
+            position,
+            unit.radius + max_range + bonus_distance + self.max_radius,
+        )
+        .filter(|target| {
+            range(target.is_flying).is_some_and(|range| {
+                let reach = unit.radius + target.radius + range + bonus_distance;
+                distance_squared(target.position, position) <= reach * reach
+            })
+        })
+        .collect()
+    }
+}
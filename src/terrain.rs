@@ -0,0 +1,123 @@
+use crate::combat_unit::CombatUnit;
+use crate::conversion;
+use crate::generated_enums::UnitTypeId;
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+lazy_static! {
+    /// Speed multiplier of Zerg ground units on creep. Drones don't get a bonus.
+    static ref CREEP_SPEED_MULTIPLIER: HashMap<UnitTypeId, f32> = [
+        (UnitTypeId::ZERGLING, 1.3),
+        (UnitTypeId::BANELING, 1.3),
+        (UnitTypeId::ROACH, 1.3),
+        (UnitTypeId::RAVAGER, 1.3),
+        (UnitTypeId::HYDRALISK, 1.3),
+        (UnitTypeId::LURKERMP, 1.3),
+        (UnitTypeId::INFESTOR, 1.3),
+        (UnitTypeId::SWARMHOSTMP, 1.3),
+        (UnitTypeId::ULTRALISK, 1.3),
+        (UnitTypeId::QUEEN, 3.5 / 1.31),
+    ]
+    .iter()
+    .cloned()
+    .collect();
+}
+
+/// Map geometry around an engagement. Players are numbered like the armies of the prediction, 1 and 2.
+#[pyclass(module = "sc2_helper.sc2_helper")]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default)]
+pub struct Terrain {
+    /// Player whose army stands on high ground, e.g. at the top of a ramp. 0 == neither.
+    #[pyo3(get, set)]
+    pub high_ground_player: u32,
+    /// Whether the players below have vision of the high ground, e.g. from a scan or an observer. Without
+    /// it their ranged ground units can't shoot the ranged ground units on the high ground, melee units
+    /// have to come down to fight. Flying units in the army below give vision as well.
+    #[pyo3(get, set)]
+    pub high_ground_vision: bool,
+    /// Width of the choke the armies meet in. Limits how many melee units engage at once. 0.0 == open
+    /// field.
+    #[pyo3(get, set)]
+    pub choke_width: f32,
+    /// Players whose armies fight on creep, their Zerg ground units move faster.
+    #[pyo3(get, set)]
+    pub creep_players: Vec<u32>,
+}
+
+#[pymethods]
+impl Terrain {
+    #[new]
+    pub fn new() -> Self {
+        Terrain {
+            high_ground_player: 0,
+            high_ground_vision: false,
+            choke_width: 0.0,
+            creep_players: vec![],
+        }
+    }
+
+    /// Copy with players 1 and 2 swapped, for predicting with the armies the other way around.
+    pub fn swapped(&self) -> Self {
+        let swap = |player: u32| match player {
+            1 => 2,
+            2 => 1,
+            p => p,
+        };
+        Terrain {
+            high_ground_player: swap(self.high_ground_player),
+            creep_players: self.creep_players.iter().map(|&p| swap(p)).collect(),
+            ..self.clone()
+        }
+    }
+
+    /// JSON representation, also used to pickle it.
+    pub fn to_json(&self) -> PyResult<String> {
+        conversion::to_json(self)
+    }
+
+    #[staticmethod]
+    pub fn from_json(json: &str) -> PyResult<Self> {
+        conversion::from_json(json)
+    }
+
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (String,))> {
+        conversion::reduce_json::<Self, _>(py, self)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl Terrain {
+    /// Speeds up the Zerg ground units of `player` if its army is on creep.
+    pub fn apply_creep<'a>(
+        &self,
+        player: u32,
+        units: impl IntoIterator<Item = &'a mut CombatUnit>,
+    ) {
+        if !self.creep_players.contains(&player) {
+            return;
+        }
+        for unit in units {
+            if let Some(multiplier) = CREEP_SPEED_MULTIPLIER.get(&unit.type_id) {
+                unit.movement_speed *= multiplier;
+            }
+        }
+    }
+
+    /// Player whose units on the high ground `attacker` can't see, if any.
+    pub fn hidden_player(&self, attacker: u32, attacker_units: &[CombatUnit]) -> Option<u32> {
+        if self.high_ground_player == 0
+            || self.high_ground_player == attacker
+            || self.high_ground_vision
+            || attacker_units.iter().any(|u| u.is_flying && u.health > 0.0)
+        {
+            None
+        } else {
+            Some(self.high_ground_player)
+        }
+    }
+}
@@ -0,0 +1,205 @@
+use crate::combat_unit::CombatUnit;
+use crate::conversion;
+use crate::generated_enums::UnitTypeId;
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+/// State of a unit at the start of an iteration and what it did during that iteration.
+#[pyclass(module = "sc2_helper.sc2_helper")]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct UnitSnapshot {
+    /// Unit tag, or a generated id for units without a tag.
+    #[pyo3(get)]
+    pub tag: u64,
+    /// Side the unit fights for, starting at 1.
+    #[pyo3(get)]
+    pub side: u32,
+    #[pyo3(get)]
+    pub type_id: UnitTypeId,
+    #[pyo3(get)]
+    pub health: f32,
+    #[pyo3(get)]
+    pub shield: f32,
+    #[pyo3(get)]
+    pub energy: f32,
+    /// Tag of the unit attacked or healed during this iteration.
+    #[pyo3(get)]
+    pub target: Option<u64>,
+    /// Health + shield removed from the target during this iteration.
+    #[pyo3(get)]
+    pub damage_dealt: f32,
+    /// Health restored to the target during this iteration.
+    #[pyo3(get)]
+    #[serde(default)]
+    pub healing_done: f32,
+}
+
+#[pyclass(module = "sc2_helper.sc2_helper")]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TraceIteration {
+    #[pyo3(get)]
+    pub iteration: u32,
+    /// Simulation time at the start of the iteration.
+    #[pyo3(get)]
+    pub time: f32,
+    #[pyo3(get)]
+    pub units: Vec<UnitSnapshot>,
+}
+
+/// Time series of an engagement simulation, recorded when `CombatSettings.trace` is enabled.
+/// A retreat adds an iteration for the damage dealt while chasing the retreating side. The last iteration
+/// holds the state of the units after the engagement.
+#[pyclass(module = "sc2_helper.sc2_helper")]
+#[derive(Clone, Default, Deserialize, Serialize)]
+pub struct CombatTrace {
+    #[pyo3(get)]
+    pub iterations: Vec<TraceIteration>,
+    /// Index of each tag in the units of the last iteration.
+    #[serde(skip)]
+    indices: HashMap<u64, usize>,
+}
+
+impl fmt::Debug for CombatTrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "CombatTrace {{ iterations: {} }}", self.iterations.len())
+    }
+}
+
+#[pymethods]
+impl UnitSnapshot {
+    /// JSON representation, also used to pickle it.
+    pub fn to_json(&self) -> PyResult<String> {
+        conversion::to_json(self)
+    }
+
+    #[staticmethod]
+    pub fn from_json(json: &str) -> PyResult<Self> {
+        conversion::from_json(json)
+    }
+
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (String,))> {
+        conversion::reduce_json::<Self, _>(py, self)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+#[pymethods]
+impl TraceIteration {
+    /// JSON representation, also used to pickle it.
+    pub fn to_json(&self) -> PyResult<String> {
+        conversion::to_json(self)
+    }
+
+    #[staticmethod]
+    pub fn from_json(json: &str) -> PyResult<Self> {
+        conversion::from_json(json)
+    }
+
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (String,))> {
+        conversion::reduce_json::<Self, _>(py, self)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "TraceIteration(iteration={}, time={}, units={})",
+            self.iteration,
+            self.time,
+            self.units.len()
+        )
+    }
+}
+
+#[pymethods]
+impl CombatTrace {
+    /// JSON representation, also used to pickle it.
+    pub fn to_json(&self) -> PyResult<String> {
+        conversion::to_json(self)
+    }
+
+    #[staticmethod]
+    pub fn from_json(json: &str) -> PyResult<Self> {
+        conversion::from_json(json)
+    }
+
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (String,))> {
+        conversion::reduce_json::<Self, _>(py, self)
+    }
+
+    fn __len__(&self) -> usize {
+        self.iterations.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl CombatTrace {
+    /// Gives every unit without a tag, or with the tag of an earlier unit like copies of one `CombatUnit`, an
+    /// id no other unit has, so attacks can be traced back to it.
+    pub fn assign_ids<'a>(units: impl Iterator<Item = &'a mut CombatUnit>) {
+        let mut units: Vec<&mut CombatUnit> = units.collect();
+        let used: HashSet<u64> = units.iter().map(|u| u.tag).collect();
+        let mut ids = (1..).filter(|id| !used.contains(id));
+        let mut seen: HashSet<u64> = HashSet::new();
+        for unit in units.iter_mut() {
+            if unit.tag == 0 || !seen.insert(unit.tag) {
+                unit.tag = ids.next().unwrap();
+            }
+        }
+    }
+
+    /// Starts a new iteration with the current state of `armies`.
+    pub fn record(&mut self, time: f32, armies: &[Vec<CombatUnit>]) {
+        let mut units: Vec<UnitSnapshot> = vec![];
+        self.indices.clear();
+        for (side, army) in armies.iter().enumerate() {
+            for unit in army {
+                self.indices.insert(unit.tag, units.len());
+                units.push(UnitSnapshot {
+                    tag: unit.tag,
+                    side: side as u32 + 1,
+                    type_id: unit.type_id,
+                    health: unit.health,
+                    shield: unit.shield,
+                    energy: unit.energy,
+                    target: None,
+                    damage_dealt: 0.0,
+                    healing_done: 0.0,
+                });
+            }
+        }
+        self.iterations.push(TraceIteration {
+            iteration: self.iterations.len() as u32,
+            time,
+            units,
+        });
+    }
+
+    /// Records an attack in the current iteration.
+    pub fn record_attack(&mut self, attacker: u64, target: u64, damage: f32) {
+        if let (Some(&index), Some(iteration)) =
+            (self.indices.get(&attacker), self.iterations.last_mut())
+        {
+            let snapshot = &mut iteration.units[index];
+            snapshot.target = Some(target);
+            snapshot.damage_dealt += damage;
+        }
+    }
+
+    /// Records healing in the current iteration.
+    pub fn record_healing(&mut self, healer: u64, target: u64, healing: f32) {
+        if let (Some(&index), Some(iteration)) =
+            (self.indices.get(&healer), self.iterations.last_mut())
+        {
+            let snapshot = &mut iteration.units[index];
+            snapshot.target = Some(target);
+            snapshot.healing_done += healing;
+        }
+    }
+}
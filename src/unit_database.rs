@@ -0,0 +1,913 @@
+use crate::combat_unit::CombatUnit;
+use crate::enums::Attribute;
+use crate::generated_enums::UnitTypeId;
+use crate::unit_type_data::{Cost, UnitTypeData};
+use crate::weapon::{DamageBonus, Weapon, WeaponTargetType};
+use std::collections::HashMap;
+
+/// Base stats of a unit type without upgrades.
+/// Times and speeds are in normal game speed, like the values python-sc2 reports.
+struct UnitStats {
+    type_id: UnitTypeId,
+    name: &'static str,
+    attributes: &'static [Attribute],
+    minerals: i32,
+    vespene: i32,
+    time: f32,
+    food: f32,
+    health: f32,
+    shield: f32,
+    armor: f32,
+    energy_max: f32,
+    movement_speed: f32,
+    radius: f32,
+    is_flying: bool,
+    weapons: &'static [Weapon],
+}
+
+const fn weapon(
+    w_type: WeaponTargetType,
+    damage: f32,
+    attacks: i32,
+    range: f32,
+    speed: f32,
+) -> Weapon {
+    Weapon {
+        w_type,
+        damage,
+        attacks,
+        range,
+        speed,
+        damage_bonus: None,
+    }
+}
+
+const fn weapon_with_bonus(
+    w_type: WeaponTargetType,
+    damage: f32,
+    attacks: i32,
+    range: f32,
+    speed: f32,
+    bonus: (Attribute, f32),
+) -> Weapon {
+    Weapon {
+        w_type,
+        damage,
+        attacks,
+        range,
+        speed,
+        damage_bonus: Some(DamageBonus {
+            attribute: bonus.0,
+            bonus: bonus.1,
+        }),
+    }
+}
+
+use Attribute::{ARMORED, BIOLOGICAL, LIGHT, MASSIVE, MECHANICAL, PSIONIC};
+use WeaponTargetType::{AIR, ANY, GROUND};
+
+static UNIT_STATS: &[UnitStats] = &[
+    // Terran
+    UnitStats {
+        type_id: UnitTypeId::SCV,
+        name: "SCV",
+        attributes: &[LIGHT, BIOLOGICAL, MECHANICAL],
+        minerals: 50,
+        vespene: 0,
+        time: 272.0,
+        food: 1.0,
+        health: 45.0,
+        shield: 0.0,
+        armor: 0.0,
+        energy_max: 0.0,
+        movement_speed: 2.8125,
+        radius: 0.375,
+        is_flying: false,
+        weapons: &[weapon(GROUND, 5.0, 1, 0.1, 1.5)],
+    },
+    UnitStats {
+        type_id: UnitTypeId::MARINE,
+        name: "Marine",
+        attributes: &[LIGHT, BIOLOGICAL],
+        minerals: 50,
+        vespene: 0,
+        time: 400.0,
+        food: 1.0,
+        health: 45.0,
+        shield: 0.0,
+        armor: 0.0,
+        energy_max: 0.0,
+        movement_speed: 2.25,
+        radius: 0.375,
+        is_flying: false,
+        weapons: &[weapon(ANY, 6.0, 1, 5.0, 0.860_839_8)],
+    },
+    UnitStats {
+        type_id: UnitTypeId::MARAUDER,
+        name: "Marauder",
+        attributes: &[ARMORED, BIOLOGICAL],
+        minerals: 100,
+        vespene: 25,
+        time: 470.0,
+        food: 2.0,
+        health: 125.0,
+        shield: 0.0,
+        armor: 1.0,
+        energy_max: 0.0,
+        movement_speed: 2.25,
+        radius: 0.5625,
+        is_flying: false,
+        weapons: &[weapon_with_bonus(
+            GROUND,
+            10.0,
+            1,
+            6.0,
+            1.5,
+            (ARMORED, 10.0),
+        )],
+    },
+    UnitStats {
+        type_id: UnitTypeId::REAPER,
+        name: "Reaper",
+        attributes: &[LIGHT, BIOLOGICAL],
+        minerals: 50,
+        vespene: 50,
+        time: 717.0,
+        food: 1.0,
+        health: 60.0,
+        shield: 0.0,
+        armor: 0.0,
+        energy_max: 0.0,
+        movement_speed: 3.75,
+        radius: 0.375,
+        is_flying: false,
+        weapons: &[weapon(GROUND, 4.0, 2, 5.0, 1.1)],
+    },
+    UnitStats {
+        type_id: UnitTypeId::GHOST,
+        name: "Ghost",
+        attributes: &[BIOLOGICAL, PSIONIC],
+        minerals: 150,
+        vespene: 125,
+        time: 650.0,
+        food: 2.0,
+        health: 100.0,
+        shield: 0.0,
+        armor: 0.0,
+        energy_max: 200.0,
+        movement_speed: 2.8125,
+        radius: 0.375,
+        is_flying: false,
+        weapons: &[weapon_with_bonus(ANY, 10.0, 1, 6.0, 1.5, (LIGHT, 10.0))],
+    },
+    UnitStats {
+        type_id: UnitTypeId::HELLION,
+        name: "Hellion",
+        attributes: &[LIGHT, MECHANICAL],
+        minerals: 100,
+        vespene: 0,
+        time: 470.0,
+        food: 2.0,
+        health: 90.0,
+        shield: 0.0,
+        armor: 0.0,
+        energy_max: 0.0,
+        movement_speed: 4.25,
+        radius: 0.625,
+        is_flying: false,
+        weapons: &[weapon_with_bonus(GROUND, 8.0, 1, 5.0, 2.5, (LIGHT, 6.0))],
+    },
+    UnitStats {
+        type_id: UnitTypeId::HELLIONTANK,
+        name: "HellionTank",
+        attributes: &[LIGHT, BIOLOGICAL, MECHANICAL],
+        minerals: 100,
+        vespene: 0,
+        time: 470.0,
+        food: 2.0,
+        health: 135.0,
+        shield: 0.0,
+        armor: 0.0,
+        energy_max: 0.0,
+        movement_speed: 2.25,
+        radius: 0.625,
+        is_flying: false,
+        weapons: &[weapon_with_bonus(GROUND, 18.0, 1, 2.0, 2.0, (LIGHT, 12.0))],
+    },
+    UnitStats {
+        type_id: UnitTypeId::SIEGETANK,
+        name: "SiegeTank",
+        attributes: &[ARMORED, MECHANICAL],
+        minerals: 150,
+        vespene: 125,
+        time: 717.0,
+        food: 3.0,
+        health: 175.0,
+        shield: 0.0,
+        armor: 1.0,
+        energy_max: 0.0,
+        movement_speed: 2.25,
+        radius: 0.875,
+        is_flying: false,
+        weapons: &[weapon_with_bonus(
+            GROUND,
+            15.0,
+            1,
+            7.0,
+            1.04,
+            (ARMORED, 10.0),
+        )],
+    },
+    UnitStats {
+        type_id: UnitTypeId::SIEGETANKSIEGED,
+        name: "SiegeTankSieged",
+        attributes: &[ARMORED, MECHANICAL],
+        minerals: 150,
+        vespene: 125,
+        time: 717.0,
+        food: 3.0,
+        health: 175.0,
+        shield: 0.0,
+        armor: 1.0,
+        energy_max: 0.0,
+        movement_speed: 0.0,
+        radius: 0.875,
+        is_flying: false,
+        weapons: &[weapon_with_bonus(
+            GROUND,
+            40.0,
+            1,
+            13.0,
+            3.0,
+            (ARMORED, 30.0),
+        )],
+    },
+    UnitStats {
+        type_id: UnitTypeId::THOR,
+        name: "Thor",
+        attributes: &[ARMORED, MECHANICAL, MASSIVE],
+        minerals: 300,
+        vespene: 200,
+        time: 963.0,
+        food: 6.0,
+        health: 400.0,
+        shield: 0.0,
+        armor: 1.0,
+        energy_max: 0.0,
+        movement_speed: 1.875,
+        radius: 1.0,
+        is_flying: false,
+        weapons: &[
+            weapon(GROUND, 30.0, 2, 7.0, 1.28),
+            weapon_with_bonus(AIR, 6.0, 4, 10.0, 3.0, (LIGHT, 6.0)),
+        ],
+    },
+    UnitStats {
+        type_id: UnitTypeId::VIKINGFIGHTER,
+        name: "VikingFighter",
+        attributes: &[ARMORED, MECHANICAL],
+        minerals: 150,
+        vespene: 75,
+        time: 672.0,
+        food: 2.0,
+        health: 135.0,
+        shield: 0.0,
+        armor: 0.0,
+        energy_max: 0.0,
+        movement_speed: 2.75,
+        radius: 0.75,
+        is_flying: true,
+        weapons: &[weapon_with_bonus(AIR, 10.0, 2, 9.0, 2.0, (ARMORED, 4.0))],
+    },
+    UnitStats {
+        type_id: UnitTypeId::VIKINGASSAULT,
+        name: "VikingAssault",
+        attributes: &[ARMORED, MECHANICAL],
+        minerals: 150,
+        vespene: 75,
+        time: 672.0,
+        food: 2.0,
+        health: 135.0,
+        shield: 0.0,
+        armor: 0.0,
+        energy_max: 0.0,
+        movement_speed: 2.25,
+        radius: 0.75,
+        is_flying: false,
+        weapons: &[weapon_with_bonus(
+            GROUND,
+            12.0,
+            1,
+            6.0,
+            1.0,
+            (MECHANICAL, 8.0),
+        )],
+    },
+    UnitStats {
+        type_id: UnitTypeId::MEDIVAC,
+        name: "Medivac",
+        attributes: &[ARMORED, MECHANICAL],
+        minerals: 100,
+        vespene: 100,
+        time: 672.0,
+        food: 2.0,
+        health: 150.0,
+        shield: 0.0,
+        armor: 1.0,
+        energy_max: 200.0,
+        movement_speed: 2.5,
+        radius: 0.75,
+        is_flying: true,
+        weapons: &[],
+    },
+    UnitStats {
+        type_id: UnitTypeId::LIBERATOR,
+        name: "Liberator",
+        attributes: &[ARMORED, MECHANICAL],
+        minerals: 150,
+        vespene: 125,
+        time: 963.0,
+        food: 3.0,
+        health: 180.0,
+        shield: 0.0,
+        armor: 0.0,
+        energy_max: 0.0,
+        movement_speed: 3.375,
+        radius: 0.75,
+        is_flying: true,
+        weapons: &[weapon(AIR, 5.0, 2, 5.0, 1.8)],
+    },
+    UnitStats {
+        type_id: UnitTypeId::BANSHEE,
+        name: "Banshee",
+        attributes: &[LIGHT, MECHANICAL],
+        minerals: 150,
+        vespene: 100,
+        time: 963.0,
+        food: 3.0,
+        health: 140.0,
+        shield: 0.0,
+        armor: 0.0,
+        energy_max: 200.0,
+        movement_speed: 2.75,
+        radius: 0.75,
+        is_flying: true,
+        weapons: &[weapon(GROUND, 12.0, 2, 6.0, 1.75)],
+    },
+    UnitStats {
+        type_id: UnitTypeId::BATTLECRUISER,
+        name: "Battlecruiser",
+        attributes: &[ARMORED, MECHANICAL, MASSIVE],
+        minerals: 400,
+        vespene: 300,
+        time: 1440.0,
+        food: 6.0,
+        health: 550.0,
+        shield: 0.0,
+        armor: 3.0,
+        energy_max: 0.0,
+        movement_speed: 1.875,
+        radius: 1.25,
+        is_flying: true,
+        weapons: &[
+            weapon(AIR, 5.0, 1, 6.0, 0.224),
+            weapon(GROUND, 8.0, 1, 6.0, 0.224),
+        ],
+    },
+    // Protoss
+    UnitStats {
+        type_id: UnitTypeId::PROBE,
+        name: "Probe",
+        attributes: &[LIGHT, MECHANICAL],
+        minerals: 50,
+        vespene: 0,
+        time: 272.0,
+        food: 1.0,
+        health: 20.0,
+        shield: 20.0,
+        armor: 0.0,
+        energy_max: 0.0,
+        movement_speed: 2.8125,
+        radius: 0.375,
+        is_flying: false,
+        weapons: &[weapon(GROUND, 5.0, 1, 0.1, 1.5)],
+    },
+    UnitStats {
+        type_id: UnitTypeId::ZEALOT,
+        name: "Zealot",
+        attributes: &[LIGHT, BIOLOGICAL],
+        minerals: 100,
+        vespene: 0,
+        time: 605.0,
+        food: 2.0,
+        health: 100.0,
+        shield: 50.0,
+        armor: 1.0,
+        energy_max: 0.0,
+        movement_speed: 2.25,
+        radius: 0.5,
+        is_flying: false,
+        weapons: &[weapon(GROUND, 8.0, 2, 0.1, 1.2)],
+    },
+    UnitStats {
+        type_id: UnitTypeId::STALKER,
+        name: "Stalker",
+        attributes: &[ARMORED, MECHANICAL],
+        minerals: 125,
+        vespene: 50,
+        time: 672.0,
+        food: 2.0,
+        health: 80.0,
+        shield: 80.0,
+        armor: 1.0,
+        energy_max: 0.0,
+        movement_speed: 2.95,
+        radius: 0.625,
+        is_flying: false,
+        weapons: &[weapon_with_bonus(ANY, 13.0, 1, 6.0, 1.87, (ARMORED, 5.0))],
+    },
+    UnitStats {
+        type_id: UnitTypeId::SENTRY,
+        name: "Sentry",
+        attributes: &[LIGHT, MECHANICAL, PSIONIC],
+        minerals: 50,
+        vespene: 100,
+        time: 582.0,
+        food: 2.0,
+        health: 40.0,
+        shield: 40.0,
+        armor: 1.0,
+        energy_max: 200.0,
+        movement_speed: 2.25,
+        radius: 0.5,
+        is_flying: false,
+        weapons: &[weapon(ANY, 6.0, 1, 5.0, 1.0)],
+    },
+    UnitStats {
+        type_id: UnitTypeId::ADEPT,
+        name: "Adept",
+        attributes: &[LIGHT, BIOLOGICAL],
+        minerals: 100,
+        vespene: 25,
+        time: 672.0,
+        food: 2.0,
+        health: 70.0,
+        shield: 70.0,
+        armor: 1.0,
+        energy_max: 0.0,
+        movement_speed: 2.5,
+        radius: 0.5,
+        is_flying: false,
+        weapons: &[weapon_with_bonus(GROUND, 10.0, 1, 4.0, 2.25, (LIGHT, 12.0))],
+    },
+    UnitStats {
+        type_id: UnitTypeId::HIGHTEMPLAR,
+        name: "HighTemplar",
+        attributes: &[LIGHT, BIOLOGICAL, PSIONIC],
+        minerals: 50,
+        vespene: 150,
+        time: 874.0,
+        food: 2.0,
+        health: 40.0,
+        shield: 40.0,
+        armor: 0.0,
+        energy_max: 200.0,
+        movement_speed: 1.875,
+        radius: 0.375,
+        is_flying: false,
+        weapons: &[weapon(GROUND, 4.0, 1, 6.0, 1.75)],
+    },
+    UnitStats {
+        type_id: UnitTypeId::DARKTEMPLAR,
+        name: "DarkTemplar",
+        attributes: &[LIGHT, BIOLOGICAL, PSIONIC],
+        minerals: 125,
+        vespene: 125,
+        time: 874.0,
+        food: 2.0,
+        health: 40.0,
+        shield: 80.0,
+        armor: 1.0,
+        energy_max: 0.0,
+        movement_speed: 2.8125,
+        radius: 0.375,
+        is_flying: false,
+        weapons: &[weapon(GROUND, 45.0, 1, 0.1, 1.694)],
+    },
+    UnitStats {
+        type_id: UnitTypeId::ARCHON,
+        name: "Archon",
+        attributes: &[PSIONIC, MASSIVE],
+        minerals: 175,
+        vespene: 275,
+        time: 202.0,
+        food: 4.0,
+        health: 10.0,
+        shield: 350.0,
+        armor: 0.0,
+        energy_max: 0.0,
+        movement_speed: 2.8125,
+        radius: 1.0,
+        is_flying: false,
+        weapons: &[weapon_with_bonus(
+            ANY,
+            25.0,
+            1,
+            3.0,
+            1.754,
+            (BIOLOGICAL, 10.0),
+        )],
+    },
+    UnitStats {
+        type_id: UnitTypeId::IMMORTAL,
+        name: "Immortal",
+        attributes: &[ARMORED, MECHANICAL],
+        minerals: 275,
+        vespene: 100,
+        time: 874.0,
+        food: 4.0,
+        health: 200.0,
+        shield: 100.0,
+        armor: 1.0,
+        energy_max: 0.0,
+        movement_speed: 2.25,
+        radius: 0.75,
+        is_flying: false,
+        weapons: &[weapon_with_bonus(
+            GROUND,
+            20.0,
+            1,
+            6.0,
+            1.45,
+            (ARMORED, 30.0),
+        )],
+    },
+    UnitStats {
+        type_id: UnitTypeId::COLOSSUS,
+        name: "Colossus",
+        attributes: &[ARMORED, MECHANICAL, MASSIVE],
+        minerals: 300,
+        vespene: 200,
+        time: 1210.0,
+        food: 6.0,
+        health: 200.0,
+        shield: 150.0,
+        armor: 1.0,
+        energy_max: 0.0,
+        movement_speed: 2.25,
+        radius: 1.0,
+        is_flying: false,
+        weapons: &[weapon_with_bonus(GROUND, 10.0, 2, 7.0, 1.5, (LIGHT, 5.0))],
+    },
+    UnitStats {
+        type_id: UnitTypeId::PHOENIX,
+        name: "Phoenix",
+        attributes: &[LIGHT, MECHANICAL],
+        minerals: 150,
+        vespene: 100,
+        time: 560.0,
+        food: 2.0,
+        health: 120.0,
+        shield: 60.0,
+        armor: 0.0,
+        energy_max: 0.0,
+        movement_speed: 4.25,
+        radius: 0.75,
+        is_flying: true,
+        weapons: &[weapon_with_bonus(AIR, 5.0, 2, 5.0, 1.1, (LIGHT, 5.0))],
+    },
+    UnitStats {
+        type_id: UnitTypeId::VOIDRAY,
+        name: "VoidRay",
+        attributes: &[ARMORED, MECHANICAL],
+        minerals: 250,
+        vespene: 150,
+        time: 829.0,
+        food: 4.0,
+        health: 150.0,
+        shield: 100.0,
+        armor: 0.0,
+        energy_max: 0.0,
+        movement_speed: 2.75,
+        radius: 1.0,
+        is_flying: true,
+        weapons: &[weapon_with_bonus(ANY, 6.0, 1, 6.0, 0.5, (ARMORED, 4.0))],
+    },
+    UnitStats {
+        type_id: UnitTypeId::ORACLE,
+        name: "Oracle",
+        attributes: &[ARMORED, MECHANICAL],
+        minerals: 150,
+        vespene: 150,
+        time: 829.0,
+        food: 3.0,
+        health: 100.0,
+        shield: 60.0,
+        armor: 0.0,
+        energy_max: 200.0,
+        movement_speed: 4.0,
+        radius: 0.75,
+        is_flying: true,
+        weapons: &[weapon_with_bonus(GROUND, 15.0, 1, 4.0, 0.86, (LIGHT, 7.0))],
+    },
+    UnitStats {
+        type_id: UnitTypeId::TEMPEST,
+        name: "Tempest",
+        attributes: &[ARMORED, MECHANICAL, MASSIVE],
+        minerals: 250,
+        vespene: 175,
+        time: 963.0,
+        food: 5.0,
+        health: 200.0,
+        shield: 100.0,
+        armor: 2.0,
+        energy_max: 0.0,
+        movement_speed: 2.25,
+        radius: 1.25,
+        is_flying: true,
+        weapons: &[
+            weapon(GROUND, 40.0, 1, 10.0, 3.3),
+            weapon_with_bonus(AIR, 30.0, 1, 14.0, 3.3, (MASSIVE, 22.0)),
+        ],
+    },
+    UnitStats {
+        type_id: UnitTypeId::CARRIER,
+        name: "Carrier",
+        attributes: &[ARMORED, MECHANICAL, MASSIVE],
+        minerals: 350,
+        vespene: 250,
+        time: 1434.0,
+        food: 6.0,
+        health: 300.0,
+        shield: 150.0,
+        armor: 2.0,
+        energy_max: 0.0,
+        movement_speed: 1.875,
+        radius: 1.25,
+        is_flying: true,
+        // 8 interceptors with 2 attacks each
+        weapons: &[weapon(ANY, 5.0, 16, 8.0, 3.0)],
+    },
+    // Zerg
+    UnitStats {
+        type_id: UnitTypeId::DRONE,
+        name: "Drone",
+        attributes: &[LIGHT, BIOLOGICAL],
+        minerals: 50,
+        vespene: 0,
+        time: 272.0,
+        food: 1.0,
+        health: 40.0,
+        shield: 0.0,
+        armor: 0.0,
+        energy_max: 0.0,
+        movement_speed: 2.8125,
+        radius: 0.375,
+        is_flying: false,
+        weapons: &[weapon(GROUND, 5.0, 1, 0.1, 1.5)],
+    },
+    UnitStats {
+        type_id: UnitTypeId::QUEEN,
+        name: "Queen",
+        attributes: &[BIOLOGICAL, PSIONIC],
+        minerals: 150,
+        vespene: 0,
+        time: 806.0,
+        food: 2.0,
+        health: 175.0,
+        shield: 0.0,
+        armor: 1.0,
+        energy_max: 200.0,
+        movement_speed: 0.9375,
+        radius: 0.875,
+        is_flying: false,
+        weapons: &[
+            weapon(GROUND, 4.0, 2, 5.0, 1.0),
+            weapon(AIR, 9.0, 1, 7.0, 1.0),
+        ],
+    },
+    UnitStats {
+        type_id: UnitTypeId::ZERGLING,
+        name: "Zergling",
+        attributes: &[LIGHT, BIOLOGICAL],
+        minerals: 25,
+        vespene: 0,
+        time: 384.0,
+        food: 0.5,
+        health: 35.0,
+        shield: 0.0,
+        armor: 0.0,
+        energy_max: 0.0,
+        movement_speed: 2.953_125,
+        radius: 0.375,
+        is_flying: false,
+        weapons: &[weapon(GROUND, 5.0, 1, 0.100_097_66, 0.696_044_9)],
+    },
+    UnitStats {
+        type_id: UnitTypeId::ROACH,
+        name: "Roach",
+        attributes: &[ARMORED, BIOLOGICAL],
+        minerals: 75,
+        vespene: 25,
+        time: 426.0,
+        food: 2.0,
+        health: 145.0,
+        shield: 0.0,
+        armor: 1.0,
+        energy_max: 0.0,
+        movement_speed: 2.25,
+        radius: 0.625,
+        is_flying: false,
+        weapons: &[weapon(GROUND, 16.0, 1, 4.0, 2.0)],
+    },
+    UnitStats {
+        type_id: UnitTypeId::RAVAGER,
+        name: "Ravager",
+        attributes: &[BIOLOGICAL],
+        minerals: 100,
+        vespene: 100,
+        time: 202.0,
+        food: 3.0,
+        health: 120.0,
+        shield: 0.0,
+        armor: 1.0,
+        energy_max: 0.0,
+        movement_speed: 2.75,
+        radius: 0.75,
+        is_flying: false,
+        weapons: &[weapon(GROUND, 16.0, 1, 6.0, 1.6)],
+    },
+    UnitStats {
+        type_id: UnitTypeId::HYDRALISK,
+        name: "Hydralisk",
+        attributes: &[LIGHT, BIOLOGICAL],
+        minerals: 100,
+        vespene: 50,
+        time: 538.0,
+        food: 2.0,
+        health: 90.0,
+        shield: 0.0,
+        armor: 0.0,
+        energy_max: 0.0,
+        movement_speed: 2.25,
+        radius: 0.625,
+        is_flying: false,
+        weapons: &[weapon(ANY, 12.0, 1, 5.0, 0.83)],
+    },
+    UnitStats {
+        type_id: UnitTypeId::LURKERMPBURROWED,
+        name: "LurkerMPBurrowed",
+        attributes: &[ARMORED, BIOLOGICAL],
+        minerals: 150,
+        vespene: 150,
+        time: 538.0,
+        food: 3.0,
+        health: 200.0,
+        shield: 0.0,
+        armor: 1.0,
+        energy_max: 0.0,
+        movement_speed: 0.0,
+        radius: 0.9375,
+        is_flying: false,
+        weapons: &[weapon_with_bonus(
+            GROUND,
+            20.0,
+            1,
+            8.0,
+            2.0,
+            (ARMORED, 10.0),
+        )],
+    },
+    UnitStats {
+        type_id: UnitTypeId::ULTRALISK,
+        name: "Ultralisk",
+        attributes: &[ARMORED, BIOLOGICAL, MASSIVE],
+        minerals: 275,
+        vespene: 200,
+        time: 874.0,
+        food: 6.0,
+        health: 500.0,
+        shield: 0.0,
+        armor: 2.0,
+        energy_max: 0.0,
+        movement_speed: 2.95,
+        radius: 1.0,
+        is_flying: false,
+        weapons: &[weapon(GROUND, 35.0, 1, 1.0, 0.86)],
+    },
+    UnitStats {
+        type_id: UnitTypeId::MUTALISK,
+        name: "Mutalisk",
+        attributes: &[LIGHT, BIOLOGICAL],
+        minerals: 100,
+        vespene: 100,
+        time: 538.0,
+        food: 2.0,
+        health: 120.0,
+        shield: 0.0,
+        armor: 0.0,
+        energy_max: 0.0,
+        movement_speed: 4.0,
+        radius: 0.5,
+        is_flying: true,
+        weapons: &[weapon(ANY, 9.0, 1, 3.0, 1.52)],
+    },
+    UnitStats {
+        type_id: UnitTypeId::CORRUPTOR,
+        name: "Corruptor",
+        attributes: &[ARMORED, BIOLOGICAL],
+        minerals: 150,
+        vespene: 100,
+        time: 650.0,
+        food: 2.0,
+        health: 200.0,
+        shield: 0.0,
+        armor: 2.0,
+        energy_max: 0.0,
+        movement_speed: 3.375,
+        radius: 0.625,
+        is_flying: true,
+        weapons: &[weapon_with_bonus(AIR, 14.0, 1, 6.0, 1.9, (MASSIVE, 6.0))],
+    },
+    UnitStats {
+        type_id: UnitTypeId::BROODLORD,
+        name: "BroodLord",
+        attributes: &[ARMORED, BIOLOGICAL, MASSIVE],
+        minerals: 300,
+        vespene: 250,
+        time: 538.0,
+        food: 4.0,
+        health: 225.0,
+        shield: 0.0,
+        armor: 1.0,
+        energy_max: 0.0,
+        movement_speed: 1.40625,
+        radius: 1.0,
+        is_flying: true,
+        weapons: &[weapon(GROUND, 20.0, 1, 10.0, 2.5)],
+    },
+];
+
+impl UnitStats {
+    fn to_combat_unit(&self) -> CombatUnit {
+        let mut unit = CombatUnit {
+            type_id: self.type_id,
+            type_data: UnitTypeData::new(
+                self.attributes.to_vec(),
+                Cost {
+                    minerals: self.minerals,
+                    vespene: self.vespene,
+                    time: self.time,
+                },
+            ),
+            name: self.name.to_string(),
+            tag: 0,
+            is_light: false,
+            is_armored: false,
+            is_biological: false,
+            is_mechanical: false,
+            is_massive: false,
+            is_psionic: false,
+            weapons: None,
+            ground_dps: 0.0,
+            ground_range: 0.0,
+            air_dps: 0.0,
+            air_range: 0.0,
+            armor: self.armor,
+            movement_speed: self.movement_speed,
+            health: self.health,
+            health_max: self.health,
+            shield: self.shield,
+            shield_max: self.shield,
+            energy: self.energy_max.min(50.0),
+            energy_max: self.energy_max,
+            radius: self.radius,
+            is_flying: self.is_flying,
+            attack_upgrade_level: 0,
+            armor_upgrade_level: 0,
+            shield_upgrade_level: 0,
+            buff_timer: 0.0,
+        };
+        unit.type_data.food_required = self.food;
+        unit.set_attributes(self.attributes.to_vec());
+        unit.set_weapons(self.weapons.to_vec());
+        unit
+    }
+}
+
+lazy_static! {
+    static ref UNIT_DATABASE: HashMap<UnitTypeId, CombatUnit> = UNIT_STATS
+        .iter()
+        .map(|stats| (stats.type_id, stats.to_combat_unit()))
+        .collect();
+}
+
+/// Unit with full health and no upgrades, `None` if there is no data for `type_id`.
+pub fn get_unit(type_id: UnitTypeId) -> Option<CombatUnit> {
+    UNIT_DATABASE.get(&type_id).cloned()
+}
+
+/// All unit types with data in the database.
+pub fn unit_types() -> Vec<UnitTypeId> {
+    UNIT_STATS.iter().map(|stats| stats.type_id).collect()
+}
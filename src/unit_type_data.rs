@@ -1,27 +1,61 @@
+use crate::conversion::{self, extract_field};
 use crate::enums::Attribute;
-use pyo3::{FromPyObject, PyAny, PyResult, Python, ToPyObject};
+use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
 
+#[pyclass(module = "sc2_helper.sc2_helper")]
 #[derive(Debug, Clone, Serialize, Deserialize, Copy)]
 pub struct Cost {
+    #[pyo3(get, set)]
     pub minerals: i32,
+    #[pyo3(get, set)]
     pub vespene: i32,
+    /// Build time in game loops.
+    #[pyo3(get, set)]
     pub time: f32,
 }
 
-impl<'source> FromPyObject<'source> for Cost {
-    fn extract(ob: &'source PyAny) -> PyResult<Self> {
-        //        println!("{:?}", "Extracting DamageBonus");
-        unsafe {
-            let py = Python::assume_gil_acquired();
-            let obj = ob.to_object(py);
-            Ok(Self {
-                minerals: obj.getattr(py, "minerals")?.extract(py)?,
-                vespene: obj.getattr(py, "vespene")?.extract(py)?,
-                time: obj.getattr(py, "time")?.extract(py)?,
-            })
+#[pymethods]
+impl Cost {
+    #[new]
+    #[pyo3(signature = (minerals = 0, vespene = 0, time = 0.0))]
+    fn py_new(minerals: i32, vespene: i32, time: f32) -> Self {
+        Cost {
+            minerals,
+            vespene,
+            time,
         }
     }
+
+    /// JSON representation, also used to pickle it.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_json(&self) -> PyResult<String> {
+        conversion::to_json(self)
+    }
+
+    #[staticmethod]
+    pub fn from_json(json: &str) -> PyResult<Self> {
+        conversion::from_json(json)
+    }
+
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (String,))> {
+        conversion::reduce_json::<Self, _>(py, self)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl Cost {
+    /// Converts a python-sc2 `Cost`, or anything else with the same fields.
+    pub fn from_sc2(ob: &PyAny) -> PyResult<Self> {
+        Ok(Self {
+            minerals: extract_field(ob, "minerals")?,
+            vespene: extract_field(ob, "vespene")?,
+            time: extract_field(ob, "time")?,
+        })
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -30,24 +64,31 @@ pub struct UnitTypeData {
     // pub name: String,
     pub attributes: Vec<Attribute>,
     pub cost: Cost,
+    #[serde(default)]
+    pub food_required: f32,
 }
 impl UnitTypeData {
     pub fn new(attributes: Vec<Attribute>, cost: Cost) -> Self {
-        Self { attributes, cost }
+        Self {
+            attributes,
+            cost,
+            food_required: 0.0,
+        }
     }
 }
 impl<'source> FromPyObject<'source> for UnitTypeData {
     fn extract(ob: &'source PyAny) -> PyResult<Self> {
-        //        println!("{:?}", "Extracting DamageBonus");
-        unsafe {
-            let py = Python::assume_gil_acquired();
-            let obj = ob.to_object(py);
-            Ok(Self {
-                // id: obj.getattr(py, "id")?.extract(py)?,
-                // name: obj.getattr(py, "name")?.extract(py)?,
-                attributes: obj.getattr(py, "attributes")?.extract(py)?,
-                cost: obj.getattr(py, "cost")?.extract(py)?,
-            })
-        }
+        Ok(Self {
+            // id: obj.getattr(py, "id")?.extract(py)?,
+            // name: obj.getattr(py, "name")?.extract(py)?,
+            attributes: extract_field(ob, "attributes")?,
+            cost: Cost::from_sc2(extract_field(ob, "cost")?)?,
+            // python-sc2 only exposes supply through the raw proto
+            food_required: ob
+                .getattr("_proto")
+                .and_then(|proto| proto.getattr("food_required"))
+                .and_then(|food| food.extract())
+                .unwrap_or(0.0),
+        })
     }
 }
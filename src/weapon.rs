@@ -1,10 +1,12 @@
-use crate::num_traits::{FromPrimitive, ToPrimitive};
+use crate::conversion::{self, extract_enum, extract_field};
+use crate::num_traits::ToPrimitive;
 use serde::{Deserialize, Serialize};
 
 use crate::combat_unit::CombatUnit;
 use crate::enums::Attribute;
 use crate::generated_enums::UnitTypeId;
-use pyo3::{FromPyObject, IntoPy, PyAny, PyObject, PyResult, Python, ToPyObject};
+use pyo3::prelude::*;
+use pyo3::ToPyObject;
 use std::f32::EPSILON;
 
 #[allow(missing_docs)]
@@ -15,6 +17,10 @@ pub enum WeaponTargetType {
     AIR = 2,
     ANY = 3,
 }
+impl WeaponTargetType {
+    /// Name and value of every variant, used to export the enum to Python.
+    pub const VARIANTS: &[(&str, i32)] = &[("NULL", 0), ("GROUND", 1), ("AIR", 2), ("ANY", 3)];
+}
 impl Default for WeaponTargetType {
     fn default() -> Self {
         WeaponTargetType::NULL
@@ -35,55 +41,103 @@ impl IntoPy<PyObject> for WeaponTargetType{
 
 impl<'source> FromPyObject<'source> for WeaponTargetType {
     fn extract(ob: &'source PyAny) -> PyResult<Self> {
-        //        println!("{:?}", "Extracting WeaponTargetType");
-        let ob1: i32 = ob.extract::<i32>().unwrap();
-        let x: WeaponTargetType = WeaponTargetType::from_i32(ob1).unwrap_or_default();
-        Ok(x)
+        extract_enum(ob, "WeaponTargetType")
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[pyclass(module = "sc2_helper.sc2_helper")]
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
 pub struct Weapon {
+    #[serde(rename = "type")]
     pub w_type: WeaponTargetType,
+    #[pyo3(get, set)]
     pub damage: f32,
+    #[pyo3(get, set)]
     pub attacks: i32,
+    #[pyo3(get, set)]
     pub range: f32,
+    /// Cooldown between attacks in seconds, normal game speed.
+    #[pyo3(get, set)]
     pub speed: f32,
+    #[pyo3(get, set)]
     pub damage_bonus: Option<DamageBonus>,
 }
-impl<'source> FromPyObject<'source> for Weapon {
-    fn extract(ob: &'source PyAny) -> PyResult<Self> {
-        unsafe {
-            let py = Python::assume_gil_acquired();
-            let obj = ob.to_object(py);
-            Ok(Self {
-                w_type: obj.getattr(py, "type")?.extract(py)?,
-                damage: obj.getattr(py, "damage")?.extract(py)?,
-                attacks: obj.getattr(py, "attacks")?.extract(py)?,
-                range: obj.getattr(py, "range")?.extract(py)?,
-                speed: obj.getattr(py, "speed")?.extract(py)?,
-                damage_bonus: {
-                    match obj.getattr(py, "damage_bonus") {
-                        Ok(damage_bonus) => {
-                            if let Ok(x) = damage_bonus.extract::<Vec<DamageBonus>>(py) {
-                                if !x.is_empty() {
-                                    Some(x[0])
-                                } else {
-                                    None
-                                }
-                            } else {
-                                None
-                            }
-                        }
-                        _ => None,
-                    }
-                },
-            })
+
+#[pymethods]
+impl Weapon {
+    #[new]
+    #[pyo3(signature = (r#type, damage, attacks, range, speed, damage_bonus = None))]
+    fn py_new(
+        r#type: WeaponTargetType,
+        damage: f32,
+        attacks: i32,
+        range: f32,
+        speed: f32,
+        damage_bonus: Option<DamageBonus>,
+    ) -> Self {
+        Weapon {
+            w_type: r#type,
+            damage,
+            attacks,
+            range,
+            speed,
+            damage_bonus,
         }
     }
+
+    /// Target type, same name as in python-sc2.
+    #[getter]
+    fn r#type(&self) -> WeaponTargetType {
+        self.w_type
+    }
+
+    #[setter]
+    fn set_type(&mut self, value: WeaponTargetType) {
+        self.w_type = value;
+    }
+
+    /// JSON representation, also used to pickle it.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_json(&self) -> PyResult<String> {
+        conversion::to_json(self)
+    }
+
+    #[staticmethod]
+    pub fn from_json(json: &str) -> PyResult<Self> {
+        conversion::from_json(json)
+    }
+
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (String,))> {
+        conversion::reduce_json::<Self, _>(py, self)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
 }
 
 impl Weapon {
+    /// Converts a python-sc2 weapon, or anything else with the same fields.
+    pub fn from_sc2(ob: &PyAny) -> PyResult<Self> {
+        // Weapons without bonus damage may leave it out
+        let damage_bonus: Vec<&PyAny> = if ob.hasattr("damage_bonus")? {
+            extract_field(ob, "damage_bonus")?
+        } else {
+            vec![]
+        };
+        Ok(Self {
+            w_type: extract_field(ob, "type")?,
+            damage: extract_field(ob, "damage")?,
+            attacks: extract_field(ob, "attacks")?,
+            range: extract_field(ob, "range")?,
+            speed: extract_field(ob, "speed")?,
+            damage_bonus: match damage_bonus.first() {
+                Some(bonus) => Some(DamageBonus::from_sc2(bonus)?),
+                None => None,
+            },
+        })
+    }
+
     pub fn battlecruiser() -> Vec<Self> {
         let air = Weapon {
             w_type: WeaponTargetType::AIR,
@@ -104,11 +158,42 @@ impl Weapon {
         vec![air, ground]
     }
 }
+#[pyclass(module = "sc2_helper.sc2_helper")]
 #[derive(Debug, Copy, Clone, Deserialize, Serialize)]
 pub struct DamageBonus {
+    #[pyo3(get, set)]
     pub(crate) attribute: Attribute,
+    #[pyo3(get, set)]
     pub(crate) bonus: f32,
 }
+
+#[pymethods]
+impl DamageBonus {
+    #[new]
+    fn py_new(attribute: Attribute, bonus: f32) -> Self {
+        DamageBonus { attribute, bonus }
+    }
+
+    /// JSON representation, also used to pickle it.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_json(&self) -> PyResult<String> {
+        conversion::to_json(self)
+    }
+
+    #[staticmethod]
+    pub fn from_json(json: &str) -> PyResult<Self> {
+        conversion::from_json(json)
+    }
+
+    fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (String,))> {
+        conversion::reduce_json::<Self, _>(py, self)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
 impl PartialEq for DamageBonus {
     fn eq(&self, other: &Self) -> bool {
         self.attribute == other.attribute && (self.bonus - other.bonus).abs() < EPSILON
@@ -127,17 +212,13 @@ impl DamageBonus {
     }
 }
 
-impl<'source> FromPyObject<'source> for DamageBonus {
-    fn extract(ob: &'source PyAny) -> PyResult<Self> {
-        //        println!("{:?}", "Extracting DamageBonus");
-        unsafe {
-            let py = Python::assume_gil_acquired();
-            let obj = ob.to_object(py);
-            Ok(Self {
-                attribute: obj.getattr(py, "attribute")?.extract(py)?,
-                bonus: obj.getattr(py, "bonus")?.extract(py)?,
-            })
-        }
+impl DamageBonus {
+    /// Converts a python-sc2 damage bonus, or anything else with the same fields.
+    pub fn from_sc2(ob: &PyAny) -> PyResult<Self> {
+        Ok(Self {
+            attribute: extract_field(ob, "attribute")?,
+            bonus: extract_field(ob, "bonus")?,
+        })
     }
 }
 